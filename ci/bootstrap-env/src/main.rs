@@ -6,6 +6,7 @@ use cw20::Cw20Coin;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_voting::{
     deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     pre_propose::PreProposeInfo,
     threshold::PercentageThreshold,
     threshold::Threshold,
@@ -87,7 +88,7 @@ fn main() -> Result<()> {
                 },
                 max_voting_period: cw_utils::Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -98,8 +99,15 @@ fn main() -> Result<()> {
                                 },
                                 amount: Uint128::new(1000000000),
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                alternatives: vec![],
+                                pricing: None,
+                                keeper_share: None,
                             }),
                             open_proposal_submission: false,
+                            rate_limit: None,
+                            proposal_threshold: None,
+                            submission_roles: None,
+                            message_templates: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -109,6 +117,8 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -8,6 +8,7 @@ use dao_interface::query::DumpStateResponse;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_voting::{
     deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     threshold::PercentageThreshold,
     threshold::Threshold,
@@ -71,8 +72,10 @@ pub fn create_dao(
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -83,8 +86,15 @@ pub fn create_dao(
                                 },
                                 amount: DEPOSIT_AMOUNT,
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                alternatives: vec![],
+                                pricing: None,
+                                keeper_share: None,
                             }),
                             open_proposal_submission: false,
+                            rate_limit: None,
+                            proposal_threshold: None,
+                            submission_roles: None,
+                            message_templates: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -248,6 +258,7 @@ pub fn create_proposal(
                     description: "desc".to_string(),
                     msgs,
                     vote: None,
+                    escrow: vec![],
                 },
             },
             key,
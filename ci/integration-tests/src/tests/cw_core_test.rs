@@ -9,7 +9,8 @@ use cw20_stake::msg::{StakedValueResponse, TotalValueResponse};
 use cw_utils::Duration;
 use dao_interface::query::{GetItemResponse, PauseInfoResponse};
 use dao_voting::{
-    pre_propose::ProposalCreationPolicy, threshold::PercentageThreshold, threshold::Threshold,
+    executor::ExecutorPolicy, pre_propose::ProposalCreationPolicy, threshold::PercentageThreshold,
+    threshold::Threshold,
 };
 use test_context::test_context;
 
@@ -363,7 +364,7 @@ fn instantiate_with_admin(chain: &mut Chain) {
     assert_eq!(config_res.min_voting_period, None);
     assert_eq!(config_res.max_voting_period, Duration::Time(432000));
     assert!(!config_res.allow_revoting);
-    assert!(config_res.only_members_execute);
+    assert_eq!(config_res.executor_policy, ExecutorPolicy::Members {});
     assert!(matches!(
         proposal_creation_policy,
         ProposalCreationPolicy::Module { .. }
@@ -0,0 +1,467 @@
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
+use cw20::Cw20ReceiveMsg;
+use cw_storage_plus::Bound;
+use cw_utils::{one_coin, Duration};
+use dao_hooks::vote::VoteHookMsg;
+use dao_interface::voting::{
+    InfoResponse, Query as VotingQueryMsg, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+use serde::Deserialize;
+
+use crate::msg::{
+    EpochResponse, ExecuteMsg, InstantiateMsg, PendingRewardsResponse, QueryMsg, ReceiveMsg,
+};
+use crate::state::{
+    allocate_finished_epochs, pending_allocations, Config, Epoch, CONFIG, COUNTED_VOTES, EPOCHS,
+    HOOK_CALLERS, NEXT_UNALLOCATED_EPOCH, REWARD_POOL, SCORES,
+};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Intialize the contract owner
+    cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
+
+    if let Duration::Height(0) | Duration::Time(0) = msg.epoch_duration {
+        return Err(ContractError::ZeroEpochDuration {});
+    }
+    if msg.rewards_per_epoch.is_zero() {
+        return Err(ContractError::ZeroRewardsPerEpoch {});
+    }
+
+    let vp_contract = deps.api.addr_validate(&msg.vp_contract)?;
+    // validate that the voting power contract responds to voting
+    // power queries.
+    let _: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &vp_contract,
+        &VotingQueryMsg::TotalPowerAtHeight { height: None },
+    )?;
+
+    for hook_caller in msg.hook_callers {
+        let hook_caller = deps.api.addr_validate(&hook_caller)?;
+        HOOK_CALLERS.save(deps.storage, &hook_caller, &Empty {})?;
+    }
+
+    let config = Config {
+        vp_contract,
+        reward_denom: msg.reward_denom.into_checked(deps.as_ref())?,
+        rewards_per_epoch: msg.rewards_per_epoch,
+        epoch_duration: msg.epoch_duration,
+        started_at: match msg.epoch_duration {
+            Duration::Height(_) => env.block.height,
+            Duration::Time(_) => env.block.time.seconds(),
+        },
+        weight_by_voting_power: msg.weight_by_voting_power,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    REWARD_POOL.save(deps.storage, &Uint128::zero())?;
+    NEXT_UNALLOCATED_EPOCH.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("owner", msg.owner.unwrap_or_else(|| "None".to_string())))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // Rewards are allocated to finished epochs before anything else
+    // happens so that the reward pool is always split between epochs
+    // in the order they finished.
+    let config = CONFIG.load(deps.storage)?;
+    allocate_finished_epochs(deps.storage, &config, &env.block)?;
+
+    match msg {
+        ExecuteMsg::VoteHook(msg) => execute_vote_hook(deps, env, info, config, msg),
+        ExecuteMsg::Claim {} => execute_claim(deps, env, info, config),
+        ExecuteMsg::Receive(msg) => execute_receive(deps, info, config, msg),
+        ExecuteMsg::Fund {} => execute_fund_native(deps, info, config),
+        ExecuteMsg::UpdateHookCallers { to_add, to_remove } => {
+            execute_update_hook_callers(deps, info, to_add, to_remove)
+        }
+        ExecuteMsg::Withdraw {} => execute_withdraw(deps, info, config),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
+    }
+}
+
+fn execute_vote_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Config,
+    msg: VoteHookMsg,
+) -> Result<Response, ContractError> {
+    // only registered proposal modules may report votes.
+    if !HOOK_CALLERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::InvalidHookSender {});
+    }
+
+    let VoteHookMsg::NewVote {
+        proposal_id, voter, ..
+    } = msg;
+    let voter = deps.api.addr_validate(&voter)?;
+
+    // revotes do not count as additional participation.
+    if COUNTED_VOTES.has(deps.storage, (&info.sender, proposal_id, &voter)) {
+        return Ok(Response::new()
+            .add_attribute("action", "vote_hook")
+            .add_attribute("voter", voter)
+            .add_attribute("score", "0"));
+    }
+    COUNTED_VOTES.save(deps.storage, (&info.sender, proposal_id, &voter), &Empty {})?;
+
+    // votes are weighted by the voting power they were cast with,
+    // so staking right before voting does not inflate a score.
+    let score = if config.weight_by_voting_power {
+        let height = get_start_height(deps.as_ref(), &info.sender, proposal_id)?;
+        get_voting_power(deps.as_ref(), &config.vp_contract, &voter, height)?
+    } else {
+        Uint128::one()
+    };
+
+    let epoch = config.epoch_at(&env.block);
+    if !score.is_zero() {
+        EPOCHS.update(deps.storage, epoch, |state| -> StdResult<_> {
+            let mut state = state.unwrap_or_default();
+            state.total_score += score;
+            Ok(state)
+        })?;
+        SCORES.update(deps.storage, (&voter, epoch), |prev| -> StdResult<_> {
+            Ok(prev.unwrap_or_default() + score)
+        })?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "vote_hook")
+        .add_attribute("voter", voter)
+        .add_attribute("epoch", epoch.to_string())
+        .add_attribute("score", score))
+}
+
+fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Config,
+) -> Result<Response, ContractError> {
+    // all finished epochs have been allocated rewards at this point.
+    let finished = SCORES
+        .prefix(&info.sender)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::exclusive(config.epoch_at(&env.block))),
+            Order::Ascending,
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut amount = Uint128::zero();
+    let mut remainder = Uint128::zero();
+    for (id, score) in finished {
+        let mut epoch = EPOCHS.load(deps.storage, id)?;
+        let (rewards, leftover) = epoch.claim(score);
+        amount += rewards;
+        // rounding leaves dust once every voter has claimed, which
+        // is returned to the pool to be paid out in later epochs.
+        remainder += leftover.unwrap_or_default();
+        EPOCHS.save(deps.storage, id, &epoch)?;
+        SCORES.remove(deps.storage, (&info.sender, id));
+    }
+    if !remainder.is_zero() {
+        REWARD_POOL.update(deps.storage, |pool| -> StdResult<_> {
+            Ok(pool + remainder)
+        })?;
+    }
+
+    if amount.is_zero() {
+        return Err(ContractError::NoRewardsClaimable {});
+    }
+
+    Ok(Response::new()
+        .add_message(
+            config
+                .reward_denom
+                .get_transfer_to_message(&info.sender, amount)?,
+        )
+        .add_attribute("action", "claim")
+        .add_attribute("amount", amount)
+        .add_attribute("remainder", remainder))
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: Config,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // verify msg
+    let _msg: ReceiveMsg = from_json(&wrapper.msg)?;
+
+    if !config.reward_denom.is_cw20(&info.sender) {
+        return Err(ContractError::InvalidFunds {});
+    }
+    execute_fund(deps, wrapper.amount)
+}
+
+fn execute_fund_native(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: Config,
+) -> Result<Response, ContractError> {
+    let fund_coin = one_coin(&info).map_err(|_| ContractError::InvalidFunds {})?;
+
+    if !config.reward_denom.is_native(&fund_coin.denom) {
+        return Err(ContractError::InvalidFunds {});
+    }
+    execute_fund(deps, fund_coin.amount)
+}
+
+fn execute_fund(deps: DepsMut, amount: Uint128) -> Result<Response, ContractError> {
+    let pool = REWARD_POOL.update(deps.storage, |pool| -> StdResult<_> { Ok(pool + amount) })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "fund")
+        .add_attribute("amount", amount)
+        .add_attribute("reward_pool", pool))
+}
+
+fn execute_update_hook_callers(
+    deps: DepsMut,
+    info: MessageInfo,
+    to_add: Vec<String>,
+    to_remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    for hook_caller in to_add {
+        let hook_caller = deps.api.addr_validate(&hook_caller)?;
+        HOOK_CALLERS.save(deps.storage, &hook_caller, &Empty {})?;
+    }
+    for hook_caller in to_remove {
+        let hook_caller = deps.api.addr_validate(&hook_caller)?;
+        HOOK_CALLERS.remove(deps.storage, &hook_caller);
+    }
+
+    Ok(Response::new().add_attribute("action", "update_hook_callers"))
+}
+
+fn execute_withdraw(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: Config,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let amount = REWARD_POOL.load(deps.storage)?;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    REWARD_POOL.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_message(
+            config
+                .reward_denom
+                .get_transfer_to_message(&info.sender, amount)?,
+        )
+        .add_attribute("action", "withdraw")
+        .add_attribute("amount", amount))
+}
+
+fn execute_update_owner(
+    deps: DepsMut,
+    info: MessageInfo,
+    env: Env,
+    action: cw_ownable::Action,
+) -> Result<Response, ContractError> {
+    // Update the current contract owner.
+    // Note, this is a two step process, the new owner must accept this ownership transfer.
+    // First the owner specifies the new owner, then the new owner must accept.
+    let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+    Ok(Response::default().add_attributes(ownership.into_attributes()))
+}
+
+fn get_voting_power(
+    deps: Deps,
+    vp_contract: &Addr,
+    addr: &Addr,
+    height: u64,
+) -> StdResult<Uint128> {
+    let resp: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        vp_contract,
+        &VotingQueryMsg::VotingPowerAtHeight {
+            address: addr.to_string(),
+            height: Some(height),
+        },
+    )?;
+    Ok(resp.power)
+}
+
+/// The `Proposal` query of dao-proposal-single and
+/// dao-proposal-multiple.
+#[cw_serde]
+enum ProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+/// The `Proposal` query of dao-proposal-condorcet.
+#[cw_serde]
+enum CondorcetProposalQuery {
+    Proposal { id: u64 },
+}
+
+/// The parts of a `Proposal` query response that may hold the height
+/// voting power was snapshotted at. dao-proposal-single and
+/// dao-proposal-multiple report it on the proposal, and
+/// dao-proposal-condorcet on the tally.
+#[derive(Deserialize)]
+struct ProposalStartResponse {
+    proposal: Option<StartHeight>,
+    tally: Option<StartHeight>,
+}
+
+#[derive(Deserialize)]
+struct StartHeight {
+    start_height: Option<u64>,
+}
+
+/// Queries PROPOSAL_MODULE for the height at which voting power was
+/// snapshotted for PROPOSAL_ID.
+fn get_start_height(
+    deps: Deps,
+    proposal_module: &Addr,
+    proposal_id: u64,
+) -> Result<u64, ContractError> {
+    let resp: ProposalStartResponse = deps
+        .querier
+        .query_wasm_smart(proposal_module, &ProposalQuery::Proposal { proposal_id })
+        .or_else(|_| {
+            deps.querier.query_wasm_smart(
+                proposal_module,
+                &CondorcetProposalQuery::Proposal { id: proposal_id },
+            )
+        })?;
+    [resp.proposal, resp.tally]
+        .into_iter()
+        .flatten()
+        .find_map(|part| part.start_height)
+        .ok_or(ContractError::UnknownStartHeight { proposal_id })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Info {} => to_json_binary(&query_info(deps)?),
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
+        QueryMsg::HookCallers {} => to_json_binary(&query_hook_callers(deps)?),
+        QueryMsg::CurrentEpoch {} => {
+            to_json_binary(&CONFIG.load(deps.storage)?.epoch_at(&env.block))
+        }
+        QueryMsg::Epoch { epoch } => to_json_binary(&query_epoch(deps, env, epoch)?),
+        QueryMsg::Score { address, epoch } => {
+            to_json_binary(&query_score(deps, env, address, epoch)?)
+        }
+        QueryMsg::PendingRewards { address } => {
+            to_json_binary(&query_pending_rewards(deps, env, address)?)
+        }
+        QueryMsg::RewardPool {} => {
+            let config = CONFIG.load(deps.storage)?;
+            let (_, pool) = pending_allocations(deps.storage, &config, &env.block)?;
+            to_json_binary(&pool)
+        }
+    }
+}
+
+fn query_info(deps: Deps) -> StdResult<InfoResponse> {
+    let info = get_contract_version(deps.storage)?;
+    Ok(InfoResponse { info })
+}
+
+fn query_hook_callers(deps: Deps) -> StdResult<Vec<Addr>> {
+    HOOK_CALLERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Loads an epoch, including rewards that have been earned but not
+/// yet allocated.
+fn load_epoch(deps: Deps, env: &Env, config: &Config, epoch: u64) -> StdResult<Epoch> {
+    let (allocations, _) = pending_allocations(deps.storage, config, &env.block)?;
+    match allocations.into_iter().find(|(id, _)| *id == epoch) {
+        Some((_, state)) => Ok(state),
+        None => Ok(EPOCHS.may_load(deps.storage, epoch)?.unwrap_or_default()),
+    }
+}
+
+fn query_epoch(deps: Deps, env: Env, epoch: Option<u64>) -> StdResult<EpochResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let epoch = epoch.unwrap_or_else(|| config.epoch_at(&env.block));
+    Ok(EpochResponse {
+        epoch,
+        state: load_epoch(deps, &env, &config, epoch)?,
+    })
+}
+
+fn query_score(deps: Deps, env: Env, address: String, epoch: Option<u64>) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let epoch = epoch.unwrap_or_else(|| config.epoch_at(&env.block));
+    Ok(SCORES
+        .may_load(deps.storage, (&address, epoch))?
+        .unwrap_or_default())
+}
+
+fn query_pending_rewards(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<PendingRewardsResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let addr = deps.api.addr_validate(&address)?;
+    let (allocations, _) = pending_allocations(deps.storage, &config, &env.block)?;
+
+    let pending_rewards = SCORES
+        .prefix(&addr)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::exclusive(config.epoch_at(&env.block))),
+            Order::Ascending,
+        )
+        .map(|item| {
+            let (epoch, score) = item?;
+            let state = match allocations.iter().find(|(id, _)| *id == epoch) {
+                Some((_, state)) => state.clone(),
+                None => EPOCHS.load(deps.storage, epoch)?,
+            };
+            Ok(state.rewards_for(score))
+        })
+        .sum::<StdResult<Uint128>>()?;
+
+    Ok(PendingRewardsResponse {
+        address,
+        pending_rewards,
+    })
+}
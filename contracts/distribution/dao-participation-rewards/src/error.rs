@@ -0,0 +1,36 @@
+use cosmwasm_std::StdError;
+use cw_denom::DenomError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownable(#[from] cw_ownable::OwnershipError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("Invalid funds")]
+    InvalidFunds {},
+
+    #[error("Vote hook sender is not a registered hook caller")]
+    InvalidHookSender {},
+
+    #[error("Could not find the start height of proposal ({proposal_id})")]
+    UnknownStartHeight { proposal_id: u64 },
+
+    #[error("No rewards claimable")]
+    NoRewardsClaimable {},
+
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw {},
+
+    #[error("Epoch duration can not be zero")]
+    ZeroEpochDuration {},
+
+    #[error("Rewards per epoch can not be zero")]
+    ZeroRewardsPerEpoch {},
+}
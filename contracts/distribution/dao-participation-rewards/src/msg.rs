@@ -0,0 +1,117 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw20::Cw20ReceiveMsg;
+use cw_denom::UncheckedDenom;
+use cw_ownable::cw_ownable_execute;
+use cw_utils::Duration;
+use dao_hooks::vote::VoteHookMsg;
+use dao_interface::voting::InfoResponse;
+
+use crate::state::{Config, Epoch};
+
+// so that consumers don't need a cw_ownable dependency to consume
+// this contract's queries.
+pub use cw_ownable::Ownership;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The owner of the contract. Is able to update the hook callers
+    /// and withdraw unallocated rewards.
+    pub owner: Option<String>,
+    /// The DAO voting power module used to weight votes.
+    pub vp_contract: String,
+    /// The proposal modules whose vote hooks are counted.
+    pub hook_callers: Vec<String>,
+    /// The token rewards are paid in.
+    pub reward_denom: UncheckedDenom,
+    /// The amount of rewards paid out at the end of each epoch. If
+    /// the reward pool holds less than this, the whole pool is paid
+    /// out.
+    pub rewards_per_epoch: Uint128,
+    /// The length of an epoch, in blocks or seconds. Epochs start
+    /// when the contract is instantiated.
+    pub epoch_duration: Duration,
+    /// If true, votes score the voter's voting power. Otherwise,
+    /// every vote scores one point.
+    pub weight_by_voting_power: bool,
+}
+
+#[cw_ownable_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Called by proposal modules when a vote is cast.
+    VoteHook(VoteHookMsg),
+    /// Claims the sender's rewards from finished epochs.
+    Claim {},
+    /// Used to fund this contract with cw20 tokens.
+    Receive(Cw20ReceiveMsg),
+    /// Used to fund this contract with native tokens.
+    Fund {},
+    /// Adds and removes proposal modules whose vote hooks are
+    /// counted. Only the owner may call this method.
+    UpdateHookCallers {
+        to_add: Vec<String>,
+        to_remove: Vec<String>,
+    },
+    /// Withdraws rewards that have not been allocated to an epoch to
+    /// the owner. Only the owner may call this method.
+    Withdraw {},
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Used to fund this contract with cw20 tokens.
+    Fund {},
+}
+
+#[cw_serde]
+pub enum MigrateMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns contract version info.
+    #[returns(InfoResponse)]
+    Info {},
+    /// Returns the contract's config.
+    #[returns(Config)]
+    Config {},
+    /// Returns information about the ownership of this contract.
+    #[returns(::cw_ownable::Ownership<::cosmwasm_std::Addr>)]
+    Ownership {},
+    /// Returns the proposal modules whose vote hooks are counted.
+    #[returns(Vec<::cosmwasm_std::Addr>)]
+    HookCallers {},
+    /// Returns the current epoch number.
+    #[returns(::std::primitive::u64)]
+    CurrentEpoch {},
+    /// Returns the participation and rewards of an epoch. Defaults
+    /// to the current epoch.
+    #[returns(EpochResponse)]
+    Epoch { epoch: Option<u64> },
+    /// Returns an address's unclaimed participation score in an
+    /// epoch. Defaults to the current epoch.
+    #[returns(Uint128)]
+    Score { address: String, epoch: Option<u64> },
+    /// Returns the rewards the given address may claim.
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { address: String },
+    /// Returns the rewards that have not yet been allocated to an
+    /// epoch.
+    #[returns(Uint128)]
+    RewardPool {},
+}
+
+#[cw_serde]
+pub struct EpochResponse {
+    pub epoch: u64,
+    /// The participation in the epoch and, once it has finished, its
+    /// rewards.
+    pub state: Epoch,
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    pub address: String,
+    pub pending_rewards: Uint128,
+}
@@ -0,0 +1,139 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Empty, Order, StdResult, Storage, Uint128};
+use cw_denom::CheckedDenom;
+use cw_storage_plus::{Bound, Item, Map};
+use cw_utils::Duration;
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO voting power module used to weight votes.
+    pub vp_contract: Addr,
+    /// The token rewards are paid in.
+    pub reward_denom: CheckedDenom,
+    /// The amount of rewards paid out at the end of each epoch.
+    pub rewards_per_epoch: Uint128,
+    /// The length of an epoch.
+    pub epoch_duration: Duration,
+    /// The block height or time (in seconds) at which the first
+    /// epoch started.
+    pub started_at: u64,
+    /// If true, votes score the voter's voting power. Otherwise,
+    /// every vote scores one point.
+    pub weight_by_voting_power: bool,
+}
+
+impl Config {
+    /// Returns the epoch that `block` is in.
+    pub fn epoch_at(&self, block: &BlockInfo) -> u64 {
+        match self.epoch_duration {
+            Duration::Height(h) => (block.height - self.started_at) / h,
+            Duration::Time(t) => (block.time.seconds() - self.started_at) / t,
+        }
+    }
+}
+
+/// The participation in an epoch.
+#[cw_serde]
+#[derive(Default)]
+pub struct Epoch {
+    /// The sum of the participation scores of all voters.
+    pub total_score: Uint128,
+    /// The rewards paid out to the epoch's voters. Set once the
+    /// epoch has finished.
+    pub rewards: Option<Uint128>,
+    /// The sum of the scores whose rewards have been claimed.
+    #[serde(default)]
+    pub claimed_score: Uint128,
+    /// The rewards that have been claimed.
+    #[serde(default)]
+    pub claimed_rewards: Uint128,
+}
+
+impl Epoch {
+    /// Returns the share of this epoch's rewards earned by `score`.
+    pub fn rewards_for(&self, score: Uint128) -> Uint128 {
+        self.rewards
+            .unwrap_or_default()
+            .multiply_ratio(score, self.total_score)
+    }
+
+    /// Records a claim of the rewards earned by `score`. Once every
+    /// score has been claimed, returns the rewards left over from
+    /// rounding down each voter's share.
+    pub fn claim(&mut self, score: Uint128) -> (Uint128, Option<Uint128>) {
+        let rewards = self.rewards_for(score);
+        self.claimed_score += score;
+        self.claimed_rewards += rewards;
+        let remainder = (self.claimed_score == self.total_score)
+            .then(|| self.rewards.unwrap_or_default() - self.claimed_rewards);
+        (rewards, remainder)
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The proposal modules whose vote hooks are counted.
+pub const HOOK_CALLERS: Map<&Addr, Empty> = Map::new("hook_callers");
+
+/// Rewards that have not yet been allocated to an epoch.
+pub const REWARD_POOL: Item<Uint128> = Item::new("reward_pool");
+
+/// Epochs in which at least one vote was counted. Epochs without votes
+/// are not stored and receive no rewards.
+pub const EPOCHS: Map<u64, Epoch> = Map::new("epochs");
+
+/// The first epoch that may not yet have been allocated rewards.
+pub const NEXT_UNALLOCATED_EPOCH: Item<u64> = Item::new("next_unallocated_epoch");
+
+/// Unclaimed participation scores, keyed by voter and epoch.
+pub const SCORES: Map<(&Addr, u64), Uint128> = Map::new("scores");
+
+/// Votes that have been counted, keyed by proposal module, proposal ID
+/// and voter. Used to only count the first vote on a proposal if
+/// revoting is enabled.
+pub const COUNTED_VOTES: Map<(&Addr, u64, &Addr), Empty> = Map::new("counted_votes");
+
+/// Computes rewards for finished epochs that have not yet been
+/// allocated rewards, in order. Each is allocated `rewards_per_epoch`,
+/// or the rest of the reward pool if it holds less. Returns the
+/// allocated epochs and the remaining reward pool.
+pub fn pending_allocations(
+    storage: &dyn Storage,
+    config: &Config,
+    block: &BlockInfo,
+) -> StdResult<(Vec<(u64, Epoch)>, Uint128)> {
+    let mut pool = REWARD_POOL.load(storage)?;
+    let epochs = EPOCHS
+        .range(
+            storage,
+            Some(Bound::inclusive(NEXT_UNALLOCATED_EPOCH.load(storage)?)),
+            Some(Bound::exclusive(config.epoch_at(block))),
+            Order::Ascending,
+        )
+        .map(|item| {
+            item.map(|(id, mut epoch)| {
+                let rewards = config.rewards_per_epoch.min(pool);
+                pool -= rewards;
+                epoch.rewards = Some(rewards);
+                (id, epoch)
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok((epochs, pool))
+}
+
+/// Allocates rewards to finished epochs. Called before any change to
+/// the reward pool or epochs so that allocations happen in order.
+pub fn allocate_finished_epochs(
+    storage: &mut dyn Storage,
+    config: &Config,
+    block: &BlockInfo,
+) -> StdResult<()> {
+    let (epochs, pool) = pending_allocations(storage, config, block)?;
+    for (id, epoch) in epochs {
+        EPOCHS.save(storage, id, &epoch)?;
+    }
+    REWARD_POOL.save(storage, &pool)?;
+    NEXT_UNALLOCATED_EPOCH.save(storage, &config.epoch_at(block))?;
+    Ok(())
+}
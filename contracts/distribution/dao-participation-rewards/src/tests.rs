@@ -0,0 +1,496 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+    StdResult, Uint128,
+};
+use cw4::Member;
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_utils::Duration;
+use dao_hooks::vote::VoteHookMsg;
+use dao_testing::contracts::{cw4_group_contract, dao_voting_cw4_contract};
+
+use crate::{
+    msg::{EpochResponse, ExecuteMsg, InstantiateMsg, PendingRewardsResponse, QueryMsg},
+    state::Config,
+    ContractError,
+};
+
+const DENOM: &str = "ujuno";
+const OWNER: &str = "owner";
+const PROPOSAL_MODULE: &str = "proposal_module";
+const ADDR1: &str = "addr0001";
+const ADDR2: &str = "addr0002";
+const EPOCH: u64 = 100;
+
+#[cw_serde]
+enum MockProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+#[cw_serde]
+struct MockProposal {
+    start_height: u64,
+}
+
+#[cw_serde]
+struct MockProposalResponse {
+    id: u64,
+    proposal: MockProposal,
+}
+
+/// A proposal module whose proposals all started at the height it
+/// was instantiated at.
+fn mock_proposal_module() -> Box<dyn Contract<Empty>> {
+    const START_HEIGHT: cw_storage_plus::Item<u64> = cw_storage_plus::Item::new("start_height");
+    let contract = ContractWrapper::new(
+        |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+            Ok(Response::new())
+        },
+        |deps: DepsMut, env: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+            START_HEIGHT.save(deps.storage, &env.block.height)?;
+            Ok(Response::new())
+        },
+        |deps: Deps, _: Env, msg: MockProposalQuery| -> StdResult<Binary> {
+            let MockProposalQuery::Proposal { proposal_id } = msg;
+            to_json_binary(&MockProposalResponse {
+                id: proposal_id,
+                proposal: MockProposal {
+                    start_height: START_HEIGHT.load(deps.storage)?,
+                },
+            })
+        },
+    );
+    Box::new(contract)
+}
+
+fn participation_rewards_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+/// Instantiates a cw4 voting module where ADDR1 has one unit of
+/// voting power and ADDR2 has three, and a participation rewards
+/// contract paying 100 tokens per epoch.
+fn setup_test(weight_by_voting_power: bool) -> (App, Addr) {
+    let mut app = App::default();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: OWNER.to_string(),
+        amount: coins(1_000, DENOM),
+    }))
+    .unwrap();
+
+    let cw4_group_code_id = app.store_code(cw4_group_contract());
+    let vp_code_id = app.store_code(dao_voting_cw4_contract());
+    let vp_contract = app
+        .instantiate_contract(
+            vp_code_id,
+            Addr::unchecked(OWNER),
+            &dao_voting_cw4::msg::InstantiateMsg {
+                group_contract: dao_voting_cw4::msg::GroupContract::New {
+                    cw4_group_code_id,
+                    initial_members: vec![
+                        Member {
+                            addr: ADDR1.to_string(),
+                            weight: 1,
+                        },
+                        Member {
+                            addr: ADDR2.to_string(),
+                            weight: 3,
+                        },
+                    ],
+                },
+            },
+            &[],
+            "cw4-vp",
+            None,
+        )
+        .unwrap();
+
+    let code_id = app.store_code(participation_rewards_contract());
+    let rewards = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: Some(OWNER.to_string()),
+                vp_contract: vp_contract.to_string(),
+                hook_callers: vec![PROPOSAL_MODULE.to_string()],
+                reward_denom: UncheckedDenom::Native(DENOM.to_string()),
+                rewards_per_epoch: Uint128::new(100),
+                epoch_duration: Duration::Height(EPOCH),
+                weight_by_voting_power,
+            },
+            &[],
+            "participation-rewards",
+            None,
+        )
+        .unwrap();
+
+    (app, rewards)
+}
+
+/// Instantiates a mock proposal module whose proposals start at the
+/// next height and registers it as a hook caller. Voting power is
+/// only queryable from the height after members were added.
+fn add_mock_proposal_module(app: &mut App, rewards: &Addr) -> Addr {
+    app.update_block(|b| b.height += 1);
+    let code_id = app.store_code(mock_proposal_module());
+    let proposal_module = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(OWNER),
+            &Empty {},
+            &[],
+            "proposal-module",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        rewards.clone(),
+        &ExecuteMsg::UpdateHookCallers {
+            to_add: vec![proposal_module.to_string()],
+            to_remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    proposal_module
+}
+
+fn fund(app: &mut App, rewards: &Addr, amount: u128) {
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        rewards.clone(),
+        &ExecuteMsg::Fund {},
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+}
+
+fn vote(
+    app: &mut App,
+    rewards: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    voter: &str,
+) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        rewards.clone(),
+        &ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+            proposal_id,
+            voter: voter.to_string(),
+            vote: "yes".to_string(),
+        }),
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn claim(app: &mut App, rewards: &Addr, sender: &str) -> Result<AppResponse, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        rewards.clone(),
+        &ExecuteMsg::Claim {},
+        &[],
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn next_epoch(app: &mut App) {
+    app.update_block(|b| b.height += EPOCH);
+}
+
+fn query_pending_rewards(app: &App, rewards: &Addr, address: &str) -> Uint128 {
+    let resp: PendingRewardsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            rewards,
+            &QueryMsg::PendingRewards {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    resp.pending_rewards
+}
+
+fn query_balance(app: &App, address: &str) -> Uint128 {
+    app.wrap().query_balance(address, DENOM).unwrap().amount
+}
+
+#[test]
+fn test_equal_weighting() {
+    let (mut app, rewards) = setup_test(false);
+    fund(&mut app, &rewards, 150);
+
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR1).unwrap();
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR2).unwrap();
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 2, ADDR1).unwrap();
+    // Revotes are not counted.
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR1).unwrap();
+
+    let epoch: EpochResponse = app
+        .wrap()
+        .query_wasm_smart(&rewards, &QueryMsg::Epoch { epoch: None })
+        .unwrap();
+    assert_eq!(epoch.epoch, 0);
+    assert_eq!(epoch.state.total_score, Uint128::new(3));
+    assert_eq!(epoch.state.rewards, None);
+
+    // Rewards are paid once the epoch has finished.
+    let err = claim(&mut app, &rewards, ADDR1).unwrap_err();
+    assert_eq!(err, ContractError::NoRewardsClaimable {});
+
+    next_epoch(&mut app);
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR1),
+        Uint128::new(66)
+    );
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR2),
+        Uint128::new(33)
+    );
+
+    claim(&mut app, &rewards, ADDR1).unwrap();
+    assert_eq!(query_balance(&app, ADDR1), Uint128::new(66));
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR1),
+        Uint128::zero()
+    );
+
+    // The next epoch is paid out of what remains of the pool.
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 3, ADDR2).unwrap();
+    next_epoch(&mut app);
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR2),
+        Uint128::new(83)
+    );
+    let pool: Uint128 = app
+        .wrap()
+        .query_wasm_smart(&rewards, &QueryMsg::RewardPool {})
+        .unwrap();
+    assert_eq!(pool, Uint128::zero());
+
+    claim(&mut app, &rewards, ADDR2).unwrap();
+    assert_eq!(query_balance(&app, ADDR2), Uint128::new(83));
+}
+
+#[test]
+fn test_rounding_remainder_returned_to_pool() {
+    let (mut app, rewards) = setup_test(false);
+    fund(&mut app, &rewards, 150);
+
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR1).unwrap();
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 2, ADDR1).unwrap();
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR2).unwrap();
+    next_epoch(&mut app);
+
+    let query_pool = |app: &App| -> Uint128 {
+        app.wrap()
+            .query_wasm_smart(&rewards, &QueryMsg::RewardPool {})
+            .unwrap()
+    };
+    assert_eq!(query_pool(&app), Uint128::new(50));
+
+    // The remainder is only known once every voter has claimed.
+    claim(&mut app, &rewards, ADDR1).unwrap();
+    assert_eq!(query_pool(&app), Uint128::new(50));
+
+    claim(&mut app, &rewards, ADDR2).unwrap();
+    assert_eq!(query_balance(&app, ADDR1), Uint128::new(66));
+    assert_eq!(query_balance(&app, ADDR2), Uint128::new(33));
+    assert_eq!(query_pool(&app), Uint128::new(51));
+
+    let epoch: EpochResponse = app
+        .wrap()
+        .query_wasm_smart(&rewards, &QueryMsg::Epoch { epoch: Some(0) })
+        .unwrap();
+    assert_eq!(epoch.state.claimed_score, Uint128::new(3));
+    assert_eq!(epoch.state.claimed_rewards, Uint128::new(99));
+
+    // The remainder is paid out in the next epoch.
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 3, ADDR2).unwrap();
+    next_epoch(&mut app);
+    claim(&mut app, &rewards, ADDR2).unwrap();
+    assert_eq!(query_balance(&app, ADDR2), Uint128::new(84));
+    assert_eq!(query_pool(&app), Uint128::zero());
+}
+
+#[test]
+fn test_voting_power_weighting() {
+    let (mut app, rewards) = setup_test(true);
+    let proposal_module = add_mock_proposal_module(&mut app, &rewards);
+    fund(&mut app, &rewards, 100);
+
+    vote(&mut app, &rewards, proposal_module.as_str(), 1, ADDR1).unwrap();
+    vote(&mut app, &rewards, proposal_module.as_str(), 1, ADDR2).unwrap();
+
+    let score: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &rewards,
+            &QueryMsg::Score {
+                address: ADDR2.to_string(),
+                epoch: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(score, Uint128::new(3));
+
+    next_epoch(&mut app);
+    claim(&mut app, &rewards, ADDR1).unwrap();
+    claim(&mut app, &rewards, ADDR2).unwrap();
+    assert_eq!(query_balance(&app, ADDR1), Uint128::new(25));
+    assert_eq!(query_balance(&app, ADDR2), Uint128::new(75));
+}
+
+#[test]
+fn test_voting_power_weighted_at_proposal_start() {
+    let (mut app, rewards) = setup_test(true);
+    let proposal_module = add_mock_proposal_module(&mut app, &rewards);
+    fund(&mut app, &rewards, 100);
+    app.update_block(|b| b.height += 1);
+
+    // ADDR1 gains voting power after the proposal was created, but
+    // its vote is counted with its power at the proposal's start.
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&rewards, &QueryMsg::Config {})
+        .unwrap();
+    let group: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &config.vp_contract,
+            &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec![],
+            add: vec![Member {
+                addr: ADDR1.to_string(),
+                weight: 97,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    vote(&mut app, &rewards, proposal_module.as_str(), 1, ADDR1).unwrap();
+    vote(&mut app, &rewards, proposal_module.as_str(), 1, ADDR2).unwrap();
+
+    next_epoch(&mut app);
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR1),
+        Uint128::new(25)
+    );
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR2),
+        Uint128::new(75)
+    );
+
+    // Votes from proposal modules that can not report a start height
+    // can not be weighted.
+    let err = vote(&mut app, &rewards, PROPOSAL_MODULE, 2, ADDR1).unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
+
+#[test]
+fn test_epochs_without_votes_are_not_paid() {
+    let (mut app, rewards) = setup_test(false);
+    fund(&mut app, &rewards, 100);
+
+    next_epoch(&mut app);
+    next_epoch(&mut app);
+    vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR1).unwrap();
+    next_epoch(&mut app);
+
+    assert_eq!(
+        query_pending_rewards(&app, &rewards, ADDR1),
+        Uint128::new(100)
+    );
+}
+
+#[test]
+fn test_permissions() {
+    let (mut app, rewards) = setup_test(false);
+
+    let err = vote(&mut app, &rewards, "someone", 1, ADDR1).unwrap_err();
+    assert_eq!(err, ContractError::InvalidHookSender {});
+
+    let update = ExecuteMsg::UpdateHookCallers {
+        to_add: vec!["someone".to_string()],
+        to_remove: vec![PROPOSAL_MODULE.to_string()],
+    };
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(ADDR1), rewards.clone(), &update, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    app.execute_contract(Addr::unchecked(OWNER), rewards.clone(), &update, &[])
+        .unwrap();
+    let hook_callers: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(&rewards, &QueryMsg::HookCallers {})
+        .unwrap();
+    assert_eq!(hook_callers, vec![Addr::unchecked("someone")]);
+    vote(&mut app, &rewards, "someone", 1, ADDR1).unwrap();
+    let err = vote(&mut app, &rewards, PROPOSAL_MODULE, 1, ADDR2).unwrap_err();
+    assert_eq!(err, ContractError::InvalidHookSender {});
+
+    // Only the reward denom may fund the contract.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: OWNER.to_string(),
+        amount: coins(100, "uatom"),
+    }))
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            rewards.clone(),
+            &ExecuteMsg::Fund {},
+            &coins(100, "uatom"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidFunds {});
+
+    // The owner may withdraw the unallocated pool.
+    fund(&mut app, &rewards, 100);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            rewards.clone(),
+            &ExecuteMsg::Withdraw {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        rewards.clone(),
+        &ExecuteMsg::Withdraw {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_balance(&app, OWNER), Uint128::new(1_000));
+}
@@ -55,6 +55,10 @@ pub fn query_proposal_v1(
         votes: v1_votes_to_v2(proposal.votes),
         allow_revoting: proposal.allow_revoting,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
     };
 
     (proposal_count, proposal)
@@ -84,6 +84,10 @@ pub fn query_proposal_v1(
                 votes: v1_votes_to_v2(proposal.votes),
                 allow_revoting: proposal.allow_revoting,
                 veto: None,
+                quadratic_voting: None,
+                turnout: Uint128::zero(),
+                commit_expiration: None,
+                escrow: vec![],
             })
         })
         .collect::<Result<Vec<dao_proposal_single::proposal::SingleChoiceProposal>, ContractError>>(
@@ -0,0 +1,495 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, StdResult, SubMsg, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_paginate_storage::paginate_map_values;
+use cw_utils::Duration;
+use dao_pre_propose_base::{
+    approval::ApprovalContract, error::PreProposeError, msg::ExecuteMsg as ExecuteBase,
+    state::PreProposeContract,
+};
+use dao_voting::proposal::MultipleChoiceProposeMsg as ProposeMsg;
+
+use crate::msg::{
+    ApproverProposeMessage, ApproversResponse, ExecuteExt, ExecuteMsg, InstantiateExt,
+    InstantiateMsg, ProposeMessage, ProposeMessageInternal, QueryExt, QueryMsg,
+};
+use crate::state::{Proposal, ProposalStatus};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-multiple";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
+type Approval = ApprovalContract<ProposeMsg>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
+    let approver = deps.api.addr_validate(&msg.extension.approver)?;
+    approval.approver.save(deps.storage, &approver)?;
+    approval.save_approvers(
+        deps.branch(),
+        &approver,
+        &msg.extension.approvers,
+        msg.extension.threshold.unwrap_or(1),
+    )?;
+    if let Some(expiration) = msg.extension.pending_expiration {
+        approval
+            .pending_expiration
+            .save(deps.storage, &expiration)?;
+    }
+
+    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp.add_attribute("approver", approver.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, PreProposeError> {
+    match msg {
+        ExecuteMsg::Propose { msg } => execute_propose(deps, env, info, msg),
+
+        ExecuteMsg::AddProposalSubmittedHook { address } => {
+            execute_add_approver_hook(deps, info, address)
+        }
+        ExecuteMsg::RemoveProposalSubmittedHook { address } => {
+            execute_remove_approver_hook(deps, info, address)
+        }
+
+        ExecuteMsg::Extension { msg } => match msg {
+            ExecuteExt::Approve { id } => execute_approve(deps, env, info, id),
+            ExecuteExt::Reject { id, reason } => {
+                let dao = PrePropose::default().dao.load(deps.storage)?;
+                Approval::default().execute_reject(deps, env, info, &dao, id, reason)
+            }
+            ExecuteExt::RequestChanges { id, feedback } => {
+                Approval::default().execute_request_changes(deps, env, info, id, feedback)
+            }
+            ExecuteExt::EditProposal { id, msg } => execute_edit_proposal(deps, env, info, id, msg),
+            ExecuteExt::Expire { id } => Approval::default().execute_expire(deps, env, id),
+            ExecuteExt::UpdatePendingExpiration { expiration } => {
+                execute_update_pending_expiration(deps, info, expiration)
+            }
+            ExecuteExt::UpdateApprover { address } => {
+                Approval::default().execute_update_approver(deps, info, address)
+            }
+            ExecuteExt::UpdateApprovers {
+                approvers,
+                threshold,
+            } => Approval::default().execute_update_approvers(deps, info, approvers, threshold),
+        },
+        // Default pre-propose-base behavior for all other messages
+        _ => PrePropose::default().execute(deps, env, info, msg),
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
+    let pre_propose_base = PrePropose::default();
+
+    pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
+    pre_propose_base.check_rate_limit(deps.as_ref(), &env, &info.sender)?;
+    pre_propose_base.check_roles(deps.as_ref(), &info.sender, &proposed_msgs(&msg))?;
+    check_message_templates(deps.as_ref(), &msg)?;
+
+    // Take deposit, if configured.
+    let deposit_info = pre_propose_base.paid_deposit(deps.as_ref(), &env, &info)?;
+    let deposit_messages = if let Some(ref deposit_info) = deposit_info {
+        deposit_info.check_native_deposit_paid(&info)?;
+        deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
+    } else {
+        vec![]
+    };
+
+    let approval_id = approval.advance_approval_id(deps.storage)?;
+
+    let propose_msg_internal = internalize(msg, &info.sender);
+
+    // Prepare proposal submitted hooks msg to notify approver.  Make
+    // a proposal on the approver DAO to approve this pre-proposal
+    let hooks_msgs =
+        pre_propose_base
+            .proposal_submitted_hooks
+            .prepare_hooks(deps.storage, |a| {
+                let execute_msg = WasmMsg::Execute {
+                    contract_addr: a.into_string(),
+                    msg: to_json_binary(&ExecuteBase::<ApproverProposeMessage, Empty>::Propose {
+                        msg: ApproverProposeMessage::Propose {
+                            title: propose_msg_internal.title.clone(),
+                            description: approver_description(&propose_msg_internal),
+                            approval_id,
+                        },
+                    })?,
+                    funds: vec![],
+                };
+                Ok(SubMsg::new(execute_msg))
+            })?;
+
+    // Save the proposal and its information as pending.
+    let expiration = approval.pending_expiration(deps.storage, &env)?;
+    approval.pending_proposals.save(
+        deps.storage,
+        approval_id,
+        &Proposal {
+            status: ProposalStatus::Pending {},
+            approval_id,
+            proposer: info.sender,
+            msg: propose_msg_internal,
+            deposit: deposit_info,
+            expiration,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_messages(deposit_messages)
+        .add_submessages(hooks_msgs)
+        .add_attribute("method", "pre-propose")
+        .add_attribute("id", approval_id.to_string()))
+}
+
+pub fn execute_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
+    if !approval.record_vote(deps.storage, &env, &info.sender, id, true)? {
+        return Ok(Response::default()
+            .add_attribute("method", "approval_recorded")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("approver", info.sender));
+    }
+
+    // Load proposal and send propose message to the proposal module
+    let proposal = approval.pending_proposals.may_load(deps.storage, id)?;
+    match proposal {
+        Some(proposal) => {
+            let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+
+            // Snapshot the deposit for the proposal that we're about
+            // to create.
+            let proposal_id = deps.querier.query_wasm_smart(
+                &proposal_module,
+                &dao_interface::proposal::Query::NextProposalId {},
+            )?;
+            PrePropose::default().deposits.save(
+                deps.storage,
+                proposal_id,
+                &(proposal.deposit.clone(), proposal.proposer.clone()),
+            )?;
+            PrePropose::default().record_open_proposal(
+                deps.storage,
+                &env,
+                &proposal.proposer,
+                proposal_id,
+            )?;
+
+            let propose_messsage = WasmMsg::Execute {
+                contract_addr: proposal_module.into_string(),
+                msg: to_json_binary(&ProposeMessageInternal::Propose(proposal.msg.clone()))?,
+                funds: vec![],
+            };
+
+            approval.completed_proposals.save(
+                deps.storage,
+                id,
+                &Proposal {
+                    status: ProposalStatus::Approved {
+                        created_proposal_id: proposal_id,
+                    },
+                    approval_id: proposal.approval_id,
+                    proposer: proposal.proposer,
+                    msg: proposal.msg,
+                    deposit: proposal.deposit,
+                    expiration: proposal.expiration,
+                },
+            )?;
+            approval
+                .created_proposal_to_completed_proposal
+                .save(deps.storage, proposal_id, &id)?;
+            approval.pending_proposals.remove(deps.storage, id);
+
+            Ok(Response::default()
+                .add_message(propose_messsage)
+                .add_attribute("method", "proposal_approved")
+                .add_attribute("approval_id", id.to_string())
+                .add_attribute("proposal_id", proposal_id.to_string()))
+        }
+        None => Err(PreProposeError::ProposalNotFound {}),
+    }
+}
+
+pub fn execute_edit_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
+    let mut proposal = approval.load_pending(deps.storage, &env, id)?;
+    if proposal.proposer != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+    if !matches!(proposal.status, ProposalStatus::ChangesRequested { .. }) {
+        return Err(PreProposeError::ChangesNotRequested {});
+    }
+
+    PrePropose::default().check_roles(deps.as_ref(), &info.sender, &proposed_msgs(&msg))?;
+    check_message_templates(deps.as_ref(), &msg)?;
+
+    proposal.msg = internalize(msg, &info.sender);
+    proposal.status = ProposalStatus::Pending {};
+    proposal.expiration = approval.pending_expiration(deps.storage, &env)?;
+    approval
+        .pending_proposals
+        .save(deps.storage, id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "proposal_edited")
+        .add_attribute("approval_id", id.to_string()))
+}
+
+pub fn execute_update_pending_expiration(
+    deps: DepsMut,
+    info: MessageInfo,
+    expiration: Option<Duration>,
+) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    let approver = approval.approver.load(deps.storage)?;
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    match expiration {
+        Some(expiration) => approval
+            .pending_expiration
+            .save(deps.storage, &expiration)?,
+        None => approval.pending_expiration.remove(deps.storage),
+    }
+
+    Ok(Response::default().add_attribute("method", "update_pending_expiration"))
+}
+
+/// Converts MSG into the message sent to the proposal module, with
+/// PROPOSER filled in.
+fn internalize(msg: ProposeMessage, proposer: &Addr) -> ProposeMsg {
+    match msg {
+        ProposeMessage::Propose {
+            title,
+            description,
+            choices,
+            vote,
+        } => ProposeMsg {
+            title,
+            description,
+            choices,
+            proposer: Some(proposer.to_string()),
+            vote,
+        },
+    }
+}
+
+/// Returns the messages of every choice in MSG.
+pub(crate) fn proposed_msgs(msg: &ProposeMessage) -> Vec<CosmosMsg> {
+    match msg {
+        ProposeMessage::Propose { choices, .. } => choices
+            .options
+            .iter()
+            .flat_map(|option| option.msgs.clone())
+            .collect(),
+    }
+}
+
+/// Checks the messages of each of MSG's choices against the message
+/// templates.
+fn check_message_templates(deps: Deps, msg: &ProposeMessage) -> Result<(), PreProposeError> {
+    let ProposeMessage::Propose { choices, .. } = msg;
+    for option in &choices.options {
+        PrePropose::default().check_message_templates(deps, &option.msgs)?;
+    }
+    Ok(())
+}
+
+/// The description of the proposal made to approvers for MSG. Lists
+/// the titles of MSG's choices after its description, as approvers
+/// only see the title and description of the proposal.
+fn approver_description(msg: &ProposeMsg) -> String {
+    let choices: Vec<String> = msg
+        .choices
+        .options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| format!("{}. {}", i + 1, option.title))
+        .collect();
+    format!("{}\n\nChoices:\n{}", msg.description, choices.join("\n"))
+}
+
+pub fn execute_add_approver_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, PreProposeError> {
+    let pre_propose_base = PrePropose::default();
+
+    let dao = pre_propose_base.dao.load(deps.storage)?;
+    let approver = Approval::default().approver.load(deps.storage)?;
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    let addr = deps.api.addr_validate(&address)?;
+    pre_propose_base
+        .proposal_submitted_hooks
+        .add_hook(deps.storage, addr)?;
+
+    Ok(Response::default())
+}
+
+pub fn execute_remove_approver_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, PreProposeError> {
+    let pre_propose_base = PrePropose::default();
+
+    let dao = pre_propose_base.dao.load(deps.storage)?;
+    let approver = Approval::default().approver.load(deps.storage)?;
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
+        return Err(PreProposeError::Unauthorized {});
+    }
+
+    // Validate address
+    let addr = deps.api.addr_validate(&address)?;
+
+    // remove hook
+    pre_propose_base
+        .proposal_submitted_hooks
+        .remove_hook(deps.storage, addr)?;
+
+    Ok(Response::default())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let approval = Approval::default();
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::Approver {} => to_json_binary(&approval.approver.load(deps.storage)?),
+            QueryExt::Approvers {} => {
+                let (approvers, threshold) = approval.load_approvers(deps.storage)?;
+                to_json_binary(&ApproversResponse {
+                    approvers,
+                    threshold,
+                })
+            }
+            QueryExt::PendingExpiration {} => {
+                to_json_binary(&approval.pending_expiration.may_load(deps.storage)?)
+            }
+            QueryExt::ApprovalVotes { id } => to_json_binary(
+                &approval
+                    .approval_votes
+                    .may_load(deps.storage, id)?
+                    .unwrap_or_default(),
+            ),
+            QueryExt::IsPending { id } => {
+                let pending = approval
+                    .pending_proposals
+                    .may_load(deps.storage, id)?
+                    .is_some();
+                // Force load completed proposal if not pending, throwing error
+                // if not found.
+                if !pending {
+                    approval.completed_proposals.load(deps.storage, id)?;
+                }
+
+                to_json_binary(&pending)
+            }
+            QueryExt::Proposal { id } => {
+                if let Some(pending) = approval.pending_proposals.may_load(deps.storage, id)? {
+                    to_json_binary(&pending)
+                } else {
+                    // Force load completed proposal if not pending, throwing
+                    // error if not found.
+                    to_json_binary(&approval.completed_proposals.load(deps.storage, id)?)
+                }
+            }
+            QueryExt::PendingProposal { id } => {
+                to_json_binary(&approval.pending_proposals.load(deps.storage, id)?)
+            }
+            QueryExt::PendingProposals { start_after, limit } => {
+                to_json_binary(&paginate_map_values(
+                    deps,
+                    &approval.pending_proposals,
+                    start_after,
+                    limit,
+                    Order::Ascending,
+                )?)
+            }
+            QueryExt::ReversePendingProposals {
+                start_before,
+                limit,
+            } => to_json_binary(&paginate_map_values(
+                deps,
+                &approval.pending_proposals,
+                start_before,
+                limit,
+                Order::Descending,
+            )?),
+            QueryExt::CompletedProposal { id } => {
+                to_json_binary(&approval.completed_proposals.load(deps.storage, id)?)
+            }
+            QueryExt::CompletedProposals { start_after, limit } => {
+                to_json_binary(&paginate_map_values(
+                    deps,
+                    &approval.completed_proposals,
+                    start_after,
+                    limit,
+                    Order::Ascending,
+                )?)
+            }
+            QueryExt::ReverseCompletedProposals {
+                start_before,
+                limit,
+            } => to_json_binary(&paginate_map_values(
+                deps,
+                &approval.completed_proposals,
+                start_before,
+                limit,
+                Order::Descending,
+            )?),
+            QueryExt::CompletedProposalIdForCreatedProposalId { id } => to_json_binary(
+                &approval
+                    .created_proposal_to_completed_proposal
+                    .may_load(deps.storage, id)?,
+            ),
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
@@ -0,0 +1,157 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+use dao_pre_propose_base::msg::{
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+};
+use dao_voting::{
+    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    proposal::MultipleChoiceProposeMsg as ProposeMsg,
+};
+
+#[cw_serde]
+pub enum ApproverProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        approval_id: u64,
+    },
+}
+
+#[cw_serde]
+pub enum ProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        choices: MultipleChoiceOptions,
+        vote: Option<MultipleChoiceAutoVote>,
+    },
+}
+
+#[cw_serde]
+pub struct InstantiateExt {
+    pub approver: String,
+    /// Addresses other than `approver` that may approve or reject
+    /// proposals.
+    #[serde(default)]
+    pub approvers: Vec<String>,
+    /// The number of approvals, from `approver` and `approvers`,
+    /// required to approve a proposal. A proposal is rejected once
+    /// enough approvers have rejected it that this can no longer be
+    /// reached. Defaults to one.
+    #[serde(default)]
+    pub threshold: Option<u64>,
+    /// How long proposals may remain pending before they expire and
+    /// their deposits are refunded. If `None`, pending proposals do
+    /// not expire.
+    #[serde(default)]
+    pub pending_expiration: Option<Duration>,
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Approve a proposal, only callable by approver
+    Approve { id: u64 },
+    /// Reject a proposal, only callable by approver
+    Reject {
+        id: u64,
+        /// Why the proposal was rejected.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// Return a proposal to its proposer to be changed with
+    /// `EditProposal` before it may be approved, keeping its
+    /// deposit. Only callable by an approver.
+    RequestChanges { id: u64, feedback: String },
+    /// Replace a proposal that changes have been requested for,
+    /// returning it to pending. Only callable by the proposer.
+    EditProposal { id: u64, msg: ProposeMessage },
+    /// Refund the deposit of a pending proposal that has expired and
+    /// mark it as expired. Callable by anyone.
+    Expire { id: u64 },
+    /// Updates how long proposals may remain pending before they
+    /// expire. Only applies to proposals submitted or edited
+    /// afterwards. Callable by the approver or the DAO.
+    UpdatePendingExpiration { expiration: Option<Duration> },
+    /// Updates the approver, can only be called the current approver
+    UpdateApprover { address: String },
+    /// Updates the addresses other than the approver that may approve
+    /// or reject proposals and the number of approvals required. Can
+    /// only be called by the approver.
+    UpdateApprovers {
+        approvers: Vec<String>,
+        threshold: u64,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// List the approver address
+    #[returns(cosmwasm_std::Addr)]
+    Approver {},
+    /// Every address that may approve or reject proposals, and the
+    /// number of approvals required.
+    #[returns(ApproversResponse)]
+    Approvers {},
+    /// The approvals and rejections a proposal has received.
+    #[returns(crate::state::ApprovalVotes)]
+    ApprovalVotes { id: u64 },
+    /// How long proposals may remain pending before they expire.
+    #[returns(::std::option::Option<cw_utils::Duration>)]
+    PendingExpiration {},
+    /// Return whether or not the proposal is pending
+    #[returns(bool)]
+    IsPending { id: u64 },
+    /// A proposal, pending or completed.
+    #[returns(crate::state::Proposal)]
+    Proposal { id: u64 },
+    /// A pending proposal
+    #[returns(crate::state::Proposal)]
+    PendingProposal { id: u64 },
+    /// List of proposals awaiting approval
+    #[returns(Vec<crate::state::Proposal>)]
+    PendingProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Proposal>)]
+    ReversePendingProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// A completed proposal
+    #[returns(crate::state::Proposal)]
+    CompletedProposal { id: u64 },
+    /// List of completed proposals
+    #[returns(Vec<crate::state::Proposal>)]
+    CompletedProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Proposal>)]
+    ReverseCompletedProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The completed approval ID for a created proposal ID.
+    #[returns(::std::option::Option<u64>)]
+    CompletedProposalIdForCreatedProposalId { id: u64 },
+}
+
+#[cw_serde]
+pub struct ApproversResponse {
+    pub approvers: Vec<cosmwasm_std::Addr>,
+    pub threshold: u64,
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
+
+/// Internal version of the propose message that includes the
+/// `proposer` field. The module will fill this in based on the sender
+/// of the external message.
+#[cw_serde]
+pub(crate) enum ProposeMessageInternal {
+    Propose(ProposeMsg),
+}
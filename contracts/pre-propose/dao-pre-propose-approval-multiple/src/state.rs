@@ -0,0 +1,6 @@
+use dao_pre_propose_base::approval;
+use dao_voting::proposal::MultipleChoiceProposeMsg as ProposeMsg;
+
+pub use dao_pre_propose_base::approval::{ApprovalVotes, ProposalStatus};
+
+pub type Proposal = approval::Proposal<ProposeMsg>;
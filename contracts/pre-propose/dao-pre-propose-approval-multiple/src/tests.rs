@@ -0,0 +1,432 @@
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, Uint128,
+};
+use cpm::query::ProposalResponse;
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use dao_interface::state::ProposalModule;
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    state::{MessageTemplate, ProposalRateLimit},
+};
+use dao_proposal_multiple as cpm;
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    deposit::UncheckedDepositInfo,
+    executor::ExecutorPolicy,
+    multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions, VotingStrategy},
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::PercentageThreshold,
+};
+
+use crate::state::Proposal;
+use crate::{contract::*, msg::*};
+
+// Approval itself is shared with dao-pre-propose-approval-single via
+// `dao_pre_propose_base::approval` and tested there. These tests
+// cover what is specific to multiple choice proposals.
+
+fn cw_dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cpm::contract::execute,
+        cpm::contract::instantiate,
+        cpm::contract::query,
+    )
+    .with_migrate(cpm::contract::migrate)
+    .with_reply(cpm::contract::reply);
+    Box::new(contract)
+}
+
+fn cw_pre_propose_base_proposal_single() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+fn get_default_proposal_module_instantiate(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    open_proposal_submission: bool,
+) -> cpm::msg::InstantiateMsg {
+    let pre_propose_id = app.store_code(cw_pre_propose_base_proposal_single());
+
+    cpm::msg::InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        max_voting_period: cw_utils::Duration::Time(86400),
+        min_voting_period: None,
+        executor_policy: ExecutorPolicy::Anyone {},
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    deposit_info,
+                    open_proposal_submission,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
+                    extension: InstantiateExt {
+                        approver: "approver".to_string(),
+                        approvers: vec![],
+                        threshold: None,
+                        pending_expiration: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "baby's first pre-propose module".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        veto: None,
+    }
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    proposal_multiple: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    open_proposal_submission: bool,
+) -> DefaultTestSetup {
+    let dao_proposal_multiple_id = app.store_code(cw_dao_proposal_multiple_contract());
+
+    let proposal_module_instantiate =
+        get_default_proposal_module_instantiate(app, deposit_info, open_proposal_submission);
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        dao_proposal_multiple_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_multiple = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &cpm::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    // Make sure things were set up correctly.
+    assert_eq!(
+        proposal_multiple,
+        get_proposal_module(app, pre_propose.clone())
+    );
+    assert_eq!(core_addr, get_dao(app, pre_propose.clone()));
+
+    DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    }
+}
+
+fn default_choices() -> MultipleChoiceOptions {
+    MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                title: "option 1".to_string(),
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+                requested_amount: None,
+            },
+            MultipleChoiceOption {
+                title: "option 2".to_string(),
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+                requested_amount: None,
+            },
+        ],
+    }
+}
+
+fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[Coin]) -> u64 {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: default_choices(),
+                vote: None,
+            },
+        },
+        funds,
+    )
+    .unwrap();
+
+    // Query for pending proposal and return latest id.
+    let mut pending: Vec<Proposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+
+    // Return last item in ascending list, id is first element of tuple
+    pending.pop().unwrap().approval_id
+}
+
+fn get_dao(app: &App, module: Addr) -> Addr {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::Dao {})
+        .unwrap()
+}
+
+fn get_proposal_module(app: &App, module: Addr) -> Addr {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::ProposalModule {})
+        .unwrap()
+}
+
+fn approve_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) -> u64 {
+    let res = app
+        .execute_contract(
+            Addr::unchecked(sender),
+            module,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::Approve { id: proposal_id },
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Parse attrs from approve_proposal response
+    let attrs = res.custom_attrs(res.events.len() - 1);
+    // Return ID
+    attrs[attrs.len() - 2].value.parse().unwrap()
+}
+
+#[test]
+fn test_approved_proposal_choices() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let id = approve_proposal(&mut app, pre_propose, "approver", pre_propose_id);
+
+    // The created proposal has the proposer's choices, followed by
+    // the "none of the above" option added by the proposal module.
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &cpm::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    let titles: Vec<String> = proposal
+        .proposal
+        .choices
+        .into_iter()
+        .map(|choice| choice.title)
+        .collect();
+    assert_eq!(titles, vec!["option 1", "option 2", "None of the above"]);
+}
+
+#[test]
+fn test_message_templates_per_choice() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: true,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: Some(vec![MessageTemplate::BankSend {
+                denom: "ujuno".to_string(),
+                max_amount: Uint128::new(10),
+            }]),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let bank_msg = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let propose = |app: &mut App, first: Vec<CosmosMsg>, second: Vec<CosmosMsg>| {
+        let mut choices = default_choices();
+        choices.options[0].msgs = first;
+        choices.options[1].msgs = second;
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    choices,
+                    vote: None,
+                },
+            },
+            &[],
+        )
+    };
+
+    // Only one choice is executed, so the spend limit applies to each
+    // choice separately.
+    propose(&mut app, vec![bank_msg(8)], vec![bank_msg(8)]).unwrap();
+
+    let err: PreProposeError = propose(&mut app, vec![bank_msg(8)], vec![bank_msg(5), bank_msg(6)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(11),
+            max: Uint128::new(10),
+        }
+    );
+
+    // Every choice is checked, not only the first.
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![bank_msg(1)],
+        vec![BankMsg::Burn {
+            amount: coins(1, "ujuno"),
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::MessageNotAllowed { index: 0 });
+}
+
+#[test]
+fn test_proposed_msgs() {
+    let bank_msg = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let mut choices = default_choices();
+    choices.options[0].msgs = vec![bank_msg(1), bank_msg(2)];
+    choices.options[1].msgs = vec![bank_msg(3)];
+
+    // Submission roles are checked against the messages of every
+    // choice.
+    let msgs = proposed_msgs(&ProposeMessage::Propose {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        choices,
+        vote: None,
+    });
+    assert_eq!(msgs, vec![bank_msg(1), bank_msg(2), bank_msg(3)]);
+}
+
+#[test]
+fn test_rate_limit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            rate_limit: Some(ProposalRateLimit {
+                max_open_proposals: Some(1),
+                cooldown: None,
+            }),
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Proposals count towards the limit once they are approved.
+    let pending = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approve_proposal(&mut app, pre_propose.clone(), "approver", pending);
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    choices: default_choices(),
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::TooManyOpenProposals { max: 1 });
+    make_pre_proposal(&mut app, pre_propose, "keze", &[]);
+}
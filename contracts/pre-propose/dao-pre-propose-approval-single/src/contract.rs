@@ -1,30 +1,29 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult,
-    SubMsg, WasmMsg,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_paginate_storage::paginate_map_values;
+use cw_utils::Duration;
 use dao_pre_propose_base::{
-    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
+    approval::ApprovalContract, error::PreProposeError, msg::ExecuteMsg as ExecuteBase,
+    state::PreProposeContract,
 };
-use dao_voting::deposit::DepositRefundPolicy;
 use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
 
 use crate::msg::{
-    ApproverProposeMessage, ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage,
-    ProposeMessageInternal, QueryExt, QueryMsg,
-};
-use crate::state::{
-    advance_approval_id, Proposal, ProposalStatus, APPROVER, COMPLETED_PROPOSALS,
-    CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL, PENDING_PROPOSALS,
+    ApproverProposeMessage, ApproversResponse, ExecuteExt, ExecuteMsg, InstantiateExt,
+    InstantiateMsg, ProposeMessage, ProposeMessageInternal, QueryExt, QueryMsg,
 };
+use crate::state::{Proposal, ProposalStatus};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
+type Approval = ApprovalContract<ProposeMsg>;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -33,8 +32,20 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
     let approver = deps.api.addr_validate(&msg.extension.approver)?;
-    APPROVER.save(deps.storage, &approver)?;
+    approval.approver.save(deps.storage, &approver)?;
+    approval.save_approvers(
+        deps.branch(),
+        &approver,
+        &msg.extension.approvers,
+        msg.extension.threshold.unwrap_or(1),
+    )?;
+    if let Some(expiration) = msg.extension.pending_expiration {
+        approval
+            .pending_expiration
+            .save(deps.storage, &expiration)?;
+    }
 
     let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -59,9 +70,26 @@ pub fn execute(
         }
 
         ExecuteMsg::Extension { msg } => match msg {
-            ExecuteExt::Approve { id } => execute_approve(deps, info, id),
-            ExecuteExt::Reject { id } => execute_reject(deps, info, id),
-            ExecuteExt::UpdateApprover { address } => execute_update_approver(deps, info, address),
+            ExecuteExt::Approve { id } => execute_approve(deps, env, info, id),
+            ExecuteExt::Reject { id, reason } => {
+                let dao = PrePropose::default().dao.load(deps.storage)?;
+                Approval::default().execute_reject(deps, env, info, &dao, id, reason)
+            }
+            ExecuteExt::RequestChanges { id, feedback } => {
+                Approval::default().execute_request_changes(deps, env, info, id, feedback)
+            }
+            ExecuteExt::EditProposal { id, msg } => execute_edit_proposal(deps, env, info, id, msg),
+            ExecuteExt::Expire { id } => Approval::default().execute_expire(deps, env, id),
+            ExecuteExt::UpdatePendingExpiration { expiration } => {
+                execute_update_pending_expiration(deps, info, expiration)
+            }
+            ExecuteExt::UpdateApprover { address } => {
+                Approval::default().execute_update_approver(deps, info, address)
+            }
+            ExecuteExt::UpdateApprovers {
+                approvers,
+                threshold,
+            } => Approval::default().execute_update_approvers(deps, info, approvers, threshold),
         },
         // Default pre-propose-base behavior for all other messages
         _ => PrePropose::default().execute(deps, env, info, msg),
@@ -74,35 +102,27 @@ pub fn execute_propose(
     info: MessageInfo,
     msg: ProposeMessage,
 ) -> Result<Response, PreProposeError> {
+    let approval = Approval::default();
     let pre_propose_base = PrePropose::default();
-    let config = pre_propose_base.config.load(deps.storage)?;
 
     pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
+    pre_propose_base.check_rate_limit(deps.as_ref(), &env, &info.sender)?;
+    let ProposeMessage::Propose { ref msgs, .. } = msg;
+    pre_propose_base.check_roles(deps.as_ref(), &info.sender, msgs)?;
+    pre_propose_base.check_message_templates(deps.as_ref(), msgs)?;
 
     // Take deposit, if configured.
-    let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
+    let deposit_info = pre_propose_base.paid_deposit(deps.as_ref(), &env, &info)?;
+    let deposit_messages = if let Some(ref deposit_info) = deposit_info {
         deposit_info.check_native_deposit_paid(&info)?;
         deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
     } else {
         vec![]
     };
 
-    let approval_id = advance_approval_id(deps.storage)?;
+    let approval_id = approval.advance_approval_id(deps.storage)?;
 
-    let propose_msg_internal = match msg {
-        ProposeMessage::Propose {
-            title,
-            description,
-            msgs,
-            vote,
-        } => ProposeMsg {
-            title,
-            description,
-            msgs,
-            proposer: Some(info.sender.to_string()),
-            vote,
-        },
-    };
+    let propose_msg_internal = internalize(msg, &info.sender);
 
     // Prepare proposal submitted hooks msg to notify approver.  Make
     // a proposal on the approver DAO to approve this pre-proposal
@@ -125,7 +145,8 @@ pub fn execute_propose(
             })?;
 
     // Save the proposal and its information as pending.
-    PENDING_PROPOSALS.save(
+    let expiration = approval.pending_expiration(deps.storage, &env)?;
+    approval.pending_proposals.save(
         deps.storage,
         approval_id,
         &Proposal {
@@ -133,7 +154,8 @@ pub fn execute_propose(
             approval_id,
             proposer: info.sender,
             msg: propose_msg_internal,
-            deposit: config.deposit_info,
+            deposit: deposit_info,
+            expiration,
         },
     )?;
 
@@ -146,17 +168,20 @@ pub fn execute_propose(
 
 pub fn execute_approve(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     id: u64,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
+    let approval = Approval::default();
+    if !approval.record_vote(deps.storage, &env, &info.sender, id, true)? {
+        return Ok(Response::default()
+            .add_attribute("method", "approval_recorded")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("approver", info.sender));
     }
 
     // Load proposal and send propose message to the proposal module
-    let proposal = PENDING_PROPOSALS.may_load(deps.storage, id)?;
+    let proposal = approval.pending_proposals.may_load(deps.storage, id)?;
     match proposal {
         Some(proposal) => {
             let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
@@ -172,6 +197,12 @@ pub fn execute_approve(
                 proposal_id,
                 &(proposal.deposit.clone(), proposal.proposer.clone()),
             )?;
+            PrePropose::default().record_open_proposal(
+                deps.storage,
+                &env,
+                &proposal.proposer,
+                proposal_id,
+            )?;
 
             let propose_messsage = WasmMsg::Execute {
                 contract_addr: proposal_module.into_string(),
@@ -179,7 +210,7 @@ pub fn execute_approve(
                 funds: vec![],
             };
 
-            COMPLETED_PROPOSALS.save(
+            approval.completed_proposals.save(
                 deps.storage,
                 id,
                 &Proposal {
@@ -190,10 +221,13 @@ pub fn execute_approve(
                     proposer: proposal.proposer,
                     msg: proposal.msg,
                     deposit: proposal.deposit,
+                    expiration: proposal.expiration,
                 },
             )?;
-            CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.save(deps.storage, proposal_id, &id)?;
-            PENDING_PROPOSALS.remove(deps.storage, id);
+            approval
+                .created_proposal_to_completed_proposal
+                .save(deps.storage, proposal_id, &id)?;
+            approval.pending_proposals.remove(deps.storage, id);
 
             Ok(Response::default()
                 .add_message(propose_messsage)
@@ -205,78 +239,79 @@ pub fn execute_approve(
     }
 }
 
-pub fn execute_reject(
+pub fn execute_edit_proposal(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     id: u64,
+    msg: ProposeMessage,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
+    let approval = Approval::default();
+    let mut proposal = approval.load_pending(deps.storage, &env, id)?;
+    if proposal.proposer != info.sender {
         return Err(PreProposeError::Unauthorized {});
     }
+    if !matches!(proposal.status, ProposalStatus::ChangesRequested { .. }) {
+        return Err(PreProposeError::ChangesNotRequested {});
+    }
 
-    let Proposal {
-        approval_id,
-        proposer,
-        msg,
-        deposit,
-        ..
-    } = PENDING_PROPOSALS
-        .may_load(deps.storage, id)?
-        .ok_or(PreProposeError::ProposalNotFound {})?;
-
-    COMPLETED_PROPOSALS.save(
-        deps.storage,
-        id,
-        &Proposal {
-            status: ProposalStatus::Rejected {},
-            approval_id,
-            proposer: proposer.clone(),
-            msg: msg.clone(),
-            deposit: deposit.clone(),
-        },
-    )?;
-    PENDING_PROPOSALS.remove(deps.storage, id);
-
-    let messages = if let Some(ref deposit_info) = deposit {
-        // Refund can be issued if proposal if deposits are always
-        // refunded. `OnlyPassed` and `Never` refund deposit policies
-        // do not apply here.
-        if deposit_info.refund_policy == DepositRefundPolicy::Always {
-            deposit_info.get_return_deposit_message(&proposer)?
-        } else {
-            // If the proposer doesn't get the deposit, the DAO does.
-            let dao = PrePropose::default().dao.load(deps.storage)?;
-            deposit_info.get_return_deposit_message(&dao)?
-        }
-    } else {
-        vec![]
-    };
+    let ProposeMessage::Propose { ref msgs, .. } = msg;
+    PrePropose::default().check_roles(deps.as_ref(), &info.sender, msgs)?;
+    PrePropose::default().check_message_templates(deps.as_ref(), msgs)?;
+
+    proposal.msg = internalize(msg, &info.sender);
+    proposal.status = ProposalStatus::Pending {};
+    proposal.expiration = approval.pending_expiration(deps.storage, &env)?;
+    approval
+        .pending_proposals
+        .save(deps.storage, id, &proposal)?;
 
     Ok(Response::default()
-        .add_attribute("method", "proposal_rejected")
-        .add_attribute("proposal", id.to_string())
-        .add_attribute("deposit_info", to_json_binary(&deposit)?.to_string())
-        .add_messages(messages))
+        .add_attribute("method", "proposal_edited")
+        .add_attribute("approval_id", id.to_string()))
 }
 
-pub fn execute_update_approver(
+pub fn execute_update_pending_expiration(
     deps: DepsMut,
     info: MessageInfo,
-    address: String,
+    expiration: Option<Duration>,
 ) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
+    let approval = Approval::default();
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    let approver = approval.approver.load(deps.storage)?;
+
+    // Check sender is the approver or the parent DAO
+    if approver != info.sender && dao != info.sender {
         return Err(PreProposeError::Unauthorized {});
     }
 
-    // Validate address and save new approver
-    let addr = deps.api.addr_validate(&address)?;
-    APPROVER.save(deps.storage, &addr)?;
+    match expiration {
+        Some(expiration) => approval
+            .pending_expiration
+            .save(deps.storage, &expiration)?,
+        None => approval.pending_expiration.remove(deps.storage),
+    }
 
-    Ok(Response::default())
+    Ok(Response::default().add_attribute("method", "update_pending_expiration"))
+}
+
+/// Converts MSG into the message sent to the proposal module, with
+/// PROPOSER filled in.
+fn internalize(msg: ProposeMessage, proposer: &Addr) -> ProposeMsg {
+    match msg {
+        ProposeMessage::Propose {
+            title,
+            description,
+            msgs,
+            vote,
+        } => ProposeMsg {
+            title,
+            description,
+            msgs,
+            proposer: Some(proposer.to_string()),
+            vote,
+        },
+    }
 }
 
 pub fn execute_add_approver_hook(
@@ -287,7 +322,7 @@ pub fn execute_add_approver_hook(
     let pre_propose_base = PrePropose::default();
 
     let dao = pre_propose_base.dao.load(deps.storage)?;
-    let approver = APPROVER.load(deps.storage)?;
+    let approver = Approval::default().approver.load(deps.storage)?;
 
     // Check sender is the approver or the parent DAO
     if approver != info.sender && dao != info.sender {
@@ -310,7 +345,7 @@ pub fn execute_remove_approver_hook(
     let pre_propose_base = PrePropose::default();
 
     let dao = pre_propose_base.dao.load(deps.storage)?;
-    let approver = APPROVER.load(deps.storage)?;
+    let approver = Approval::default().approver.load(deps.storage)?;
 
     // Check sender is the approver or the parent DAO
     if approver != info.sender && dao != info.sender {
@@ -330,35 +365,55 @@ pub fn execute_remove_approver_hook(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let approval = Approval::default();
     match msg {
         QueryMsg::QueryExtension { msg } => match msg {
-            QueryExt::Approver {} => to_json_binary(&APPROVER.load(deps.storage)?),
+            QueryExt::Approver {} => to_json_binary(&approval.approver.load(deps.storage)?),
+            QueryExt::Approvers {} => {
+                let (approvers, threshold) = approval.load_approvers(deps.storage)?;
+                to_json_binary(&ApproversResponse {
+                    approvers,
+                    threshold,
+                })
+            }
+            QueryExt::PendingExpiration {} => {
+                to_json_binary(&approval.pending_expiration.may_load(deps.storage)?)
+            }
+            QueryExt::ApprovalVotes { id } => to_json_binary(
+                &approval
+                    .approval_votes
+                    .may_load(deps.storage, id)?
+                    .unwrap_or_default(),
+            ),
             QueryExt::IsPending { id } => {
-                let pending = PENDING_PROPOSALS.may_load(deps.storage, id)?.is_some();
+                let pending = approval
+                    .pending_proposals
+                    .may_load(deps.storage, id)?
+                    .is_some();
                 // Force load completed proposal if not pending, throwing error
                 // if not found.
                 if !pending {
-                    COMPLETED_PROPOSALS.load(deps.storage, id)?;
+                    approval.completed_proposals.load(deps.storage, id)?;
                 }
 
                 to_json_binary(&pending)
             }
             QueryExt::Proposal { id } => {
-                if let Some(pending) = PENDING_PROPOSALS.may_load(deps.storage, id)? {
+                if let Some(pending) = approval.pending_proposals.may_load(deps.storage, id)? {
                     to_json_binary(&pending)
                 } else {
                     // Force load completed proposal if not pending, throwing
                     // error if not found.
-                    to_json_binary(&COMPLETED_PROPOSALS.load(deps.storage, id)?)
+                    to_json_binary(&approval.completed_proposals.load(deps.storage, id)?)
                 }
             }
             QueryExt::PendingProposal { id } => {
-                to_json_binary(&PENDING_PROPOSALS.load(deps.storage, id)?)
+                to_json_binary(&approval.pending_proposals.load(deps.storage, id)?)
             }
             QueryExt::PendingProposals { start_after, limit } => {
                 to_json_binary(&paginate_map_values(
                     deps,
-                    &PENDING_PROPOSALS,
+                    &approval.pending_proposals,
                     start_after,
                     limit,
                     Order::Ascending,
@@ -369,18 +424,18 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 limit,
             } => to_json_binary(&paginate_map_values(
                 deps,
-                &PENDING_PROPOSALS,
+                &approval.pending_proposals,
                 start_before,
                 limit,
                 Order::Descending,
             )?),
             QueryExt::CompletedProposal { id } => {
-                to_json_binary(&COMPLETED_PROPOSALS.load(deps.storage, id)?)
+                to_json_binary(&approval.completed_proposals.load(deps.storage, id)?)
             }
             QueryExt::CompletedProposals { start_after, limit } => {
                 to_json_binary(&paginate_map_values(
                     deps,
-                    &COMPLETED_PROPOSALS,
+                    &approval.completed_proposals,
                     start_after,
                     limit,
                     Order::Ascending,
@@ -391,14 +446,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 limit,
             } => to_json_binary(&paginate_map_values(
                 deps,
-                &COMPLETED_PROPOSALS,
+                &approval.completed_proposals,
                 start_before,
                 limit,
                 Order::Descending,
             )?),
-            QueryExt::CompletedProposalIdForCreatedProposalId { id } => {
-                to_json_binary(&CREATED_PROPOSAL_TO_COMPLETED_PROPOSAL.may_load(deps.storage, id)?)
-            }
+            QueryExt::CompletedProposalIdForCreatedProposalId { id } => to_json_binary(
+                &approval
+                    .created_proposal_to_completed_proposal
+                    .may_load(deps.storage, id)?,
+            ),
         },
         _ => PrePropose::default().query(deps, env, msg),
     }
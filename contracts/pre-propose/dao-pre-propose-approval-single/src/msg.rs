@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 use dao_pre_propose_base::msg::{
     ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
 };
@@ -27,6 +28,21 @@ pub enum ProposeMessage {
 #[cw_serde]
 pub struct InstantiateExt {
     pub approver: String,
+    /// Addresses other than `approver` that may approve or reject
+    /// proposals.
+    #[serde(default)]
+    pub approvers: Vec<String>,
+    /// The number of approvals, from `approver` and `approvers`,
+    /// required to approve a proposal. A proposal is rejected once
+    /// enough approvers have rejected it that this can no longer be
+    /// reached. Defaults to one.
+    #[serde(default)]
+    pub threshold: Option<u64>,
+    /// How long proposals may remain pending before they expire and
+    /// their deposits are refunded. If `None`, pending proposals do
+    /// not expire.
+    #[serde(default)]
+    pub pending_expiration: Option<Duration>,
 }
 
 #[cw_serde]
@@ -34,9 +50,35 @@ pub enum ExecuteExt {
     /// Approve a proposal, only callable by approver
     Approve { id: u64 },
     /// Reject a proposal, only callable by approver
-    Reject { id: u64 },
+    Reject {
+        id: u64,
+        /// Why the proposal was rejected.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// Return a proposal to its proposer to be changed with
+    /// `EditProposal` before it may be approved, keeping its
+    /// deposit. Only callable by an approver.
+    RequestChanges { id: u64, feedback: String },
+    /// Replace a proposal that changes have been requested for,
+    /// returning it to pending. Only callable by the proposer.
+    EditProposal { id: u64, msg: ProposeMessage },
+    /// Refund the deposit of a pending proposal that has expired and
+    /// mark it as expired. Callable by anyone.
+    Expire { id: u64 },
+    /// Updates how long proposals may remain pending before they
+    /// expire. Only applies to proposals submitted or edited
+    /// afterwards. Callable by the approver or the DAO.
+    UpdatePendingExpiration { expiration: Option<Duration> },
     /// Updates the approver, can only be called the current approver
     UpdateApprover { address: String },
+    /// Updates the addresses other than the approver that may approve
+    /// or reject proposals and the number of approvals required. Can
+    /// only be called by the approver.
+    UpdateApprovers {
+        approvers: Vec<String>,
+        threshold: u64,
+    },
 }
 
 #[cw_serde]
@@ -45,6 +87,16 @@ pub enum QueryExt {
     /// List the approver address
     #[returns(cosmwasm_std::Addr)]
     Approver {},
+    /// Every address that may approve or reject proposals, and the
+    /// number of approvals required.
+    #[returns(ApproversResponse)]
+    Approvers {},
+    /// The approvals and rejections a proposal has received.
+    #[returns(crate::state::ApprovalVotes)]
+    ApprovalVotes { id: u64 },
+    /// How long proposals may remain pending before they expire.
+    #[returns(::std::option::Option<cw_utils::Duration>)]
+    PendingExpiration {},
     /// Return whether or not the proposal is pending
     #[returns(bool)]
     IsPending { id: u64 },
@@ -84,6 +136,12 @@ pub enum QueryExt {
     CompletedProposalIdForCreatedProposalId { id: u64 },
 }
 
+#[cw_serde]
+pub struct ApproversResponse {
+    pub approvers: Vec<cosmwasm_std::Addr>,
+    pub threshold: u64,
+}
+
 pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
 pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
 pub type QueryMsg = QueryBase<QueryExt>;
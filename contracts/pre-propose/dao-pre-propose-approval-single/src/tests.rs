@@ -1,4 +1,4 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Decimal, Empty, Uint128};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
@@ -6,18 +6,26 @@ use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{DepositInfoResponse, DepositQuoteResponse},
+    state::{Config, ProposalRateLimit},
+};
 use dao_proposal_single::query::ProposalResponse;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositError, DepositPricing, DepositPricingScope, DepositRefundPolicy,
+        DepositToken, UncheckedDepositInfo,
+    },
+    executor::ExecutorPolicy,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
     voting::Vote,
 };
 
-use crate::state::{Proposal, ProposalStatus};
+use crate::state::{ApprovalVotes, Proposal, ProposalStatus};
 use crate::{contract::*, msg::*};
 
 fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
@@ -58,7 +66,7 @@ fn get_default_proposal_module_instantiate(
         },
         max_voting_period: cw_utils::Duration::Time(86400),
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -66,8 +74,15 @@ fn get_default_proposal_module_instantiate(
                 msg: to_json_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
                     extension: InstantiateExt {
                         approver: "approver".to_string(),
+                        approvers: vec![],
+                        threshold: None,
+                        pending_expiration: None,
                     },
                 })
                 .unwrap(),
@@ -77,6 +92,8 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        reveal_period: None,
         veto: None,
     }
 }
@@ -311,6 +328,10 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -332,6 +353,10 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -410,7 +435,10 @@ fn reject_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
         Addr::unchecked(sender),
         module,
         &ExecuteMsg::Extension {
-            msg: ExecuteExt::Reject { id: proposal_id },
+            msg: ExecuteExt::Reject {
+                id: proposal_id,
+                reason: None,
+            },
         },
         &[],
     )
@@ -452,6 +480,9 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -526,6 +557,9 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -779,6 +813,9 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -866,6 +903,9 @@ fn test_pending_proposal_queries() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -949,6 +989,9 @@ fn test_completed_proposal_queries() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1037,7 +1080,7 @@ fn test_completed_proposal_queries() {
             },
         )
         .unwrap();
-    assert_eq!(prop2.status, ProposalStatus::Rejected {});
+    assert_eq!(prop2.status, ProposalStatus::Rejected { reason: None });
 
     // Query for the pre-propose proposals
     let pre_propose_props: Vec<Proposal> = app
@@ -1091,6 +1134,9 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1127,6 +1173,9 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false, // no open proposal submission.
     );
@@ -1138,6 +1187,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                keeper: None,
             },
             &[],
         )
@@ -1183,6 +1233,9 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1217,7 +1270,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose,
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Reject { id: pre_propose_id },
+                msg: ExecuteExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1242,6 +1298,9 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1341,7 +1400,7 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -1353,10 +1412,20 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            approvers: vec![],
+                            threshold: None,
+                            pending_expiration: None,
                         },
                     })
                     .unwrap(),
@@ -1366,6 +1435,8 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
             veto: None,
         }
     };
@@ -1406,7 +1477,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -1418,10 +1489,20 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            approvers: vec![],
+                            threshold: None,
+                            pending_expiration: None,
                         },
                     })
                     .unwrap(),
@@ -1431,6 +1512,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
             veto: None,
         }
     };
@@ -1467,7 +1550,11 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1486,6 +1573,9 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -1497,9 +1587,16 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             open_proposal_submission: true,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1533,7 +1630,10 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1594,6 +1694,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1641,6 +1744,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1713,3 +1819,415 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+#[test]
+fn test_multiple_approvers() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let update_approvers = |app: &mut App, approvers: &[&str], threshold| {
+        app.execute_contract(
+            Addr::unchecked("approver"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateApprovers {
+                    approvers: approvers.iter().map(|a| a.to_string()).collect(),
+                    threshold,
+                },
+            },
+            &[],
+        )
+    };
+    let cast = |app: &mut App, sender: &str, id, approve| {
+        let msg = if approve {
+            ExecuteExt::Approve { id }
+        } else {
+            ExecuteExt::Reject { id, reason: None }
+        };
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+    };
+    let get_proposal = |app: &App, id| -> Proposal {
+        app.wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::QueryExtension {
+                    msg: QueryExt::Proposal { id },
+                },
+            )
+            .unwrap()
+    };
+
+    // Three approvers can not require four approvals.
+    let err: PreProposeError = update_approvers(&mut app, &["alice", "bob"], 4)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    // The approver is always an approver, so listing it again has no
+    // effect.
+    update_approvers(&mut app, &["bob", "alice", "approver"], 2).unwrap();
+    let approvers: ApproversResponse = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Approvers {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        approvers,
+        ApproversResponse {
+            approvers: vec![
+                Addr::unchecked("approver"),
+                Addr::unchecked("alice"),
+                Addr::unchecked("bob")
+            ],
+            threshold: 2,
+        }
+    );
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+
+    let err: PreProposeError = cast(&mut app, "ekez", id, true)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // One approval and one rejection leave the proposal pending.
+    cast(&mut app, "alice", id, true).unwrap();
+    cast(&mut app, "bob", id, false).unwrap();
+    assert_eq!(get_proposal(&app, id).status, ProposalStatus::Pending {});
+    let votes: ApprovalVotes = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApprovalVotes { id },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        votes,
+        ApprovalVotes {
+            approvals: vec![Addr::unchecked("alice")],
+            rejections: vec![Addr::unchecked("bob")],
+        }
+    );
+
+    // A second approval approves it.
+    cast(&mut app, "approver", id, true).unwrap();
+    assert_eq!(
+        get_proposal(&app, id).status,
+        ProposalStatus::Approved {
+            created_proposal_id: 1
+        }
+    );
+
+    // Two rejections mean two approvals can no longer be reached.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    cast(&mut app, "alice", id, false).unwrap();
+    assert_eq!(get_proposal(&app, id).status, ProposalStatus::Pending {});
+    cast(&mut app, "bob", id, false).unwrap();
+    assert_eq!(
+        get_proposal(&app, id).status,
+        ProposalStatus::Rejected { reason: None }
+    );
+
+    let err: PreProposeError = cast(&mut app, "approver", id, true)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+}
+
+#[test]
+fn test_request_changes_and_expiration() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+
+    let execute = |app: &mut App, sender: &str, msg| -> Result<_, PreProposeError> {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+        .map_err(|e| e.downcast().unwrap())
+    };
+    let get_proposal = |app: &App, id| -> Proposal {
+        app.wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::QueryExtension {
+                    msg: QueryExt::Proposal { id },
+                },
+            )
+            .unwrap()
+    };
+    let revised = ProposeMessage::Propose {
+        title: "revised".to_string(),
+        description: "description".to_string(),
+        msgs: vec![],
+        vote: None,
+    };
+
+    execute(
+        &mut app,
+        core_addr.as_str(),
+        ExecuteExt::UpdatePendingExpiration {
+            expiration: Some(Duration::Height(10)),
+        },
+    )
+    .unwrap();
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    execute(
+        &mut app,
+        "approver",
+        ExecuteExt::RequestChanges {
+            id,
+            feedback: "needs a budget".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        get_proposal(&app, id).status,
+        ProposalStatus::ChangesRequested {
+            feedback: "needs a budget".to_string()
+        }
+    );
+
+    // The proposal may not be approved until the proposer edits it.
+    let err = execute(&mut app, "approver", ExecuteExt::Approve { id }).unwrap_err();
+    assert_eq!(err, PreProposeError::AwaitingChanges {});
+
+    let err = execute(
+        &mut app,
+        "keze",
+        ExecuteExt::EditProposal {
+            id,
+            msg: revised.clone(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute(
+        &mut app,
+        "ekez",
+        ExecuteExt::EditProposal {
+            id,
+            msg: revised.clone(),
+        },
+    )
+    .unwrap();
+    let proposal = get_proposal(&app, id);
+    assert_eq!(proposal.status, ProposalStatus::Pending {});
+    assert_eq!(proposal.msg.title, "revised");
+    let err = execute(
+        &mut app,
+        "ekez",
+        ExecuteExt::EditProposal { id, msg: revised },
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::ChangesNotRequested {});
+
+    // Once expired, the proposal may no longer be approved, and
+    // anyone may expire it to refund its deposit.
+    let err = execute(&mut app, "someone", ExecuteExt::Expire { id }).unwrap_err();
+    assert_eq!(err, PreProposeError::PendingProposalNotExpired {});
+    app.update_block(|block| block.height += 10);
+    let err = execute(&mut app, "approver", ExecuteExt::Approve { id }).unwrap_err();
+    assert_eq!(err, PreProposeError::PendingProposalExpired {});
+    execute(&mut app, "someone", ExecuteExt::Expire { id }).unwrap();
+    assert_eq!(get_proposal(&app, id).status, ProposalStatus::Expired {});
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(20));
+
+    // Rejections may give a reason.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    execute(
+        &mut app,
+        "approver",
+        ExecuteExt::Reject {
+            id,
+            reason: Some("out of scope".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        get_proposal(&app, id).status,
+        ProposalStatus::Rejected {
+            reason: Some("out of scope".to_string())
+        }
+    );
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn test_rate_limit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            rate_limit: Some(ProposalRateLimit {
+                max_open_proposals: Some(1),
+                cooldown: None,
+            }),
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let propose_should_fail = |app: &mut App, sender: &str| -> PreProposeError {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    // Proposals count towards the limit once they are approved.
+    let pending = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let id = approve_proposal(&mut app, pre_propose.clone(), "approver", pending);
+    assert_eq!(
+        propose_should_fail(&mut app, "ekez"),
+        PreProposeError::TooManyOpenProposals { max: 1 }
+    );
+    make_pre_proposal(&mut app, pre_propose.clone(), "keze", &[]);
+
+    // Completing the proposal frees up the slot.
+    vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(&mut app, proposal_single, "ekez", id);
+    make_pre_proposal(&mut app, pre_propose, "ekez", &[]);
+}
+
+#[test]
+fn test_deposit_pricing() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: Some(DepositPricing {
+                scope: DepositPricingScope::Module,
+                increment: Decimal::one(),
+            }),
+            keeper_share: None,
+        }),
+        false,
+    );
+    let get_deposit_quote = |app: &App| -> DepositQuoteResponse {
+        app.wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::DepositQuote { proposer: None },
+            )
+            .unwrap()
+    };
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    mint_natives(&mut app, "keze", coins(30, "ujuno"));
+
+    // Pending proposals are not counted.
+    let pending = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let quote = get_deposit_quote(&app);
+    assert_eq!(quote.open_proposals, 0);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(10));
+
+    // Approved proposals are.
+    approve_proposal(&mut app, pre_propose.clone(), "approver", pending);
+    let quote = get_deposit_quote(&app);
+    assert_eq!(quote.open_proposals, 1);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(20));
+
+    // The base deposit is no longer enough.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("keze"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::InvalidDeposit {
+            actual: Uint128::new(10),
+            expected: Uint128::new(20)
+        })
+    );
+    make_pre_proposal(&mut app, pre_propose, "keze", &coins(20, "ujuno"));
+    assert_eq!(get_balance_native(&app, "keze", "ujuno"), Uint128::new(10));
+}
@@ -38,6 +38,10 @@ pub fn instantiate(
     let base_instantiate_msg = BaseInstantiateMsg {
         deposit_info: None,
         open_proposal_submission: false,
+        rate_limit: None,
+        proposal_threshold: None,
+        submission_roles: None,
+        message_templates: None,
         extension: Empty {},
     };
     // Default pre-propose-base instantiation
@@ -49,7 +53,9 @@ pub fn instantiate(
     )?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    // Validate and save the address of the pre-propose-approval-single contract
+    // Validate and save the address of the pre-propose-approval-single
+    // or pre-propose-approval-multiple contract. Both accept the same
+    // approver messages.
     let addr = deps.api.addr_validate(&msg.pre_propose_approval_contract)?;
     PRE_PROPOSE_APPROVAL_CONTRACT.save(deps.storage, &addr)?;
 
@@ -88,6 +94,7 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            ..
         } => execute_proposal_completed(deps, info, proposal_id, new_status),
         ExecuteMsg::Extension { msg } => match msg {
             ExecuteExt::ResetApprover {} => execute_reset_approver(deps, env, info),
@@ -107,6 +114,14 @@ pub fn execute_propose(
         return Err(PreProposeError::Unauthorized {});
     }
 
+    // Approval proposals never carry messages, but the DAO may still
+    // restrict submission by role, so the approval contract is held
+    // to the same checks as any other proposer.
+    let pre_propose_base = PrePropose::default();
+    let msgs: &[CosmosMsg<Empty>] = &[];
+    pre_propose_base.check_roles(deps.as_ref(), &info.sender, msgs)?;
+    pre_propose_base.check_message_templates(deps.as_ref(), msgs)?;
+
     // Get pre_prospose_id, transform proposal for the approver
     // Here we make sure that there are no messages that can be executed
     let (pre_propose_id, sanitized_msg) = match msg {
@@ -125,7 +140,7 @@ pub fn execute_propose(
         ),
     };
 
-    let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+    let proposal_module = pre_propose_base.proposal_module.load(deps.storage)?;
     let proposal_id = deps.querier.query_wasm_smart(
         &proposal_module,
         &dao_interface::proposal::Query::NextProposalId {},
@@ -164,7 +179,10 @@ pub fn execute_proposal_completed(
         Status::Closed => Some(WasmMsg::Execute {
             contract_addr: approval_contract.into_string(),
             msg: to_json_binary(&PreProposeApprovalExecuteMsg::Extension {
-                msg: ApprovalExt::Reject { id: pre_propose_id },
+                msg: ApprovalExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             })?,
             funds: vec![],
         }),
@@ -5,6 +5,7 @@ use cw_denom::UncheckedDenom;
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
 use dps::query::{ProposalListResponse, ProposalResponse};
 
+use dao_cw721_extensions::roles::{ExecuteExt as RolesExecuteExt, MetadataExt};
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 use dao_pre_propose_approval_single::{
@@ -13,11 +14,16 @@ use dao_pre_propose_approval_single::{
     },
     state::Proposal,
 };
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::DepositInfoResponse,
+    state::{Config, SubmissionRole, UncheckedSubmissionRoles},
+};
 use dao_proposal_single as dps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -85,7 +91,7 @@ fn get_proposal_module_approval_single_instantiate(
         },
         max_voting_period: cw_utils::Duration::Time(86400),
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -93,8 +99,15 @@ fn get_proposal_module_approval_single_instantiate(
                 msg: to_json_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
                     extension: InstantiateExt {
                         approver: APPROVER.to_string(),
+                        approvers: vec![],
+                        threshold: None,
+                        pending_expiration: None,
                     },
                 })
                 .unwrap(),
@@ -104,6 +117,8 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        reveal_period: None,
         veto: None,
     }
 }
@@ -122,7 +137,7 @@ fn get_proposal_module_approver_instantiate(
         },
         max_voting_period: cw_utils::Duration::Time(86400),
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -137,6 +152,8 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        reveal_period: None,
         veto: None,
     }
 }
@@ -459,6 +476,10 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -480,6 +501,10 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -583,6 +608,9 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -691,6 +719,9 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -983,6 +1014,9 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1080,6 +1114,9 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1122,6 +1159,9 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false, // no open proposal submission.
     );
@@ -1133,6 +1173,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                keeper: None,
             },
             &[],
         )
@@ -1185,6 +1226,9 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1219,7 +1263,10 @@ fn test_approval_and_rejection_permissions() {
             Addr::unchecked("nonmember"),
             pre_propose,
             &ExecuteMsg::Extension {
-                msg: ExecuteExt::Reject { id: pre_propose_id },
+                msg: ExecuteExt::Reject {
+                    id: pre_propose_id,
+                    reason: None,
+                },
             },
             &[],
         )
@@ -1251,6 +1298,9 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1316,7 +1366,11 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1342,6 +1396,9 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -1353,9 +1410,16 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             open_proposal_submission: true,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1391,7 +1455,10 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1458,6 +1525,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1507,6 +1577,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1599,6 +1672,9 @@ fn test_reset_approver() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1670,3 +1746,339 @@ fn test_reset_approver() {
         .unwrap();
     assert_eq!(approver, approver_core_addr);
 }
+
+#[test]
+fn test_approve_multiple_choice_proposal() {
+    use dao_pre_propose_approval_multiple as dpam;
+    use dao_proposal_multiple as dpm;
+    use dao_voting::multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, VotingStrategy,
+    };
+
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    // Instantiate the main DAO with pre-propose-approval-multiple.
+    let dpm_id = app.store_code(Box::new(
+        ContractWrapper::new(
+            dpm::contract::execute,
+            dpm::contract::instantiate,
+            dpm::contract::query,
+        )
+        .with_reply(dpm::contract::reply),
+    ));
+    let approval_id = app.store_code(Box::new(ContractWrapper::new(
+        dpam::contract::execute,
+        dpam::contract::instantiate,
+        dpam::contract::query,
+    )));
+    let proposal_module_instantiate = dpm::msg::InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: cw_utils::Duration::Time(86400),
+        min_voting_period: None,
+        executor_policy: ExecutorPolicy::Anyone {},
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: approval_id,
+                msg: to_json_binary(&dpam::msg::InstantiateMsg {
+                    deposit_info: None,
+                    open_proposal_submission: false,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
+                    extension: dpam::msg::InstantiateExt {
+                        approver: APPROVER.to_string(),
+                        approvers: vec![],
+                        threshold: None,
+                        pending_expiration: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "multiple choice pre-propose module, needs supervision".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        veto: None,
+    };
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        dpm_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_multiple = proposal_modules.into_iter().next().unwrap().address;
+    let pre_propose = match app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &dpm::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    // Instantiate the approver DAO, which reviews proposals made to
+    // the multiple choice approval contract.
+    let dps_id = app.store_code(cw_dao_proposal_single_contract());
+    let proposal_module_instantiate =
+        get_proposal_module_approver_instantiate(&mut app, None, false, pre_propose.to_string());
+    let approver_core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        dps_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            approver_core_addr,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_single_approver = proposal_modules.into_iter().next().unwrap().address;
+    let pre_propose_approver = match app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver.clone(),
+            &dps::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+    let approver: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &dpam::msg::QueryMsg::QueryExtension {
+                msg: dpam::msg::QueryExt::Approver {},
+            },
+        )
+        .unwrap();
+    assert_eq!(approver, pre_propose_approver);
+
+    let make_pre_proposal = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &dpam::msg::ExecuteMsg::Propose {
+                msg: dpam::msg::ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    choices: MultipleChoiceOptions {
+                        options: vec![
+                            MultipleChoiceOption {
+                                title: "option 1".to_string(),
+                                description: "multiple choice option 1".to_string(),
+                                msgs: vec![],
+                                requested_amount: None,
+                            },
+                            MultipleChoiceOption {
+                                title: "option 2".to_string(),
+                                description: "multiple choice option 2".to_string(),
+                                msgs: vec![],
+                                requested_amount: None,
+                            },
+                        ],
+                    },
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    // The approval proposal lists the choices being reviewed.
+    make_pre_proposal(&mut app);
+    let id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver.clone(),
+            &dps::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.proposal.description,
+        "description\n\nChoices:\n1. option 1\n2. option 2"
+    );
+    assert!(proposal.proposal.msgs.is_empty());
+
+    // Approving it creates the multiple choice proposal.
+    approve_proposal(&mut app, proposal_single_approver.clone(), "ekez", id);
+    let proposal: dpm::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &dpm::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    assert_eq!(proposal.proposal.choices.len(), 3);
+
+    // Rejecting it rejects the pending proposal.
+    make_pre_proposal(&mut app);
+    let id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    vote(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        id,
+        Vote::No,
+    );
+    close_proposal(&mut app, proposal_single_approver, "ekez", id);
+    let proposal: dpam::state::Proposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &dpam::msg::QueryMsg::QueryExtension {
+                msg: dpam::msg::QueryExt::Proposal { id: 2 },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.status,
+        dpam::state::ProposalStatus::Rejected { reason: None }
+    );
+}
+
+#[test]
+fn test_approver_submission_roles() {
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+        approver_core_addr,
+        proposal_single_approver,
+        pre_propose_approver,
+    } = setup_default_test(&mut app, None, false);
+
+    let cw721_id = app.store_code(dao_testing::contracts::cw721_roles_contract());
+    let cw721_roles = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked("minter"),
+            &cw721_base::InstantiateMsg {
+                name: "Working Groups".to_string(),
+                symbol: "WG".to_string(),
+                minter: "minter".to_string(),
+            },
+            &[],
+            "cw721-roles",
+            None,
+        )
+        .unwrap();
+
+    // Only holders of the approval role may create proposals in the
+    // approver DAO.
+    app.execute_contract(
+        approver_core_addr.clone(),
+        pre_propose_approver.clone(),
+        &ApproverExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: Some(UncheckedSubmissionRoles {
+                cw721_roles: cw721_roles.to_string(),
+                roles: vec![SubmissionRole {
+                    role: "approval".to_string(),
+                    allowed_msgs: None,
+                }],
+            }),
+            message_templates: Some(vec![]),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The approval contract does not hold the role, so it may not
+    // create approval proposals.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::MissingSubmissionRole {});
+
+    // Once it holds the role, approval proposals are created. They
+    // carry no messages, so they pass the message templates.
+    app.execute_contract(
+        Addr::unchecked("minter"),
+        cw721_roles,
+        &cw721_base::ExecuteMsg::<MetadataExt, RolesExecuteExt>::Mint {
+            token_id: "1".to_string(),
+            owner: pre_propose.to_string(),
+            token_uri: None,
+            extension: MetadataExt {
+                role: Some("approval".to_string()),
+                weight: 1,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    make_pre_proposal(&mut app, pre_propose, "ekez", &[]);
+    assert_eq!(get_latest_proposal_id(&app, proposal_single_approver), 1);
+}
@@ -74,23 +74,42 @@ pub fn execute(
                     choices,
                     vote,
                 },
-        } => ExecuteInternal::Propose {
-            msg: ProposeMessageInternal::Propose(ProposeMsg {
-                proposer: Some(info.sender.to_string()),
-                title,
-                description,
-                choices,
-                vote,
-            }),
-        },
+        } => {
+            let msgs: Vec<_> = choices
+                .options
+                .iter()
+                .flat_map(|option| option.msgs.clone())
+                .collect();
+            PrePropose::default().check_roles(deps.as_ref(), &info.sender, &msgs)?;
+            for option in &choices.options {
+                PrePropose::default().check_message_templates(deps.as_ref(), &option.msgs)?;
+            }
+            ExecuteInternal::Propose {
+                msg: ProposeMessageInternal::Propose(ProposeMsg {
+                    proposer: Some(info.sender.to_string()),
+                    title,
+                    description,
+                    choices,
+                    vote,
+                }),
+            }
+        }
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit,
+            proposal_threshold,
+            submission_roles,
+            message_templates,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit,
+            proposal_threshold,
+            submission_roles,
+            message_templates,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -101,9 +120,11 @@ pub fn execute(
         ExecuteBase::ProposalCompletedHook {
             proposal_id,
             new_status,
+            keeper,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            keeper,
         },
     };
 
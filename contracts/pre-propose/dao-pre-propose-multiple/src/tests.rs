@@ -12,6 +12,7 @@ use dao_proposal_multiple as cpm;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
         MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
@@ -60,7 +61,7 @@ fn get_default_proposal_module_instantiate(
         },
         max_voting_period: Duration::Time(86400),
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -68,6 +69,10 @@ fn get_default_proposal_module_instantiate(
                 msg: to_json_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -77,6 +82,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
         veto: None,
     }
 }
@@ -194,11 +200,13 @@ fn make_proposal(
                         MultipleChoiceOption {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
+                            requested_amount: None,
                             title: "title".to_string(),
                         },
                         MultipleChoiceOption {
                             description: "multiple choice option 2".to_string(),
                             msgs: vec![],
+                            requested_amount: None,
                             title: "title".to_string(),
                         },
                     ],
@@ -233,6 +241,7 @@ fn make_proposal(
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::Standard,
                 vote_count: Uint128::zero(),
                 index: 0,
@@ -241,6 +250,7 @@ fn make_proposal(
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::Standard,
                 vote_count: Uint128::zero(),
                 index: 1,
@@ -249,6 +259,7 @@ fn make_proposal(
             CheckedMultipleChoiceOption {
                 description: "None of the above".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::None,
                 vote_count: Uint128::zero(),
                 index: 2,
@@ -364,6 +375,10 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -385,6 +400,10 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -468,6 +487,9 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -540,6 +562,9 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -716,6 +741,9 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -798,6 +826,9 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -834,6 +865,9 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false, // no open proposal submission.
     );
@@ -845,6 +879,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                keeper: None,
             },
             &[],
         )
@@ -867,6 +902,7 @@ fn test_permissions() {
                         options: vec![MultipleChoiceOption {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
+                            requested_amount: None,
                             title: "title".to_string(),
                         }],
                     },
@@ -896,6 +932,9 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -974,6 +1013,7 @@ fn test_no_deposit_required_members_submission() {
                         options: vec![MultipleChoiceOption {
                             description: "multiple choice option 1".to_string(),
                             msgs: vec![],
+                            requested_amount: None,
                             title: "title".to_string(),
                         }],
                     },
@@ -1046,7 +1086,7 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -1058,8 +1098,15 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1069,6 +1116,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             veto: None,
         }
     };
@@ -1109,7 +1157,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -1121,8 +1169,15 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1132,6 +1187,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             veto: None,
         }
     };
@@ -1168,7 +1224,11 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1190,6 +1250,9 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -1201,9 +1264,16 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             open_proposal_submission: true,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1233,7 +1303,10 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1306,6 +1379,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1350,6 +1426,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
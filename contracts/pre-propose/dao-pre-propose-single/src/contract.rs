@@ -2,7 +2,7 @@ use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
 };
 use cw2::set_contract_version;
 
@@ -27,6 +27,12 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        /// Native tokens to escrow with the proposal, sent along with
+        /// any native deposit. The proposal module sends them to the
+        /// DAO if the proposal is executed, and refunds them to the
+        /// proposer otherwise.
+        #[serde(default)]
+        escrow: Vec<Coin>,
     },
 }
 
@@ -76,25 +82,37 @@ pub fn execute(
                     description,
                     msgs,
                     vote,
+                    escrow,
                 },
-        } => ExecuteInternal::Propose {
-            msg: ProposeMessageInternal::Propose(ProposeMsg {
+        } => {
+            PrePropose::default().check_roles(deps.as_ref(), &info.sender, &msgs)?;
+            PrePropose::default().check_message_templates(deps.as_ref(), &msgs)?;
+            let msg = ProposeMessageInternal::Propose(ProposeMsg {
                 // Fill in proposer based on message sender.
                 proposer: Some(info.sender.to_string()),
                 title,
                 description,
                 msgs,
                 vote,
-            }),
-        },
+            });
+            return PrePropose::default().execute_propose_with_escrow(deps, env, info, msg, escrow);
+        }
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit,
+            proposal_threshold,
+            submission_roles,
+            message_templates,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit,
+            proposal_threshold,
+            submission_roles,
+            message_templates,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -105,9 +123,11 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            keeper,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            keeper,
         },
     };
 
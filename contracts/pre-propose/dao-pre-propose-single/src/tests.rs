@@ -1,16 +1,31 @@
-use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{
+    coin, coins, from_json, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty,
+    Uint128, WasmMsg,
+};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
+use dao_cw721_extensions::roles::{ExecuteExt, MetadataExt};
 use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{DepositInfoResponse, DepositQuoteResponse},
+    state::{
+        Config, MessageTemplate, ProposalMessageType, ProposalRateLimit, ProposalThreshold,
+        SubmissionRole, UncheckedSubmissionRoles,
+    },
+};
 use dao_proposal_single as dps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositError, DepositPricing, DepositPricingScope, DepositRefundPolicy,
+        DepositToken, UncheckedDepositAlternative, UncheckedDepositInfo,
+    },
+    executor::ExecutorPolicy,
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -58,7 +73,7 @@ fn get_default_proposal_module_instantiate(
         },
         max_voting_period: cw_utils::Duration::Time(86400),
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::ModuleMayPropose {
             info: ModuleInstantiateInfo {
@@ -66,6 +81,10 @@ fn get_default_proposal_module_instantiate(
                 msg: to_json_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -75,6 +94,8 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        reveal_period: None,
         veto: None,
     }
 }
@@ -179,6 +200,17 @@ fn make_proposal(
     proposal_module: Addr,
     proposer: &str,
     funds: &[Coin],
+) -> u64 {
+    make_proposal_with_escrow(app, pre_propose, proposal_module, proposer, &[], funds)
+}
+
+fn make_proposal_with_escrow(
+    app: &mut App,
+    pre_propose: Addr,
+    proposal_module: Addr,
+    proposer: &str,
+    escrow: &[Coin],
+    funds: &[Coin],
 ) -> u64 {
     app.execute_contract(
         Addr::unchecked(proposer),
@@ -189,6 +221,7 @@ fn make_proposal(
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                escrow: escrow.to_vec(),
             },
         },
         funds,
@@ -332,6 +365,17 @@ fn get_deposit_info(app: &App, module: Addr, id: u64) -> DepositInfoResponse {
         .unwrap()
 }
 
+fn get_deposit_quote(app: &App, module: Addr, proposer: Option<&str>) -> DepositQuoteResponse {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::DepositQuote {
+                proposer: proposer.map(str::to_string),
+            },
+        )
+        .unwrap()
+}
+
 fn update_config(
     app: &mut App,
     module: Addr,
@@ -345,6 +389,10 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -366,6 +414,10 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         },
         &[],
     )
@@ -449,6 +501,9 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -513,6 +568,9 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -665,201 +723,1099 @@ fn test_cw20_failed_passed_refund() {
     )
 }
 
-// See: <https://github.com/DA0-DA0/dao-contracts/pull/465#discussion_r960092321>
 #[test]
-fn test_multiple_open_proposals() {
+fn test_deposit_alternatives() {
     let mut app = App::default();
 
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
     let DefaultTestSetup {
-        core_addr: _,
+        core_addr,
         proposal_single,
         pre_propose,
     } = setup_default_test(
         &mut app,
         Some(UncheckedDepositInfo {
             denom: DepositToken::Token {
-                denom: UncheckedDenom::Native("ujuno".to_string()),
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![UncheckedDepositAlternative {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                },
+                amount: Uint128::new(5),
+            }],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
 
-    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
-    let first_id = make_proposal(
+    // Pay the alternative native deposit.
+    mint_natives(&mut app, "ekez", coins(5, "ujuno"));
+    let native_id = make_proposal(
         &mut app,
         pre_propose.clone(),
         proposal_single.clone(),
         "ekez",
-        &coins(10, "ujuno"),
+        &coins(5, "ujuno"),
+    );
+    assert_eq!(
+        get_deposit_info(&app, pre_propose.clone(), native_id).deposit_info,
+        Some(CheckedDepositInfo {
+            denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(5),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        })
+    );
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), "ekez"),
+        Uint128::new(10)
     );
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
 
-    let second_id = make_proposal(
+    // Pay the primary cw20 deposit.
+    increase_allowance(
         &mut app,
-        pre_propose,
-        proposal_single.clone(),
         "ekez",
-        &coins(10, "ujuno"),
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
     );
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(0, balance.u128());
-
-    // Finish up the first proposal.
-    let new_status = vote(
+    let cw20_id = make_proposal(
         &mut app,
+        pre_propose.clone(),
         proposal_single.clone(),
         "ekez",
-        first_id,
-        Vote::Yes,
+        &[],
+    );
+    assert_eq!(
+        get_deposit_info(&app, pre_propose.clone(), cw20_id).deposit_info,
+        Some(CheckedDepositInfo {
+            denom: cw_denom::CheckedDenom::Cw20(cw20_address.clone()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        })
+    );
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), "ekez"),
+        Uint128::zero()
     );
-    assert_eq!(Status::Passed, new_status);
-
-    // Still zero.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(0, balance.u128());
-
-    execute_proposal(&mut app, proposal_single.clone(), "ekez", first_id);
-
-    // First proposal refunded.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
 
-    // Finish up the second proposal.
-    let new_status = vote(
+    // Deposits are refunded in the denom they were paid in.
+    vote(
         &mut app,
         proposal_single.clone(),
         "ekez",
-        second_id,
+        native_id,
         Vote::No,
     );
-    assert_eq!(Status::Rejected, new_status);
-
-    // Still zero.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
-
-    close_proposal(&mut app, proposal_single, "ekez", second_id);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", native_id);
+    vote(&mut app, proposal_single.clone(), "ekez", cw20_id, Vote::No);
+    close_proposal(&mut app, proposal_single, "ekez", cw20_id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(5));
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address, "ekez"),
+        Uint128::new(10)
+    );
 
-    // All deposits have been refunded.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(20, balance.u128());
+    // Alternatives are validated like the primary deposit.
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose,
+            &ExecuteMsg::UpdateConfig {
+                deposit_info: Some(UncheckedDepositInfo {
+                    denom: DepositToken::Token {
+                        denom: UncheckedDenom::Native("ujuno".to_string()),
+                    },
+                    amount: Uint128::new(5),
+                    refund_policy: DepositRefundPolicy::Always,
+                    alternatives: vec![UncheckedDepositAlternative {
+                        denom: DepositToken::Token {
+                            denom: UncheckedDenom::Native("uatom".to_string()),
+                        },
+                        amount: Uint128::zero(),
+                    }],
+                    pricing: None,
+                    keeper_share: None,
+                }),
+                open_proposal_submission: false,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Deposit(DepositError::ZeroDeposit));
 }
 
 #[test]
-fn test_set_version() {
+fn test_rate_limit() {
     let mut app = App::default();
-
     let DefaultTestSetup {
-        core_addr: _,
-        proposal_single: _,
+        core_addr,
+        proposal_single,
         pre_propose,
-    } = setup_default_test(
-        &mut app,
-        Some(UncheckedDepositInfo {
-            denom: DepositToken::Token {
-                denom: UncheckedDenom::Native("ujuno".to_string()),
+    } = setup_default_test(&mut app, None, false);
+
+    let update_rate_limit = |app: &mut App, rate_limit| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateConfig {
+                deposit_info: None,
+                open_proposal_submission: false,
+                rate_limit: Some(rate_limit),
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
             },
-            amount: Uint128::new(10),
-            refund_policy: DepositRefundPolicy::Always,
-        }),
-        false,
-    );
+            &[],
+        )
+    };
+    let propose = |app: &mut App, sender: &str| -> Result<u64, PreProposeError> {
+        let id: u64 = app
+            .wrap()
+            .query_wasm_smart(&proposal_single, &dps::msg::QueryMsg::NextProposalId {})
+            .unwrap();
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &[],
+        )
+        .map(|_| id)
+        .map_err(|e| e.downcast().unwrap())
+    };
 
-    let info: ContractVersion = from_json(
-        app.wrap()
-            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
-            .unwrap()
-            .unwrap(),
+    // A limit of zero open proposals would prevent all proposals.
+    let err: PreProposeError = update_rate_limit(
+        &mut app,
+        ProposalRateLimit {
+            max_open_proposals: Some(0),
+            cooldown: None,
+        },
     )
+    .unwrap_err()
+    .downcast()
     .unwrap();
-    assert_eq!(
-        ContractVersion {
-            contract: CONTRACT_NAME.to_string(),
-            version: CONTRACT_VERSION.to_string()
+    assert_eq!(err, PreProposeError::ZeroMaxOpenProposals {});
+
+    update_rate_limit(
+        &mut app,
+        ProposalRateLimit {
+            max_open_proposals: Some(1),
+            cooldown: Some(Duration::Height(10)),
         },
-        info
     )
+    .unwrap();
+
+    let first = propose(&mut app, "ekez").unwrap();
+    let cooldown = Expiration::AtHeight(app.block_info().height + 10);
+
+    // Proposers may not exceed their open proposal limit, but other
+    // proposers are unaffected.
+    assert_eq!(
+        propose(&mut app, "ekez").unwrap_err(),
+        PreProposeError::TooManyOpenProposals { max: 1 }
+    );
+    propose(&mut app, "keze").unwrap();
+
+    // Completing the proposal frees up a slot, but the proposer
+    // must still wait out their cooldown.
+    vote(&mut app, proposal_single.clone(), "ekez", first, Vote::No);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", first);
+    assert_eq!(
+        propose(&mut app, "ekez").unwrap_err(),
+        PreProposeError::ProposalCooldown {
+            expiration: cooldown
+        }
+    );
+
+    app.update_block(|block| block.height += 10);
+    propose(&mut app, "ekez").unwrap();
 }
 
 #[test]
-fn test_permissions() {
+fn test_proposal_threshold() {
     let mut app = App::default();
-
     let DefaultTestSetup {
         core_addr,
-        proposal_single: _,
+        proposal_single,
         pre_propose,
-    } = setup_default_test(
-        &mut app,
-        Some(UncheckedDepositInfo {
-            denom: DepositToken::Token {
-                denom: UncheckedDenom::Native("ujuno".to_string()),
-            },
-            amount: Uint128::new(10),
-            refund_policy: DepositRefundPolicy::Always,
-        }),
-        false, // no open proposal submission.
-    );
+    } = setup_default_test(&mut app, None, false);
 
-    let err: PreProposeError = app
-        .execute_contract(
-            core_addr,
+    let update_threshold = |app: &mut App, proposal_threshold| {
+        app.execute_contract(
+            core_addr.clone(),
             pre_propose.clone(),
-            &ExecuteMsg::ProposalCompletedHook {
-                proposal_id: 1,
-                new_status: Status::Closed,
+            &ExecuteMsg::UpdateConfig {
+                deposit_info: None,
+                open_proposal_submission: false,
+                rate_limit: None,
+                proposal_threshold: Some(proposal_threshold),
+                submission_roles: None,
+                message_templates: None,
             },
             &[],
         )
-        .unwrap_err()
-        .downcast()
-        .unwrap();
-    assert_eq!(err, PreProposeError::NotModule {});
-
-    // Non-members may not propose when open_propose_submission is
-    // disabled.
-    let err: PreProposeError = app
-        .execute_contract(
-            Addr::unchecked("nonmember"),
-            pre_propose,
+    };
+    let propose = |app: &mut App, sender: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
             &ExecuteMsg::Propose {
                 msg: ProposeMessage::Propose {
-                    title: "I would like to join the DAO".to_string(),
-                    description: "though, I am currently not a member.".to_string(),
+                    title: "title".to_string(),
+                    description: "description".to_string(),
                     msgs: vec![],
                     vote: None,
+                    escrow: vec![],
                 },
             },
             &[],
         )
-        .unwrap_err()
-        .downcast()
+    };
+
+    let err: PreProposeError = update_threshold(
+        &mut app,
+        ProposalThreshold::Percentage {
+            percent: Decimal::percent(101),
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::UnreachableProposalThreshold {});
+    let err: PreProposeError = update_threshold(
+        &mut app,
+        ProposalThreshold::AbsoluteCount {
+            count: Uint128::zero(),
+        },
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::ZeroProposalThreshold {});
+
+    // ekez has 9 voting power and keze has 8.
+    update_threshold(
+        &mut app,
+        ProposalThreshold::AbsoluteCount {
+            count: Uint128::new(9),
+        },
+    )
+    .unwrap();
+    let err: PreProposeError = propose(&mut app, "keze").unwrap_err().downcast().unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::BelowProposalThreshold {
+            power: Uint128::new(8),
+            required: Uint128::new(9)
+        }
+    );
+    propose(&mut app, "ekez").unwrap();
+
+    // 60% of the total voting power of 17.
+    update_threshold(
+        &mut app,
+        ProposalThreshold::Percentage {
+            percent: Decimal::percent(60),
+        },
+    )
+    .unwrap();
+    let err: PreProposeError = propose(&mut app, "ekez").unwrap_err().downcast().unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::BelowProposalThreshold {
+            power: Uint128::new(9),
+            required: Uint128::new(10)
+        }
+    );
+
+    update_threshold(
+        &mut app,
+        ProposalThreshold::Percentage {
+            percent: Decimal::percent(50),
+        },
+    )
+    .unwrap();
+    propose(&mut app, "keze").unwrap();
+
+    let proposals: dps::query::ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dps::msg::QueryMsg::ListProposals {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
-    assert_eq!(err, PreProposeError::NotMember {})
+    assert_eq!(proposals.proposals.len(), 2);
 }
 
 #[test]
-fn test_propose_open_proposal_submission() {
+fn test_submission_roles() {
     let mut app = App::default();
     let DefaultTestSetup {
-        core_addr: _,
+        core_addr,
         proposal_single,
         pre_propose,
-    } = setup_default_test(
-        &mut app,
-        Some(UncheckedDepositInfo {
-            denom: DepositToken::Token {
-                denom: UncheckedDenom::Native("ujuno".to_string()),
+    } = setup_default_test(&mut app, None, true);
+
+    let cw721_id = app.store_code(dao_testing::contracts::cw721_roles_contract());
+    let cw721_roles = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked("minter"),
+            &cw721_base::InstantiateMsg {
+                name: "Working Groups".to_string(),
+                symbol: "WG".to_string(),
+                minter: "minter".to_string(),
             },
-            amount: Uint128::new(10),
-            refund_policy: DepositRefundPolicy::Always,
-        }),
-        true, // yes, open proposal submission.
-    );
-
+            &[],
+            "cw721-roles",
+            None,
+        )
+        .unwrap();
+    for (token_id, owner, role) in [("1", "ekez", "treasury"), ("2", "keze", "grants")] {
+        app.execute_contract(
+            Addr::unchecked("minter"),
+            cw721_roles.clone(),
+            &cw721_base::ExecuteMsg::<MetadataExt, ExecuteExt>::Mint {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: MetadataExt {
+                    role: Some(role.to_string()),
+                    weight: 1,
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let update_roles = |app: &mut App, roles| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateConfig {
+                deposit_info: None,
+                open_proposal_submission: true,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: Some(UncheckedSubmissionRoles {
+                    cw721_roles: cw721_roles.to_string(),
+                    roles,
+                }),
+                message_templates: None,
+            },
+            &[],
+        )
+    };
+    let propose = |app: &mut App, sender: &str, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs,
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &[],
+        )
+    };
+
+    let err: PreProposeError = update_roles(&mut app, vec![])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NoSubmissionRoles {});
+
+    update_roles(
+        &mut app,
+        vec![
+            SubmissionRole {
+                role: "treasury".to_string(),
+                allowed_msgs: None,
+            },
+            SubmissionRole {
+                role: "grants".to_string(),
+                allowed_msgs: Some(vec![ProposalMessageType::Wasm]),
+            },
+        ],
+    )
+    .unwrap();
+
+    let bank_msg: CosmosMsg = BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into();
+    let wasm_msg: CosmosMsg = WasmMsg::Execute {
+        contract_addr: "grants".to_string(),
+        msg: to_json_binary(&Empty {}).unwrap(),
+        funds: vec![],
+    }
+    .into();
+
+    // Addresses without a role may not propose, even though
+    // submission is otherwise open.
+    let err: PreProposeError = propose(&mut app, "someone", vec![])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::MissingSubmissionRole {});
+
+    // The grants role may only propose wasm messages.
+    let err: PreProposeError = propose(&mut app, "keze", vec![wasm_msg.clone(), bank_msg.clone()])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::MessageTypeNotAllowed {
+            msg_type: ProposalMessageType::Bank
+        }
+    );
+    propose(&mut app, "keze", vec![wasm_msg.clone()]).unwrap();
+
+    // The treasury role may propose anything.
+    propose(&mut app, "ekez", vec![wasm_msg, bank_msg]).unwrap();
+
+    let proposals: dps::query::ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dps::msg::QueryMsg::ListProposals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals.len(), 2);
+}
+
+#[test]
+fn test_deposit_pricing() {
+    let mut app = App::default();
+
+    let deposit_info = |scope| UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::Always,
+        alternatives: vec![],
+        pricing: Some(DepositPricing {
+            scope,
+            increment: Decimal::one(),
+        }),
+        keeper_share: None,
+    };
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(deposit_info(DepositPricingScope::Module)),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(100, "ujuno"));
+    mint_natives(&mut app, "keze", coins(100, "ujuno"));
+
+    let quote = get_deposit_quote(&app, pre_propose.clone(), None);
+    assert_eq!(quote.open_proposals, 0);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(10));
+
+    let first = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let quote = get_deposit_quote(&app, pre_propose.clone(), Some("keze"));
+    assert_eq!(quote.open_proposals, 1);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(20));
+
+    // The base deposit is no longer enough.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("keze"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::InvalidDeposit {
+            actual: Uint128::new(10),
+            expected: Uint128::new(20)
+        })
+    );
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "keze",
+        &coins(20, "ujuno"),
+    );
+
+    // Completed proposals are no longer counted.
+    vote(&mut app, proposal_single.clone(), "ekez", first, Vote::No);
+    close_proposal(&mut app, proposal_single, "ekez", first);
+    let quote = get_deposit_quote(&app, pre_propose.clone(), None);
+    assert_eq!(quote.open_proposals, 1);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(20));
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(100));
+
+    // Deposits may instead be priced by the proposer's open
+    // proposals.
+    update_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(deposit_info(DepositPricingScope::Proposer)),
+        false,
+    );
+    let quote = get_deposit_quote(&app, pre_propose.clone(), Some("ekez"));
+    assert_eq!(quote.open_proposals, 0);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(10));
+    let quote = get_deposit_quote(&app, pre_propose, Some("keze"));
+    assert_eq!(quote.open_proposals, 1);
+    assert_eq!(quote.deposit_info.unwrap().amount, Uint128::new(20));
+}
+
+#[test]
+fn test_escrowed_funds() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", vec![coin(15, "uatom"), coin(40, "ujuno")]);
+
+    // The escrow is sent along with the deposit and split from it,
+    // even when they share a denom.
+    let passed = make_proposal_with_escrow(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[coin(5, "uatom"), coin(5, "ujuno")],
+        &[coin(5, "uatom"), coin(15, "ujuno")],
+    );
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal {
+                proposal_id: passed,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        proposal.proposal.escrow,
+        vec![coin(5, "uatom"), coin(5, "ujuno")]
+    );
+    let rejected = make_proposal_with_escrow(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[coin(5, "uatom")],
+        &[coin(5, "uatom"), coin(10, "ujuno")],
+    );
+
+    // The deposit must still be paid in full, and escrowed funds
+    // don't count towards it.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: coins(5, "ujuno"),
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::InvalidDeposit {
+            actual: Uint128::new(5),
+            expected: Uint128::new(10)
+        })
+    );
+
+    // The escrow must be sent.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: coins(5, "uatom"),
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::EscrowNotSent {
+            denom: "uatom".to_string(),
+            amount: Uint128::new(5)
+        }
+    );
+
+    // Funds that are neither escrow nor deposit are rejected.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose,
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &[coin(5, "uatom"), coin(10, "ujuno")],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        PreProposeError::Deposit(DepositError::Payment(_))
+    ));
+
+    // Executing a proposal sends its escrow to the DAO.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", passed, Vote::Yes);
+    assert_eq!(new_status, Status::Passed);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", passed);
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "uatom"),
+        Uint128::new(5)
+    );
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(25));
+
+    // Closing a proposal refunds its escrow to the proposer.
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        rejected,
+        Vote::No,
+    );
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", rejected);
+    assert_eq!(get_balance_native(&app, "ekez", "uatom"), Uint128::new(10));
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(35));
+    assert_eq!(
+        get_balance_native(&app, proposal_single.as_str(), "uatom"),
+        Uint128::zero()
+    );
+}
+
+#[test]
+fn test_escrow_overlaps_deposit_alternative() {
+    let mut app = App::default();
+
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![UncheckedDepositAlternative {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                },
+                amount: Uint128::new(5),
+            }],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+
+    // Escrowing the alternative's denom doesn't select the
+    // alternative, so the cw20 deposit is taken and the escrow is
+    // forwarded in full.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
+    );
+    let cw20_id = make_proposal_with_escrow(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(7, "ujuno"),
+        &coins(7, "ujuno"),
+    );
+    assert_eq!(
+        get_deposit_info(&app, pre_propose.clone(), cw20_id)
+            .deposit_info
+            .unwrap()
+            .denom,
+        cw_denom::CheckedDenom::Cw20(cw20_address.clone())
+    );
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal {
+                proposal_id: cw20_id,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.escrow, coins(7, "ujuno"));
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address, "ekez"),
+        Uint128::zero()
+    );
+
+    // Paying the alternative alongside an escrow in the same denom
+    // takes only the alternative's amount as the deposit.
+    let native_id = make_proposal_with_escrow(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(7, "ujuno"),
+        &coins(12, "ujuno"),
+    );
+    assert_eq!(
+        get_deposit_info(&app, pre_propose.clone(), native_id).deposit_info,
+        Some(CheckedDepositInfo {
+            denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(5),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        })
+    );
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_single,
+            &dps::msg::QueryMsg::Proposal {
+                proposal_id: native_id,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.escrow, coins(7, "ujuno"));
+    assert_eq!(
+        get_balance_native(&app, pre_propose.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+    assert_eq!(
+        get_balance_native(&app, proposal_single.as_str(), "ujuno"),
+        Uint128::new(14)
+    );
+}
+
+// See: <https://github.com/DA0-DA0/dao-contracts/pull/465#discussion_r960092321>
+#[test]
+fn test_multiple_open_proposals() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let first_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    let second_id = make_proposal(
+        &mut app,
+        pre_propose,
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    // Finish up the first proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        first_id,
+        Vote::Yes,
+    );
+    assert_eq!(Status::Passed, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", first_id);
+
+    // First proposal refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    // Finish up the second proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        second_id,
+        Vote::No,
+    );
+    assert_eq!(Status::Rejected, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    close_proposal(&mut app, proposal_single, "ekez", second_id);
+
+    // All deposits have been refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(20, balance.u128());
+}
+
+#[test]
+fn test_set_version() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+
+    let info: ContractVersion = from_json(
+        app.wrap()
+            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
+            .unwrap()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string()
+        },
+        info
+    )
+}
+
+#[test]
+fn test_permissions() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false, // no open proposal submission.
+    );
+
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose.clone(),
+            &ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+                keeper: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotModule {});
+
+    // Non-members may not propose when open_propose_submission is
+    // disabled.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose,
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "I would like to join the DAO".to_string(),
+                    description: "though, I am currently not a member.".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotMember {})
+}
+
+#[test]
+fn test_propose_open_proposal_submission() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        }),
+        true, // yes, open proposal submission.
+    );
+
     // Non-member proposes.
     mint_natives(&mut app, "nonmember", coins(10, "ujuno"));
     let id = make_proposal(
@@ -920,6 +1876,7 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    escrow: vec![],
                 },
             },
             &[],
@@ -982,7 +1939,7 @@ fn test_instantiate_with_zero_native_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -994,8 +1951,15 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1005,6 +1969,8 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
             veto: None,
         }
     };
@@ -1045,7 +2011,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
             },
             max_voting_period: Duration::Time(86400),
             min_voting_period: None,
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             pre_propose_info: PreProposeInfo::ModuleMayPropose {
                 info: ModuleInstantiateInfo {
@@ -1057,8 +2023,15 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
                         }),
                         open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1068,6 +2041,8 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
             veto: None,
         }
     };
@@ -1104,7 +2079,11 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1126,6 +2105,9 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -1137,9 +2119,16 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             open_proposal_submission: true,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -1169,7 +2158,10 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             proposer: Addr::unchecked("ekez"),
         }
@@ -1230,6 +2222,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1274,6 +2269,9 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1345,6 +2343,76 @@ fn test_withdraw() {
     assert_eq!(balance, Uint128::new(30));
 }
 
+#[test]
+fn test_withdraw_alternative_deposits() {
+    let mut app = App::default();
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![UncheckedDepositAlternative {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native("ujuno".to_string()),
+                },
+                amount: Uint128::new(5),
+            }],
+            pricing: None,
+            keeper_share: None,
+        }),
+        false,
+    );
+
+    // Put both the primary and the alternative deposit tokens in the
+    // module.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
+    );
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    mint_natives(&mut app, "ekez", coins(5, "ujuno"));
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single,
+        "ekez",
+        &coins(5, "ujuno"),
+    );
+
+    // Withdrawing with no specified denom withdraws every accepted
+    // deposit token.
+    withdraw(&mut app, pre_propose.clone(), core_addr.as_str(), None);
+    assert_eq!(
+        get_balance_cw20(&app, &cw20_address, core_addr.as_str()),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+
+    let err = withdraw_should_fail(&mut app, pre_propose, core_addr.as_str(), None);
+    assert_eq!(err, PreProposeError::NothingToWithdraw {});
+}
+
 #[test]
 fn test_hook_management() {
     let app = &mut App::default();
@@ -1362,3 +2430,177 @@ fn test_hook_management() {
     let hooks = query_hooks(app, pre_propose).hooks;
     assert_eq!(hooks, vec!["two".to_string()])
 }
+
+#[test]
+fn test_message_templates() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    let update_templates = |app: &mut App, templates| {
+        app.execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::UpdateConfig {
+                deposit_info: None,
+                open_proposal_submission: true,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: templates,
+            },
+            &[],
+        )
+    };
+    let propose = |app: &mut App, msgs: Vec<CosmosMsg>| {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs,
+                    vote: None,
+                    escrow: vec![],
+                },
+            },
+            &[],
+        )
+    };
+    let bank_msg = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let wasm_msg = |variant: &str, funds: Vec<Coin>| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: "grants".to_string(),
+            msg: format!(r#"{{"{variant}":{{}}}}"#).into_bytes().into(),
+            funds,
+        }
+        .into()
+    };
+
+    // Template contracts must be valid addresses.
+    update_templates(
+        &mut app,
+        Some(vec![MessageTemplate::WasmExecute {
+            contract: "INVALID".to_string(),
+            variants: None,
+        }]),
+    )
+    .unwrap_err();
+
+    update_templates(
+        &mut app,
+        Some(vec![
+            MessageTemplate::BankSend {
+                denom: "ujuno".to_string(),
+                max_amount: Uint128::new(10),
+            },
+            MessageTemplate::WasmExecute {
+                contract: "grants".to_string(),
+                variants: Some(vec!["transfer".to_string()]),
+            },
+        ]),
+    )
+    .unwrap();
+
+    propose(&mut app, vec![bank_msg(5), wasm_msg("transfer", vec![])]).unwrap();
+
+    // The spend limit applies to the proposal's messages in total,
+    // including funds sent with wasm executes.
+    let err: PreProposeError = propose(&mut app, vec![bank_msg(5), bank_msg(10)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(15),
+            max: Uint128::new(10),
+        }
+    );
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![bank_msg(5), wasm_msg("transfer", coins(6, "ujuno"))],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(11),
+            max: Uint128::new(10),
+        }
+    );
+
+    // Denoms without a template may not be spent at all.
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(1, "uatom"),
+        }
+        .into()],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::SpendLimitExceeded {
+            denom: "uatom".to_string(),
+            amount: Uint128::new(1),
+            max: Uint128::zero(),
+        }
+    );
+
+    // Executes must use an allowed variant.
+    let err: PreProposeError = propose(&mut app, vec![wasm_msg("burn", vec![])])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::MessageNotAllowed { index: 0 });
+
+    // Messages matching no template are rejected.
+    let err: PreProposeError = propose(
+        &mut app,
+        vec![
+            bank_msg(1),
+            BankMsg::Burn {
+                amount: coins(1, "ujuno"),
+            }
+            .into(),
+        ],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(err, PreProposeError::MessageNotAllowed { index: 1 });
+
+    // Removing the templates allows any message again.
+    update_templates(&mut app, None).unwrap();
+    propose(&mut app, vec![wasm_msg("burn", vec![])]).unwrap();
+
+    let proposals: dps::query::ProposalListResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dps::msg::QueryMsg::ListProposals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposals.proposals.len(), 2);
+}
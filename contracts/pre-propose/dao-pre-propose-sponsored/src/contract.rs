@@ -0,0 +1,411 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, SubMsg,
+    WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_denom::CheckedDenom;
+use cw_paginate_storage::paginate_map_values;
+use cw_utils::{nonpayable, Duration};
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
+use dao_voting::{
+    deposit::{CheckedDepositInfo, DepositError},
+    proposal::SingleChoiceProposeMsg as ProposeMsg,
+};
+
+use crate::msg::{
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, ProposeMessageInternal,
+    QueryExt, QueryMsg,
+};
+use crate::state::{
+    advance_draft_id, Draft, DraftStatus, SponsorshipConfig, CLOSED_DRAFTS, OPEN_DRAFTS,
+    SPONSORSHIP,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-sponsored";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, PreProposeError> {
+    save_sponsorship(
+        deps.branch(),
+        msg.extension.sponsors_required.unwrap_or(1),
+        msg.extension.sponsor_pays_deposit,
+        msg.extension.draft_expiration,
+    )?;
+
+    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, PreProposeError> {
+    match msg {
+        ExecuteMsg::Propose { msg } => execute_propose(deps, env, info, msg),
+
+        ExecuteMsg::Extension { msg } => match msg {
+            ExecuteExt::Sponsor { id } => execute_sponsor(deps, env, info, id),
+            ExecuteExt::Expire { id } => execute_expire(deps, env, id),
+            ExecuteExt::UpdateSponsorship {
+                sponsors_required,
+                sponsor_pays_deposit,
+                draft_expiration,
+            } => execute_update_sponsorship(
+                deps,
+                info,
+                sponsors_required,
+                sponsor_pays_deposit,
+                draft_expiration,
+            ),
+        },
+        // Default pre-propose-base behavior for all other messages
+        _ => PrePropose::default().execute(deps, env, info, msg),
+    }
+}
+
+/// Submits a draft. Anyone may submit a draft, as it only becomes a
+/// proposal once it has been sponsored by members.
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let pre_propose_base = PrePropose::default();
+    let sponsorship = SPONSORSHIP.load(deps.storage)?;
+
+    pre_propose_base.check_rate_limit(deps.as_ref(), &env, &info.sender)?;
+    let ProposeMessage::Propose {
+        ref msgs, ref vote, ..
+    } = msg;
+    // The proposal is created by the last sponsor, at which point the
+    // proposer may no longer have the voting power to cast a vote.
+    if vote.is_some() {
+        return Err(PreProposeError::DraftVote {});
+    }
+    pre_propose_base.check_message_templates(deps.as_ref(), msgs)?;
+
+    // Take deposit from the proposer, unless sponsors pay it.
+    let deposit_info = if sponsorship.sponsor_pays_deposit {
+        None
+    } else {
+        pre_propose_base.paid_deposit(deps.as_ref(), &env, &info)?
+    };
+    check_deposit_funds(&info, &deposit_info)?;
+    let deposit_messages = if let Some(ref deposit_info) = deposit_info {
+        deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
+    } else {
+        vec![]
+    };
+
+    let id = advance_draft_id(deps.storage)?;
+    OPEN_DRAFTS.save(
+        deps.storage,
+        id,
+        &Draft {
+            status: DraftStatus::Open {},
+            id,
+            proposer: info.sender.clone(),
+            msg: internalize(msg, &info),
+            sponsors: vec![],
+            deposit: deposit_info,
+            expiration: sponsorship.draft_expiration.after(&env.block),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_messages(deposit_messages)
+        .add_attribute("method", "submit_draft")
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_sponsor(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, PreProposeError> {
+    let pre_propose_base = PrePropose::default();
+
+    let mut draft = OPEN_DRAFTS
+        .may_load(deps.storage, id)?
+        .ok_or(PreProposeError::ProposalNotFound {})?;
+    if draft.expiration.is_expired(&env.block) {
+        return Err(PreProposeError::PendingProposalExpired {});
+    }
+
+    // Sponsors must be allowed to create the proposal themselves.
+    pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
+    pre_propose_base.check_roles(deps.as_ref(), &info.sender, &draft.msg.msgs)?;
+    pre_propose_base.check_message_templates(deps.as_ref(), &draft.msg.msgs)?;
+
+    if draft.sponsors.contains(&info.sender) {
+        return Err(PreProposeError::AlreadySponsored {});
+    }
+    draft.sponsors.push(info.sender.clone());
+
+    let sponsorship = SPONSORSHIP.load(deps.storage)?;
+    if (draft.sponsors.len() as u64) < sponsorship.sponsors_required {
+        nonpayable(&info).map_err(DepositError::from)?;
+        OPEN_DRAFTS.save(deps.storage, id, &draft)?;
+        return Ok(Response::default()
+            .add_attribute("method", "draft_sponsored")
+            .add_attribute("id", id.to_string())
+            .add_attribute("sponsor", info.sender));
+    }
+
+    // If the proposer did not pay a deposit when submitting the
+    // draft, the sponsor creating the proposal pays it.
+    let (deposit_info, depositor, deposit_messages) = match draft.deposit {
+        Some(ref deposit_info) => {
+            nonpayable(&info).map_err(DepositError::from)?;
+            (Some(deposit_info.clone()), draft.proposer.clone(), vec![])
+        }
+        None if sponsorship.sponsor_pays_deposit => {
+            let deposit_info = pre_propose_base.paid_deposit(deps.as_ref(), &env, &info)?;
+            check_deposit_funds(&info, &deposit_info)?;
+            let deposit_messages = if let Some(ref deposit_info) = deposit_info {
+                deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
+            } else {
+                vec![]
+            };
+            (deposit_info, info.sender.clone(), deposit_messages)
+        }
+        None => {
+            nonpayable(&info).map_err(DepositError::from)?;
+            (None, draft.proposer.clone(), vec![])
+        }
+    };
+
+    // Snapshot the deposit for the proposal that we're about to
+    // create.
+    let proposal_module = pre_propose_base.proposal_module.load(deps.storage)?;
+    let proposal_id = deps.querier.query_wasm_smart(
+        &proposal_module,
+        &dao_interface::proposal::Query::NextProposalId {},
+    )?;
+    pre_propose_base
+        .deposits
+        .save(deps.storage, proposal_id, &(deposit_info, depositor))?;
+    pre_propose_base.record_open_proposal(deps.storage, &env, &draft.proposer, proposal_id)?;
+
+    let msg = ProposeMessageInternal::Propose(draft.msg.clone());
+    let propose_messsage = WasmMsg::Execute {
+        contract_addr: proposal_module.into_string(),
+        msg: to_json_binary(&msg)?,
+        funds: vec![],
+    };
+    let hooks_msgs =
+        pre_propose_base
+            .proposal_submitted_hooks
+            .prepare_hooks(deps.storage, |a| {
+                let execute = WasmMsg::Execute {
+                    contract_addr: a.into_string(),
+                    msg: to_json_binary(&msg)?,
+                    funds: vec![],
+                };
+                Ok(SubMsg::new(execute))
+            })?;
+
+    OPEN_DRAFTS.remove(deps.storage, id);
+    CLOSED_DRAFTS.save(
+        deps.storage,
+        id,
+        &Draft {
+            status: DraftStatus::Sponsored { proposal_id },
+            ..draft
+        },
+    )?;
+
+    Ok(Response::default()
+        // The propose message must come first so that hook receivers
+        // can not invalidate our `NextProposalId {}` query.
+        .add_message(propose_messsage)
+        .add_submessages(hooks_msgs)
+        .add_messages(deposit_messages)
+        .add_attribute("method", "draft_sponsored")
+        .add_attribute("id", id.to_string())
+        .add_attribute("sponsor", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_expire(deps: DepsMut, env: Env, id: u64) -> Result<Response, PreProposeError> {
+    let draft = OPEN_DRAFTS
+        .may_load(deps.storage, id)?
+        .ok_or(PreProposeError::ProposalNotFound {})?;
+    if !draft.expiration.is_expired(&env.block) {
+        return Err(PreProposeError::PendingProposalNotExpired {});
+    }
+
+    // Expired drafts were never decided on, so their deposits are
+    // refunded regardless of the refund policy.
+    let messages = match draft.deposit {
+        Some(ref deposit_info) => deposit_info.get_return_deposit_message(&draft.proposer)?,
+        None => vec![],
+    };
+
+    OPEN_DRAFTS.remove(deps.storage, id);
+    CLOSED_DRAFTS.save(
+        deps.storage,
+        id,
+        &Draft {
+            status: DraftStatus::Expired {},
+            ..draft
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "draft_expired")
+        .add_attribute("id", id.to_string())
+        .add_messages(messages))
+}
+
+pub fn execute_update_sponsorship(
+    deps: DepsMut,
+    info: MessageInfo,
+    sponsors_required: u64,
+    sponsor_pays_deposit: bool,
+    draft_expiration: Duration,
+) -> Result<Response, PreProposeError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(PreProposeError::NotDao {});
+    }
+
+    save_sponsorship(
+        deps,
+        sponsors_required,
+        sponsor_pays_deposit,
+        draft_expiration,
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "update_sponsorship")
+        .add_attribute("sponsors_required", sponsors_required.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::QueryExtension { msg } => {
+            match msg {
+                QueryExt::Sponsorship {} => to_json_binary(&SPONSORSHIP.load(deps.storage)?),
+                QueryExt::Draft { id } => {
+                    if let Some(open) = OPEN_DRAFTS.may_load(deps.storage, id)? {
+                        to_json_binary(&open)
+                    } else {
+                        // Force load closed draft if not open, throwing
+                        // error if not found.
+                        to_json_binary(&CLOSED_DRAFTS.load(deps.storage, id)?)
+                    }
+                }
+                QueryExt::OpenDrafts { start_after, limit } => to_json_binary(
+                    &paginate_map_values(deps, &OPEN_DRAFTS, start_after, limit, Order::Ascending)?,
+                ),
+                QueryExt::ReverseOpenDrafts {
+                    start_before,
+                    limit,
+                } => to_json_binary(&paginate_map_values(
+                    deps,
+                    &OPEN_DRAFTS,
+                    start_before,
+                    limit,
+                    Order::Descending,
+                )?),
+                QueryExt::ClosedDrafts { start_after, limit } => {
+                    to_json_binary(&paginate_map_values(
+                        deps,
+                        &CLOSED_DRAFTS,
+                        start_after,
+                        limit,
+                        Order::Ascending,
+                    )?)
+                }
+                QueryExt::ReverseClosedDrafts {
+                    start_before,
+                    limit,
+                } => to_json_binary(&paginate_map_values(
+                    deps,
+                    &CLOSED_DRAFTS,
+                    start_before,
+                    limit,
+                    Order::Descending,
+                )?),
+            }
+        }
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
+
+fn save_sponsorship(
+    deps: DepsMut,
+    sponsors_required: u64,
+    sponsor_pays_deposit: bool,
+    draft_expiration: Duration,
+) -> Result<(), PreProposeError> {
+    if sponsors_required == 0 {
+        return Err(PreProposeError::ZeroSponsorsRequired {});
+    }
+    if matches!(draft_expiration, Duration::Height(0) | Duration::Time(0)) {
+        return Err(PreProposeError::ZeroDraftExpiration {});
+    }
+    SPONSORSHIP.save(
+        deps.storage,
+        &SponsorshipConfig {
+            sponsors_required,
+            sponsor_pays_deposit,
+            draft_expiration,
+        },
+    )?;
+    Ok(())
+}
+
+/// Checks that INFO pays DEPOSIT_INFO if it is a native deposit, and
+/// that no funds were sent otherwise.
+fn check_deposit_funds(
+    info: &MessageInfo,
+    deposit_info: &Option<CheckedDepositInfo>,
+) -> Result<(), PreProposeError> {
+    match deposit_info {
+        Some(d) if matches!(d.denom, CheckedDenom::Native(_)) => {
+            d.check_native_deposit_paid(info)?
+        }
+        _ => {
+            nonpayable(info).map_err(DepositError::from)?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts MSG into the message sent to the proposal module, with
+/// the sender filled in as the proposer.
+fn internalize(msg: ProposeMessage, info: &MessageInfo) -> ProposeMsg {
+    match msg {
+        ProposeMessage::Propose {
+            title,
+            description,
+            msgs,
+            vote,
+        } => ProposeMsg {
+            title,
+            description,
+            msgs,
+            proposer: Some(info.sender.to_string()),
+            vote,
+        },
+    }
+}
@@ -0,0 +1,101 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
+use dao_pre_propose_base::msg::{
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+};
+use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+
+#[cw_serde]
+pub enum ProposeMessage {
+    /// Submits a draft. Note that this is identical to the propose
+    /// message used by dao-proposal-single, except that it omits the
+    /// `proposer` field which it fills in for the sender.
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+        /// Must be `None`. The proposal is created when the last
+        /// sponsor signs on, at which point the proposer may not have
+        /// the voting power needed to vote.
+        vote: Option<SingleChoiceAutoVote>,
+    },
+}
+
+#[cw_serde]
+pub struct InstantiateExt {
+    /// The number of members that must sponsor a draft before it
+    /// becomes a proposal. Defaults to one.
+    #[serde(default)]
+    pub sponsors_required: Option<u64>,
+    /// If true, the deposit is paid by the sponsor whose sponsorship
+    /// creates the proposal instead of by the draft's author.
+    #[serde(default)]
+    pub sponsor_pays_deposit: bool,
+    /// How long drafts may wait for sponsorship before they expire.
+    pub draft_expiration: Duration,
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Sponsor a draft. Once it has been sponsored by enough members,
+    /// the draft is submitted to the proposal module. Only callable
+    /// by addresses that may create proposals.
+    Sponsor { id: u64 },
+    /// Refund the deposit of a draft that has expired and mark it as
+    /// expired. Callable by anyone.
+    Expire { id: u64 },
+    /// Updates how drafts are sponsored. Only applies to drafts
+    /// submitted afterwards, except for `sponsors_required`. Only
+    /// callable by the DAO.
+    UpdateSponsorship {
+        sponsors_required: u64,
+        sponsor_pays_deposit: bool,
+        draft_expiration: Duration,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// How drafts are sponsored.
+    #[returns(crate::state::SponsorshipConfig)]
+    Sponsorship {},
+    /// A draft, open or closed.
+    #[returns(crate::state::Draft)]
+    Draft { id: u64 },
+    /// List of drafts awaiting sponsorship.
+    #[returns(Vec<crate::state::Draft>)]
+    OpenDrafts {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Draft>)]
+    ReverseOpenDrafts {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// List of drafts that have been sponsored or have expired.
+    #[returns(Vec<crate::state::Draft>)]
+    ClosedDrafts {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::Draft>)]
+    ReverseClosedDrafts {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
+
+/// Internal version of the propose message that includes the
+/// `proposer` field. The module will fill this in based on the author
+/// of the draft.
+#[cw_serde]
+pub(crate) enum ProposeMessageInternal {
+    Propose(ProposeMsg),
+}
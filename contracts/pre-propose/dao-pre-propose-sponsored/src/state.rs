@@ -0,0 +1,67 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+
+use dao_voting::deposit::CheckedDepositInfo;
+use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+
+#[cw_serde]
+pub enum DraftStatus {
+    /// The draft is awaiting sponsorship.
+    Open {},
+    /// The draft has been sponsored and submitted.
+    Sponsored {
+        /// The created proposal ID.
+        proposal_id: u64,
+    },
+    /// The draft was not sponsored before it expired.
+    Expired {},
+}
+
+#[cw_serde]
+pub struct Draft {
+    /// The status of the draft.
+    pub status: DraftStatus,
+    /// The ID used to identify this draft.
+    pub id: u64,
+    /// The address that wrote the draft. It is the proposer of the
+    /// proposal created for it.
+    pub proposer: Addr,
+    /// The propose message that is sent to the proposal module once
+    /// the draft has been sponsored.
+    pub msg: ProposeMsg,
+    /// The members that have sponsored the draft.
+    pub sponsors: Vec<Addr>,
+    /// The deposit paid by the proposer, if any. If `None` and the
+    /// module is configured for sponsors to pay deposits, the deposit
+    /// is paid on sponsorship.
+    pub deposit: Option<CheckedDepositInfo>,
+    /// When the draft expires if it has not been sponsored.
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+pub struct SponsorshipConfig {
+    /// The number of members that must sponsor a draft before it
+    /// becomes a proposal.
+    pub sponsors_required: u64,
+    /// If true, the sponsor whose sponsorship creates the proposal
+    /// pays its deposit.
+    pub sponsor_pays_deposit: bool,
+    /// How long drafts may wait for sponsorship before they expire.
+    pub draft_expiration: Duration,
+}
+
+pub const SPONSORSHIP: Item<SponsorshipConfig> = Item::new("sponsorship");
+pub const OPEN_DRAFTS: Map<u64, Draft> = Map::new("open_drafts");
+pub const CLOSED_DRAFTS: Map<u64, Draft> = Map::new("closed_drafts");
+
+/// Used internally to track the current draft ID.
+const CURRENT_ID: Item<u64> = Item::new("current_id");
+
+pub(crate) fn advance_draft_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = CURRENT_ID.may_load(store)?.unwrap_or_default() + 1;
+    CURRENT_ID.save(store, &id)?;
+    Ok(id)
+}
@@ -0,0 +1,629 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, Coin, Empty, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::{Duration, PaymentError};
+use dao_interface::state::ProposalModule;
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_base::{
+    error::PreProposeError, msg::DepositInfoResponse, state::ProposalRateLimit,
+};
+use dao_proposal_single::query::ProposalResponse;
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    deposit::{DepositError, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    status::Status,
+    threshold::{PercentageThreshold, Threshold},
+    voting::{SingleChoiceAutoVote, Vote},
+};
+
+use crate::state::{Draft, DraftStatus, SponsorshipConfig};
+use crate::{contract::*, msg::*};
+
+fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_single::contract::execute,
+        dao_proposal_single::contract::instantiate,
+        dao_proposal_single::contract::query,
+    )
+    .with_migrate(dao_proposal_single::contract::migrate)
+    .with_reply(dao_proposal_single::contract::reply);
+    Box::new(contract)
+}
+
+fn cw_pre_propose_sponsored() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+fn native_deposit() -> UncheckedDepositInfo {
+    UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::OnlyPassed,
+        alternatives: vec![],
+        pricing: None,
+        keeper_share: None,
+    }
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    proposal_single: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    extension: InstantiateExt,
+) -> DefaultTestSetup {
+    let dao_proposal_single_id = app.store_code(cw_dao_proposal_single_contract());
+    let pre_propose_id = app.store_code(cw_pre_propose_sponsored());
+
+    let proposal_module_instantiate = dao_proposal_single::msg::InstantiateMsg {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Time(86400),
+        min_voting_period: None,
+        executor_policy: ExecutorPolicy::Anyone {},
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    deposit_info,
+                    open_proposal_submission: false,
+                    rate_limit: None,
+                    proposal_threshold: None,
+                    submission_roles: None,
+                    message_templates: None,
+                    extension,
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "sponsored pre-propose module".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        quadratic_voting: None,
+        reveal_period: None,
+        veto: None,
+    };
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        dao_proposal_single_id,
+        to_json_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_single = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single.clone(),
+            &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    }
+}
+
+fn default_extension() -> InstantiateExt {
+    InstantiateExt {
+        sponsors_required: None,
+        sponsor_pays_deposit: false,
+        draft_expiration: Duration::Height(10),
+    }
+}
+
+fn mint_natives(app: &mut App, receiver: &str, coins: Vec<Coin>) {
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: receiver.to_string(),
+        amount: coins,
+    }))
+    .unwrap();
+}
+
+fn get_balance_native(app: &App, who: &str, denom: &str) -> Uint128 {
+    let res = app.wrap().query_balance(who, denom).unwrap();
+    res.amount
+}
+
+fn submit_draft(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[Coin]) -> u64 {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "I would like to join the DAO".to_string(),
+                description: "though, I am currently not a member.".to_string(),
+                msgs: vec![],
+                vote: None,
+            },
+        },
+        funds,
+    )
+    .unwrap();
+
+    // Return the ID of the latest open draft.
+    let mut open: Vec<Draft> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::OpenDrafts {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    open.pop().unwrap().id
+}
+
+fn sponsor(
+    app: &mut App,
+    pre_propose: Addr,
+    sender: &str,
+    id: u64,
+    funds: &[Coin],
+) -> Result<AppResponse, PreProposeError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        pre_propose,
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::Sponsor { id },
+        },
+        funds,
+    )
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn get_draft(app: &App, pre_propose: Addr, id: u64) -> Draft {
+    app.wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Draft { id },
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_sponsored_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, Some(native_deposit()), default_extension());
+
+    // Non-members may submit drafts, paying the deposit.
+    mint_natives(&mut app, "outsider", coins(10, "ujuno"));
+    let id = submit_draft(
+        &mut app,
+        pre_propose.clone(),
+        "outsider",
+        &coins(10, "ujuno"),
+    );
+    assert_eq!(
+        get_balance_native(&app, "outsider", "ujuno"),
+        Uint128::zero()
+    );
+
+    // But may not sponsor them.
+    let err = sponsor(&mut app, pre_propose.clone(), "outsider", id, &[]).unwrap_err();
+    assert_eq!(err, PreProposeError::NotMember {});
+
+    sponsor(&mut app, pre_propose.clone(), "ekez", id, &[]).unwrap();
+    let draft = get_draft(&app, pre_propose.clone(), id);
+    assert_eq!(draft.status, DraftStatus::Sponsored { proposal_id: 1 });
+    assert_eq!(draft.sponsors, vec![Addr::unchecked("ekez")]);
+
+    // The proposal is made on behalf of the draft's author, who is
+    // refunded when it passes.
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single.clone(),
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("outsider"));
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(
+        get_balance_native(&app, "outsider", "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Sponsored drafts may not be sponsored again.
+    let err = sponsor(&mut app, pre_propose, "keze", id, &[]).unwrap_err();
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+}
+
+#[test]
+fn test_multiple_sponsors_pay_deposit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(native_deposit()),
+        InstantiateExt {
+            sponsors_required: Some(2),
+            sponsor_pays_deposit: true,
+            draft_expiration: Duration::Height(10),
+        },
+    );
+
+    // Drafts are free when sponsors pay the deposit.
+    mint_natives(&mut app, "outsider", coins(10, "ujuno"));
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("outsider"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &coins(10, "ujuno"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::Payment(PaymentError::NonPayable {}))
+    );
+    let id = submit_draft(&mut app, pre_propose.clone(), "outsider", &[]);
+
+    // Only the sponsor creating the proposal pays.
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let err = sponsor(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        id,
+        &coins(10, "ujuno"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::Payment(PaymentError::NonPayable {}))
+    );
+    sponsor(&mut app, pre_propose.clone(), "ekez", id, &[]).unwrap();
+    let draft = get_draft(&app, pre_propose.clone(), id);
+    assert_eq!(draft.status, DraftStatus::Open {});
+    let err = sponsor(&mut app, pre_propose.clone(), "ekez", id, &[]).unwrap_err();
+    assert_eq!(err, PreProposeError::AlreadySponsored {});
+
+    // The sponsor that creates the proposal pays its deposit.
+    mint_natives(&mut app, "keze", coins(10, "ujuno"));
+    sponsor(
+        &mut app,
+        pre_propose.clone(),
+        "keze",
+        id,
+        &coins(10, "ujuno"),
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "keze", "ujuno"), Uint128::zero());
+    let draft = get_draft(&app, pre_propose.clone(), id);
+    assert_eq!(draft.status, DraftStatus::Sponsored { proposal_id: 1 });
+    assert_eq!(
+        draft.sponsors,
+        vec![Addr::unchecked("ekez"), Addr::unchecked("keze")]
+    );
+
+    let deposit: DepositInfoResponse = app
+        .wrap()
+        .query_wasm_smart(pre_propose, &QueryMsg::DepositInfo { proposal_id: 1 })
+        .unwrap();
+    assert_eq!(deposit.proposer, Addr::unchecked("keze"));
+}
+
+#[test]
+fn test_draft_expiration() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, Some(native_deposit()), default_extension());
+
+    mint_natives(&mut app, "outsider", coins(10, "ujuno"));
+    let id = submit_draft(
+        &mut app,
+        pre_propose.clone(),
+        "outsider",
+        &coins(10, "ujuno"),
+    );
+
+    let expire = |app: &mut App| {
+        app.execute_contract(
+            Addr::unchecked("someone"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::Expire { id },
+            },
+            &[],
+        )
+    };
+
+    let err: PreProposeError = expire(&mut app).unwrap_err().downcast().unwrap();
+    assert_eq!(err, PreProposeError::PendingProposalNotExpired {});
+
+    // Once expired, the draft may no longer be sponsored, and anyone
+    // may expire it to refund its deposit.
+    app.update_block(|block| block.height += 10);
+    let err = sponsor(&mut app, pre_propose.clone(), "ekez", id, &[]).unwrap_err();
+    assert_eq!(err, PreProposeError::PendingProposalExpired {});
+    expire(&mut app).unwrap();
+    assert_eq!(
+        get_balance_native(&app, "outsider", "ujuno"),
+        Uint128::new(10)
+    );
+
+    let open: Vec<Draft> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::OpenDrafts {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert!(open.is_empty());
+    let closed: Vec<Draft> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ClosedDrafts {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(closed.len(), 1);
+    assert_eq!(closed[0].status, DraftStatus::Expired {});
+}
+
+#[test]
+fn test_update_sponsorship() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, default_extension());
+
+    let update = |app: &mut App, sender: &Addr, sponsors_required, draft_expiration| {
+        app.execute_contract(
+            sender.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateSponsorship {
+                    sponsors_required,
+                    sponsor_pays_deposit: true,
+                    draft_expiration,
+                },
+            },
+            &[],
+        )
+    };
+
+    let err: PreProposeError = update(&mut app, &Addr::unchecked("ekez"), 2, Duration::Time(3600))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+    let err: PreProposeError = update(&mut app, &core_addr, 0, Duration::Time(3600))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::ZeroSponsorsRequired {});
+    let err: PreProposeError = update(&mut app, &core_addr, 2, Duration::Height(0))
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::ZeroDraftExpiration {});
+
+    update(&mut app, &core_addr, 2, Duration::Time(3600)).unwrap();
+    let sponsorship: SponsorshipConfig = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Sponsorship {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        sponsorship,
+        SponsorshipConfig {
+            sponsors_required: 2,
+            sponsor_pays_deposit: true,
+            draft_expiration: Duration::Time(3600),
+        }
+    );
+}
+
+#[test]
+fn test_draft_vote() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, default_extension());
+
+    // The proposer is not a member, so a vote cast on their behalf
+    // would fail once the draft is sponsored.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("outsider"),
+            pre_propose,
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: Some(SingleChoiceAutoVote {
+                        vote: Vote::Yes,
+                        rationale: None,
+                    }),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::DraftVote {});
+}
+
+#[test]
+fn test_rate_limit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, default_extension());
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            rate_limit: Some(ProposalRateLimit {
+                max_open_proposals: Some(1),
+                cooldown: None,
+            }),
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Drafts count towards their author's limit once sponsored.
+    let id = submit_draft(&mut app, pre_propose.clone(), "outsider", &[]);
+    sponsor(&mut app, pre_propose.clone(), "ekez", id, &[]).unwrap();
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("outsider"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    vote: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::TooManyOpenProposals { max: 1 });
+    submit_draft(&mut app, pre_propose.clone(), "keze", &[]);
+
+    // Completing the proposal frees up the slot.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::No,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_single,
+        &dao_proposal_single::msg::ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    submit_draft(&mut app, pre_propose, "outsider", &[]);
+}
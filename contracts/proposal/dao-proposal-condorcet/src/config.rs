@@ -1,7 +1,10 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Deps;
 use cw_utils::Duration;
 use dao_voting::{
+    executor::ExecutorPolicy,
     threshold::{validate_quorum, PercentageThreshold},
+    veto::VetoConfig,
     voting::validate_voting_period,
 };
 
@@ -13,6 +16,10 @@ pub struct UncheckedConfig {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    pub allow_revoting: bool,
+    pub resolve_cycles: bool,
+    pub veto: Option<VetoConfig>,
+    pub executor_policy: ExecutorPolicy,
 }
 
 #[cw_serde]
@@ -21,16 +28,28 @@ pub(crate) struct Config {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    pub allow_revoting: bool,
+    pub resolve_cycles: bool,
+    pub veto: Option<VetoConfig>,
+    pub executor_policy: ExecutorPolicy,
 }
 
 impl UncheckedConfig {
-    pub(crate) fn into_checked(self) -> Result<Config, ContractError> {
+    pub(crate) fn into_checked(self, deps: Deps) -> Result<Config, ContractError> {
         validate_quorum(&self.quorum)?;
         let (min_voting_period, voting_period) =
             validate_voting_period(self.min_voting_period, self.voting_period)?;
+        if let Some(veto) = &self.veto {
+            veto.validate(&deps, &voting_period)?;
+        }
+        self.executor_policy.validate(&deps)?;
         Ok(Config {
             quorum: self.quorum,
             close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
+            allow_revoting: self.allow_revoting,
+            resolve_cycles: self.resolve_cycles,
+            veto: self.veto,
+            executor_policy: self.executor_policy,
             voting_period,
             min_voting_period,
         })
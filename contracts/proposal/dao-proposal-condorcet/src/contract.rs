@@ -1,20 +1,34 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, SubMsg,
 };
 
 use cw2::set_contract_version;
-use dao_voting::reply::TaggedReplyId;
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::parse_reply_instantiate_data;
+use dao_hooks::proposal::{
+    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+};
+use dao_hooks::vote::new_vote_hooks;
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::proposal::DEFAULT_LIMIT;
+use dao_voting::reply::{failed_pre_propose_module_hook_id, TaggedReplyId};
+use dao_voting::veto::VetoError;
 use dao_voting::voting::{get_total_power, get_voting_power};
 
 use crate::config::UncheckedConfig;
 use crate::error::ContractError;
-use crate::msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::proposal::{Proposal, ProposalResponse, Status};
-use crate::state::{next_proposal_id, CONFIG, DAO, PROPOSAL, TALLY, VOTE};
+use crate::msg::{Choice, ExecuteMsg, InstantiateMsg, ProposeMsg, QueryMsg};
+use crate::proposal::{Proposal, ProposalListResponse, ProposalResponse, Status};
+use crate::state::{
+    next_proposal_id, CONFIG, CREATION_POLICY, DAO, PROPOSAL, PROPOSAL_HOOKS, TALLY, VOTE,
+    VOTE_HOOKS,
+};
 use crate::tally::Tally;
-use crate::vote::Vote;
+use crate::vote::{Ballot, Vote, VoteInfo, VoteListResponse, VoteResponse};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-condorcet";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -28,10 +42,27 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    let config = UncheckedConfig {
+        quorum: msg.quorum,
+        voting_period: msg.voting_period,
+        min_voting_period: msg.min_voting_period,
+        close_proposals_on_execution_failure: msg.close_proposals_on_execution_failure,
+        allow_revoting: msg.allow_revoting,
+        resolve_cycles: msg.resolve_cycles,
+        veto: msg.veto,
+        executor_policy: msg.executor_policy,
+    };
+    let (initial_policy, pre_propose_messages) = msg
+        .pre_propose_info
+        .into_initial_policy_and_messages(info.sender.clone())?;
+
+    let config = config.into_checked(deps.as_ref())?;
     DAO.save(deps.storage, &info.sender)?;
-    CONFIG.save(deps.storage, &msg.into_checked()?)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
 
     Ok(Response::default()
+        .add_submessages(pre_propose_messages)
         .add_attribute("method", "instantiate")
         .add_attribute("creator", info.sender))
 }
@@ -49,27 +80,31 @@ pub fn instantiate(
 // in terms of storage costs:
 //
 // propose: proposal_load + proposal_store + tally_load + tally_store + config_load
-// execute: proposal_load + proposal_store + tally_load
+// execute: proposal_load + proposal_store + tally_load               + config_load
 // vote:                                     tally_load + tally_store               + vote_load + vote_store
 //
 // so we are good so long as:
 //
 // `vote_load + vote_store <= proposal_load + proposal_store + config_load`
 //
-// this is true so long as a vote is smaller than a proposal in
+// this is true so long as a ballot is smaller than a proposal in
 // storage which is true because proposals store `choices =
 // Vec<Vec<CosmosMsg>>`, `choices.len() = vote.len()`, vote is a
 // `Vec<u32>`, even an empty vec must contain it's length which is a
 // usize, so `sizeof(Vec<u32>) <= sizeof(Vec<usize>) <=
-// sizeof(Vec<Vec<CosmosMsg>) => sizeof(vote) <= sizeof(proposal)`.
+// sizeof(Vec<Vec<CosmosMsg>) => sizeof(vote) <= sizeof(proposal)`. a
+// ballot additionally stores the voter's power, which is matched by
+// the proposal's `total_power`.
 //
 // in terms of other costs:
 //
 // propose: query_voting_power + compute_winner [2]
-// execute: query_voting_power
+// execute: query_voting_power (when only members may execute)
 // vote:    query_voting_power + compute_winner
+// revote:  query_voting_power + compute_winner * 2
 //
-// so we're good there as well.
+// so we're good there as well, as when revoting is enabled
+// Tally::new performs an equivalent revote.
 //
 // [1] we need to be gas efficent in this way because the size of the
 //     Tally type grows with candidates^2 and thus can be too large to
@@ -88,12 +123,30 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Propose { choices } => execute_propose(deps, env, info, choices),
+        ExecuteMsg::Propose(ProposeMsg { choices, proposer }) => {
+            execute_propose(deps, env, info, choices, proposer)
+        }
         ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
 
         ExecuteMsg::SetConfig(config) => execute_set_config(deps, info, config),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_pre_propose_info(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => {
+            execute_add_hook(deps, info, PROPOSAL_HOOKS, "add_proposal_hook", address)
+        }
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_hook(deps, info, PROPOSAL_HOOKS, "remove_proposal_hook", address)
+        }
+        ExecuteMsg::AddVoteHook { address } => {
+            execute_add_hook(deps, info, VOTE_HOOKS, "add_vote_hook", address)
+        }
+        ExecuteMsg::RemoveVoteHook { address } => {
+            execute_remove_hook(deps, info, VOTE_HOOKS, "remove_vote_hook", address)
+        }
     }
 }
 
@@ -102,13 +155,33 @@ fn execute_propose(
     env: Env,
     info: MessageInfo,
     choices: Vec<Choice>,
+    proposer: Option<String>,
 ) -> Result<Response, ContractError> {
     let dao = DAO.load(deps.storage)?;
-    let sender_voting_power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
-    if sender_voting_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    if !proposal_creation_policy.is_permitted(&info.sender) {
+        return Err(ContractError::Unauthorized {});
     }
 
+    // if a pre-propose module is attached it must specify the
+    // proposer and is responsible for checking that they may
+    // propose. otherwise, the sender is the proposer and must have
+    // voting power.
+    let proposer = match (proposer, &proposal_creation_policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => {
+            let sender_voting_power =
+                get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+            if sender_voting_power.is_zero() {
+                return Err(ContractError::ZeroVotingPower {});
+            }
+            info.sender
+        }
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
     let config = CONFIG.load(deps.storage)?;
 
     let id = next_proposal_id(deps.storage)?;
@@ -127,14 +200,19 @@ fn execute_propose(
         total_power,
         env.block.height,
         config.voting_period.after(&env.block),
+        config.allow_revoting,
+        config.resolve_cycles,
     );
     TALLY.save(deps.storage, id, &tally)?;
 
-    let mut proposal = Proposal::new(&env.block, &config, info.sender, id, choices, total_power);
-    proposal.update_status(&env.block, &tally);
+    let mut proposal = Proposal::new(&env.block, &config, proposer, id, choices, total_power);
+    proposal.update_status(&env.block, &tally)?;
     PROPOSAL.save(deps.storage, id, &proposal)?;
 
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposal.proposer.as_str())?;
+
     Ok(Response::default()
+        .add_submessages(hooks)
         .add_attribute("method", "propose")
         .add_attribute("proposal_id", proposal.id.to_string())
         .add_attribute("proposer", proposal.proposer))
@@ -144,7 +222,7 @@ fn execute_vote(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    proposal_id: u32,
+    proposal_id: u64,
     vote: Vec<u32>,
 ) -> Result<Response, ContractError> {
     let tally = TALLY.load(deps.storage, proposal_id)?;
@@ -154,21 +232,44 @@ fn execute_vote(
         &DAO.load(deps.storage)?,
         Some(tally.start_height),
     )?;
+    let prior = VOTE.may_load(deps.storage, (proposal_id, info.sender.clone()))?;
     if sender_power.is_zero() {
         Err(ContractError::ZeroVotingPower {})
-    } else if VOTE.has(deps.storage, (proposal_id, info.sender.clone())) {
+    } else if prior.is_some() && !tally.allow_revoting {
         Err(ContractError::Voted {})
     } else if tally.expired(&env.block) {
         Err(ContractError::Expired {})
     } else {
         let vote = Vote::new(vote, tally.candidates())?;
-        VOTE.save(deps.storage, (proposal_id, info.sender.clone()), &vote)?;
-
         let mut tally = tally;
+        if let Some(prior) = prior {
+            if prior.vote == vote {
+                return Err(ContractError::AlreadyCast {});
+            }
+            tally.remove_vote(&prior.vote, prior.power);
+        }
+        VOTE.save(
+            deps.storage,
+            (proposal_id, info.sender.clone()),
+            &Ballot {
+                vote: vote.clone(),
+                power: sender_power,
+            },
+        )?;
+
+        let hooks = new_vote_hooks(
+            VOTE_HOOKS,
+            deps.storage,
+            proposal_id,
+            info.sender.to_string(),
+            vote.to_string(),
+        )?;
+
         tally.add_vote(vote, sender_power);
         TALLY.save(deps.storage, proposal_id, &tally)?;
 
         Ok(Response::default()
+            .add_submessages(hooks)
             .add_attribute("method", "vote")
             .add_attribute("proposal_id", proposal_id.to_string())
             .add_attribute("voter", info.sender)
@@ -180,48 +281,89 @@ fn execute_execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    proposal_id: u32,
+    proposal_id: u64,
 ) -> Result<Response, ContractError> {
     let tally = TALLY.load(deps.storage, proposal_id)?;
     let dao = DAO.load(deps.storage)?;
-    let sender_power = get_voting_power(
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+    let sender_can_execute = config.executor_policy.can_execute(
         deps.as_ref(),
-        info.sender.clone(),
         &dao,
-        Some(tally.start_height),
+        &info.sender,
+        &proposal.proposer,
+        tally.start_height,
     )?;
-    if sender_power.is_zero() {
-        return Err(ContractError::ZeroVotingPower {});
-    }
+    let old_status = proposal.update_status(&env.block, &tally)?;
+    let winner = match old_status {
+        Status::Passed { winner } => {
+            if !sender_can_execute {
+                return Err(ContractError::NotExecutor {});
+            }
+            winner
+        }
+        Status::VetoTimelock { winner, .. } => {
+            // only the vetoer may execute a timelocked proposal, and
+            // only if early execution is enabled.
+            let veto = proposal
+                .veto
+                .as_ref()
+                .ok_or(VetoError::NoVetoConfiguration {})?;
+            if veto.vetoer != info.sender {
+                return Err(if sender_can_execute {
+                    VetoError::Timelocked {}.into()
+                } else {
+                    ContractError::NotExecutor {}
+                });
+            }
+            veto.check_early_execute_enabled()?;
+            winner
+        }
+        _ => return Err(ContractError::Unexecutable {}),
+    };
 
-    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Passed { winner } = proposal.update_status(&env.block, &tally) {
-        let msgs = proposal.set_executed(dao, winner)?;
-        PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+    let msgs = proposal.set_executed(dao, winner)?;
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
 
-        Ok(Response::default()
-            .add_attribute("method", "execute")
-            .add_attribute("proposal_id", proposal_id.to_string())
-            .add_attribute("executor", info.sender)
-            .add_submessage(msgs))
-    } else {
-        Err(ContractError::Unexecutable {})
-    }
+    let hooks = completion_hooks(
+        deps.as_ref(),
+        proposal_id,
+        old_status,
+        Status::Executed,
+        Some(&info.sender),
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executor", info.sender)
+        .add_submessage(msgs)
+        .add_submessages(hooks))
 }
 
 fn execute_close(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    proposal_id: u32,
+    proposal_id: u64,
 ) -> Result<Response, ContractError> {
     let tally = TALLY.load(deps.storage, proposal_id)?;
     let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
-    if let Status::Rejected = proposal.update_status(&env.block, &tally) {
+    if let Status::Rejected = proposal.update_status(&env.block, &tally)? {
         proposal.set_closed();
         PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
 
+        let hooks = completion_hooks(
+            deps.as_ref(),
+            proposal_id,
+            Status::Rejected,
+            Status::Closed,
+            Some(&info.sender),
+        )?;
+
         Ok(Response::default()
+            .add_submessages(hooks)
             .add_attribute("method", "close")
             .add_attribute("proposal_id", proposal_id.to_string())
             .add_attribute("closer", info.sender))
@@ -230,6 +372,47 @@ fn execute_close(
     }
 }
 
+fn execute_veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let tally = TALLY.load(deps.storage, proposal_id)?;
+    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+    let old_status = proposal.update_status(&env.block, &tally)?;
+
+    let veto = proposal
+        .veto
+        .as_ref()
+        .ok_or(VetoError::NoVetoConfiguration {})?;
+    veto.check_is_vetoer(&info)?;
+
+    match old_status {
+        Status::Open => veto.check_veto_before_passed_enabled()?,
+        // the timelock has already expired.
+        Status::Passed { .. } => return Err(VetoError::TimelockExpired {}.into()),
+        Status::VetoTimelock { .. } => (),
+        _ => {
+            return Err(VetoError::InvalidProposalStatus {
+                status: old_status.to_string(),
+            }
+            .into())
+        }
+    }
+
+    proposal.set_vetoed();
+    PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+    let hooks = completion_hooks(deps.as_ref(), proposal_id, old_status, Status::Vetoed, None)?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("method", "veto")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("vetoer", info.sender))
+}
+
 fn execute_set_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -238,23 +421,183 @@ fn execute_set_config(
     if info.sender != DAO.load(deps.storage)? {
         Err(ContractError::NotDao {})
     } else {
-        CONFIG.save(deps.storage, &config.into_checked()?)?;
+        let config = config.into_checked(deps.as_ref())?;
+        CONFIG.save(deps.storage, &config)?;
         Ok(Response::default()
             .add_attribute("method", "update_config")
             .add_attribute("updater", info.sender))
     }
 }
 
+fn execute_update_pre_propose_info(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::NotDao {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("method", "update_pre_propose_info")
+        .add_attribute("updater", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    method: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::NotDao {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    hooks.add_hook(deps.storage, address.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("method", method)
+        .add_attribute("address", address))
+}
+
+fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    method: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::NotDao {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+    hooks.remove_hook(deps.storage, address.clone())?;
+
+    Ok(Response::default()
+        .add_attribute("method", method)
+        .add_attribute("address", address))
+}
+
+/// Hooks fired when a proposal is executed or closed. Proposal hook
+/// consumers are notified of the status change, and the pre-propose
+/// module (if any) is told the proposal completed so that it may
+/// handle deposits. `keeper` is the address that executed or closed
+/// the proposal, if any.
+fn completion_hooks(
+    deps: Deps,
+    proposal_id: u64,
+    old_status: Status,
+    new_status: Status,
+    keeper: Option<&Addr>,
+) -> StdResult<Vec<SubMsg>> {
+    let mut hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        new_status.to_string(),
+    )?;
+    hooks.extend(proposal_completed_hooks(
+        CREATION_POLICY.load(deps.storage)?,
+        proposal_id,
+        new_status.into(),
+        keeper,
+    )?);
+    Ok(hooks)
+}
+
+fn proposal_response(deps: Deps, env: &Env, mut proposal: Proposal) -> StdResult<ProposalResponse> {
+    let tally = TALLY.load(deps.storage, proposal.id)?;
+    proposal.update_status(&env.block, &tally)?;
+    Ok(ProposalResponse { proposal, tally })
+}
+
+fn list_proposals(
+    deps: Deps,
+    env: &Env,
+    min: Option<Bound<u64>>,
+    max: Option<Bound<u64>>,
+    order: Order,
+    limit: Option<u64>,
+) -> StdResult<ProposalListResponse> {
+    let proposals = PROPOSAL
+        .range(deps.storage, min, max, order)
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| proposal_response(deps, env, item?.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(ProposalListResponse { proposals })
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Proposal { id } => {
-            let mut proposal = PROPOSAL.load(deps.storage, id)?;
-            let tally = TALLY.load(deps.storage, id)?;
-            proposal.update_status(&env.block, &tally);
-            to_json_binary(&ProposalResponse { proposal, tally })
+        QueryMsg::Proposal { id } => to_json_binary(&proposal_response(
+            deps,
+            &env,
+            PROPOSAL.load(deps.storage, id)?,
+        )?),
+        QueryMsg::ListProposals { start_after, limit } => to_json_binary(&list_proposals(
+            deps,
+            &env,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+            limit,
+        )?),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => to_json_binary(&list_proposals(
+            deps,
+            &env,
+            None,
+            start_before.map(Bound::exclusive),
+            Order::Descending,
+            limit,
+        )?),
+        QueryMsg::ProposalCount {} => to_json_binary(&(next_proposal_id(deps.storage)? - 1)),
+        QueryMsg::GetVote { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let vote = VOTE
+                .may_load(deps.storage, (proposal_id, voter.clone()))?
+                .map(|Ballot { vote, power }| VoteInfo { voter, vote, power });
+            to_json_binary(&VoteResponse { vote })
+        }
+        QueryMsg::ListVotes {
+            proposal_id,
+            start_after,
+            limit,
+        } => {
+            let start_after = start_after
+                .map(|addr| deps.api.addr_validate(&addr))
+                .transpose()?;
+            let votes = VOTE
+                .prefix(proposal_id)
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+                .map(|item| {
+                    let (voter, Ballot { vote, power }) = item?;
+                    Ok(VoteInfo { voter, vote, power })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_json_binary(&VoteListResponse { votes })
         }
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::ProposalCreationPolicy {} => to_json_binary(&CREATION_POLICY.load(deps.storage)?),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
         QueryMsg::NextProposalId {} => to_json_binary(&next_proposal_id(deps.storage)?),
         QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
         QueryMsg::Info {} => to_json_binary(&dao_interface::voting::InfoResponse {
@@ -268,14 +611,50 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            let mut proposal = PROPOSAL.load(deps.storage, proposal_id as u32)?;
+            let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
             proposal.set_execution_failed();
-            PROPOSAL.save(deps.storage, proposal_id as u32, &proposal)?;
+            PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
 
             Ok(Response::default()
                 .add_attribute("proposal_execution_failed", proposal_id.to_string())
                 .add_attribute("error", msg.result.into_result().err().unwrap_or_default()))
         }
-        _ => unimplemented!("pre-propose and hooks not yet supported"),
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::FailedVoteHook(idx) => {
+            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::default().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            let response = Response::default()
+                .add_attribute("update_pre_propose_module", res.contract_address);
+            Ok(match res.data {
+                Some(data) => response.set_data(data),
+                None => response,
+            })
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => {
+            // the pre-propose module errored while receiving a
+            // hook. remove it and allow anyone to propose.
+            let addr: Addr = match CREATION_POLICY.load(deps.storage)? {
+                ProposalCreationPolicy::Anyone {} => {
+                    return Err(ContractError::UnexpectedReply {
+                        id: failed_pre_propose_module_hook_id(),
+                    })
+                }
+                ProposalCreationPolicy::Module { addr } => addr,
+            };
+            CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+            Ok(Response::default().add_attribute("failed_prepropose_hook", addr))
+        }
     }
 }
@@ -1,5 +1,10 @@
 use cosmwasm_std::StdError;
-use dao_voting::{error::VotingError, reply::error::TagError, threshold::ThresholdError};
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{
+    error::VotingError, executor::ExecutorPolicyError, reply::error::TagError,
+    threshold::ThresholdError, veto::VetoError,
+};
 use thiserror::Error;
 
 use crate::vote::VoteError;
@@ -16,10 +21,21 @@ pub enum ContractError {
     Voting(#[from] VotingError),
     #[error(transparent)]
     Tag(#[from] TagError),
+    #[error(transparent)]
+    Veto(#[from] VetoError),
+    #[error(transparent)]
+    ExecutorPolicy(#[from] ExecutorPolicyError),
+    #[error(transparent)]
+    Hook(#[from] HookError),
+    #[error(transparent)]
+    ParseReply(#[from] ParseReplyError),
 
     #[error("non-zero voting power required to perform this action")]
     ZeroVotingPower {},
 
+    #[error("the executor policy does not permit the sender to execute this proposal")]
+    NotExecutor {},
+
     #[error("only proposals that are in the passed state may be executed")]
     Unexecutable {},
 
@@ -32,9 +48,21 @@ pub enum ContractError {
     #[error("already voted")]
     Voted {},
 
+    #[error("already cast a vote with that ranking")]
+    AlreadyCast {},
+
     #[error("only non-expired proposals may be voted on")]
     Expired {},
 
     #[error("must specify at least one choice for proposal")]
     ZeroChoices {},
+
+    #[error("the proposal creation policy does not permit the sender to propose")]
+    Unauthorized {},
+
+    #[error("a proposer must be specified if and only if a pre-propose module is attached")]
+    InvalidProposer {},
+
+    #[error("unexpected reply id ({id})")]
+    UnexpectedReply { id: u64 },
 }
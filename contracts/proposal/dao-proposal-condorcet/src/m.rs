@@ -118,6 +118,49 @@ impl M {
         }
     }
 
+    /// Computes the winner of M according to the Schulze method [1],
+    /// using the margins in M as the strength of each pairwise
+    /// defeat. Returns `None` if multiple candidates tie.
+    ///
+    /// This runs in O(N^3), though for any N which may be loaded from
+    /// storage this is dwarfed by the cost of loading M.
+    ///
+    /// [1] https://en.wikipedia.org/wiki/Schulze_method
+    pub fn schulze_winner(&self) -> Option<u32> {
+        let n = self.n as usize;
+        // p[x][y] is the strength of the strongest path from x to y.
+        let mut p = vec![vec![Uint128::zero(); n]; n];
+        for (x, row) in p.iter_mut().enumerate() {
+            for (y, strength) in row.iter_mut().enumerate() {
+                if x != y {
+                    if let Cell::Positive(margin) = self.get((x as u32, y as u32)) {
+                        *strength = margin
+                    }
+                }
+            }
+        }
+        for k in 0..n {
+            for x in 0..n {
+                if x == k {
+                    continue;
+                }
+                for y in 0..n {
+                    if y != x && y != k {
+                        let through_k = std::cmp::min(p[x][k], p[k][y]);
+                        if through_k > p[x][y] {
+                            p[x][y] = through_k
+                        }
+                    }
+                }
+            }
+        }
+        let mut winners = (0..n).filter(|&x| (0..n).all(|y| x == y || p[x][y] >= p[y][x]));
+        match (winners.next(), winners.next()) {
+            (Some(winner), None) => Some(winner as u32),
+            _ => None,
+        }
+    }
+
     /// Computes statistics about M which are used to determine if a
     /// proposal has passed or may be rejected early.
     ///
@@ -290,6 +333,23 @@ mod test_lm {
         }
     }
 
+    #[test]
+    fn test_schulze_winner() {
+        let mut m = new_m(3);
+        // 0 > 1 > 2 > 0 with the weakest defeat being 2 > 0.
+        m.increment((0, 1), Uint128::new(3));
+        m.increment((1, 2), Uint128::new(2));
+        m.increment((2, 0), Uint128::new(1));
+        assert_eq!(m.schulze_winner(), Some(0));
+
+        // all defeats are equally strong, so all candidates tie.
+        let mut m = new_m(3);
+        m.increment((0, 1), Uint128::one());
+        m.increment((1, 2), Uint128::one());
+        m.increment((2, 0), Uint128::one());
+        assert_eq!(m.schulze_winner(), None);
+    }
+
     #[test]
     fn test_stats_positive_column() {
         let n = 8;
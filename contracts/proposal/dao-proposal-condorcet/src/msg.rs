@@ -1,24 +1,79 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Empty};
+use cw_utils::Duration;
 
 use dao_dao_macros::proposal_module_query;
+use dao_voting::{
+    executor::ExecutorPolicy, pre_propose::PreProposeInfo, threshold::PercentageThreshold,
+    veto::VetoConfig,
+};
 
 use crate::config::UncheckedConfig;
 
-pub type InstantiateMsg = UncheckedConfig;
+pub use dao_voting::proposal::{CondorcetChoice as Choice, CondorcetProposeMsg as ProposeMsg};
 
 #[cw_serde]
-pub struct Choice {
-    pub msgs: Vec<CosmosMsg<Empty>>,
+pub struct InstantiateMsg {
+    pub quorum: PercentageThreshold,
+    pub voting_period: Duration,
+    pub min_voting_period: Option<Duration>,
+    pub close_proposals_on_execution_failure: bool,
+    /// If voters may change their votes while a proposal is open. If
+    /// enabled, proposals may not pass or be rejected early.
+    pub allow_revoting: bool,
+    /// If proposals without a Condorcet winner are resolved with the
+    /// Schulze method once their voting period ends. Otherwise, they
+    /// are rejected.
+    pub resolve_cycles: bool,
+    /// Optional veto configuration for proposal execution. If set,
+    /// passed proposals are timelocked and may be vetoed by the
+    /// vetoer until the timelock expires.
+    pub veto: Option<VetoConfig>,
+    /// Who may execute passed proposals.
+    pub executor_policy: ExecutorPolicy,
+    /// Information about what addresses may create proposals.
+    pub pre_propose_info: PreProposeInfo,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Propose { choices: Vec<Choice> },
-    Vote { proposal_id: u32, vote: Vec<u32> },
-    Execute { proposal_id: u32 },
-    Close { proposal_id: u32 },
+    Propose(ProposeMsg),
+    /// Casts a vote ranking choices from most to least preferred.
+    /// Choices may be left out of the ranking, in which case they
+    /// are tied for last place.
+    Vote {
+        proposal_id: u64,
+        vote: Vec<u32>,
+    },
+    Execute {
+        proposal_id: u64,
+    },
+    Close {
+        proposal_id: u64,
+    },
+    /// Vetoes a proposal. Only the vetoer configured on the proposal
+    /// may do this, either while it is timelocked or, if
+    /// `veto_before_passed` is enabled, while it is open.
+    Veto {
+        proposal_id: u64,
+    },
     SetConfig(UncheckedConfig),
+    /// Updates the proposal creation policy used for this
+    /// module. Only the DAO may call this method.
+    UpdatePreProposeInfo {
+        info: PreProposeInfo,
+    },
+    AddProposalHook {
+        address: String,
+    },
+    RemoveProposalHook {
+        address: String,
+    },
+    AddVoteHook {
+        address: String,
+    },
+    RemoveVoteHook {
+        address: String,
+    },
 }
 
 #[proposal_module_query]
@@ -26,7 +81,61 @@ pub enum ExecuteMsg {
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     #[returns(crate::proposal::ProposalResponse)]
-    Proposal { id: u32 },
+    Proposal { id: u64 },
+    /// Lists all the proposals that have been cast in this
+    /// module.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ListProposals {
+        /// The proposal ID to start listing proposals after. For
+        /// example, if this is set to 2 proposals with IDs 3 and
+        /// higher will be returned.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists all of the proposals that have been cast in this module
+    /// in decending order of proposal ID.
+    #[returns(crate::proposal::ProposalListResponse)]
+    ReverseProposals {
+        /// The proposal ID to start listing proposals before. For
+        /// example, if this is set to 6 proposals with IDs 5 and
+        /// lower will be returned.
+        start_before: Option<u64>,
+        /// The maximum number of proposals to return as part of this
+        /// query. If no limit is set a max of 30 proposals will be
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Returns the number of proposals that have been created in this module.
+    #[returns(::std::primitive::u64)]
+    ProposalCount {},
+    /// Returns a voters ranking on a propsal.
+    #[returns(crate::vote::VoteResponse)]
+    GetVote { proposal_id: u64, voter: String },
+    /// Lists all of the votes that have been cast on a
+    /// proposal.
+    #[returns(crate::vote::VoteListResponse)]
+    ListVotes {
+        /// The proposal to list the votes of.
+        proposal_id: u64,
+        /// The voter to start listing votes after. Ordering is done
+        /// alphabetically.
+        start_after: Option<String>,
+        /// The maximum number of votes to return in response to this
+        /// query. If no limit is specified a max of 30 are returned.
+        limit: Option<u64>,
+    },
     #[returns(crate::config::Config)]
     Config {},
+    /// Gets the current proposal creation policy for this module.
+    #[returns(::dao_voting::pre_propose::ProposalCreationPolicy)]
+    ProposalCreationPolicy {},
+    /// Lists all of the consumers of proposal hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    ProposalHooks {},
+    /// Lists all of the consumers of vote hooks for this module.
+    #[returns(::cw_hooks::HooksResponse)]
+    VoteHooks {},
 }
@@ -1,8 +1,10 @@
+use std::ops::Add;
+
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_json_binary, Addr, BlockInfo, StdResult, SubMsg, Uint128, WasmMsg};
 use cw_utils::Expiration;
 use dao_voting::{
-    reply::mask_proposal_execution_proposal_id, threshold::PercentageThreshold,
+    reply::mask_proposal_execution_proposal_id, threshold::PercentageThreshold, veto::VetoConfig,
     voting::does_vote_count_pass,
 };
 
@@ -23,8 +25,12 @@ pub struct Proposal {
 
     pub close_on_execution_failure: bool,
     pub total_power: Uint128,
+    /// Optional veto configuration. If set, a passed proposal is
+    /// timelocked until `timelock_duration` after voting ends, during
+    /// which the vetoer may veto it.
+    pub veto: Option<VetoConfig>,
 
-    pub id: u32,
+    pub id: u64,
     pub choices: Vec<Choice>,
 }
 
@@ -44,6 +50,32 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal has passed and is timelocked. Only the configured
+    /// vetoer can execute or veto until the timelock expires.
+    VetoTimelock { winner: u32, expiration: Expiration },
+    /// The proposal has been vetoed.
+    Vetoed,
+}
+
+impl From<Status> for dao_voting::status::Status {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Open => Self::Open,
+            Status::Rejected => Self::Rejected,
+            Status::Passed { .. } => Self::Passed,
+            Status::Executed => Self::Executed,
+            Status::Closed => Self::Closed,
+            Status::ExecutionFailed => Self::ExecutionFailed,
+            Status::VetoTimelock { expiration, .. } => Self::VetoTimelock { expiration },
+            Status::Vetoed => Self::Vetoed,
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", dao_voting::status::Status::from(*self))
+    }
 }
 
 #[cw_serde]
@@ -52,19 +84,50 @@ pub struct ProposalResponse {
     pub tally: Tally,
 }
 
-fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
-    match proposal.last_status {
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> StdResult<Status> {
+    // a passed proposal is timelocked until the veto timelock after
+    // the end of voting expires.
+    let passed = |winner| -> StdResult<Status> {
+        match &proposal.veto {
+            Some(veto) => {
+                // units match as the timelock duration is validated
+                // against the voting period. should they not, error
+                // rather than skip the timelock.
+                let expiration = tally.expiration.add(veto.timelock_duration)?;
+                if expiration.is_expired(block) {
+                    Ok(Status::Passed { winner })
+                } else {
+                    Ok(Status::VetoTimelock { winner, expiration })
+                }
+            }
+            None => Ok(Status::Passed { winner }),
+        }
+    };
+    Ok(match proposal.last_status {
         Status::Rejected
         | Status::Passed { .. }
         | Status::Executed
         | Status::Closed
-        | Status::ExecutionFailed => proposal.last_status,
+        | Status::ExecutionFailed
+        | Status::Vetoed => proposal.last_status,
+        Status::VetoTimelock { winner, expiration } => {
+            if expiration.is_expired(block) {
+                Status::Passed { winner }
+            } else {
+                proposal.last_status
+            }
+        }
         Status::Open => {
             if proposal
                 .min_voting_period
                 .map_or(false, |min| !min.is_expired(block))
             {
-                return Status::Open;
+                return Ok(Status::Open);
             }
 
             let winner = tally.winner;
@@ -82,21 +145,24 @@ fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
                     Winner::Never => Status::Rejected,
                     Winner::None => {
                         if expired {
-                            Status::Rejected
+                            match tally.fallback_winner() {
+                                Some(winner) => passed(winner)?,
+                                None => Status::Rejected,
+                            }
                         } else {
                             Status::Open
                         }
                     }
                     Winner::Some(winner) => {
                         if expired && quorum {
-                            Status::Passed { winner }
+                            passed(winner)?
                         } else {
                             Status::Open
                         }
                     }
                     Winner::Undisputed(winner) => {
                         if quorum {
-                            Status::Passed { winner }
+                            passed(winner)?
                         } else {
                             Status::Open
                         }
@@ -104,7 +170,7 @@ fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
                 }
             }
         }
-    }
+    })
 }
 
 impl Proposal {
@@ -112,7 +178,7 @@ impl Proposal {
         block: &BlockInfo,
         config: &Config,
         proposer: Addr,
-        id: u32,
+        id: u64,
         choices: Vec<Choice>,
         total_power: Uint128,
     ) -> Self {
@@ -127,15 +193,16 @@ impl Proposal {
             proposer,
             choices,
             total_power,
+            veto: config.veto.clone(),
         }
     }
 
-    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> Status {
-        self.last_status = status(block, self, tally);
-        self.last_status
+    pub(crate) fn update_status(&mut self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
+        self.last_status = status(block, self, tally)?;
+        Ok(self.last_status)
     }
 
-    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> Status {
+    pub fn status(&self, block: &BlockInfo, tally: &Tally) -> StdResult<Status> {
         status(block, self, tally)
     }
 
@@ -154,7 +221,10 @@ impl Proposal {
     /// Sets the proposal's status to executed and returns a
     /// submessage to be executed.
     pub(crate) fn set_executed(&mut self, dao: Addr, winner: u32) -> StdResult<SubMsg> {
-        debug_assert_eq!(self.last_status, Status::Passed { winner });
+        debug_assert!(matches!(
+            self.last_status,
+            Status::Passed { winner: w } | Status::VetoTimelock { winner: w, .. } if w == winner
+        ));
 
         self.last_status = Status::Executed;
 
@@ -165,13 +235,17 @@ impl Proposal {
             funds: vec![],
         };
         Ok(if self.close_on_execution_failure {
-            let masked_id = mask_proposal_execution_proposal_id(self.id as u64);
+            let masked_id = mask_proposal_execution_proposal_id(self.id);
             SubMsg::reply_on_error(core_exec, masked_id)
         } else {
             SubMsg::new(core_exec)
         })
     }
 
+    pub(crate) fn set_vetoed(&mut self) {
+        self.last_status = Status::Vetoed;
+    }
+
     pub(crate) fn set_execution_failed(&mut self) {
         debug_assert_eq!(self.last_status, Status::Executed);
 
@@ -30,6 +30,14 @@ pub struct Tally {
     pub power_outstanding: Uint128,
     /// The current winner. Always up to date and updated on vote.
     pub winner: Winner,
+    /// If voters may change their votes. If so, a winner is never
+    /// undisputed, and a proposal without one may not be rejected,
+    /// before the tally expires.
+    pub allow_revoting: bool,
+    /// If a tally with no Condorcet winner is resolved with the
+    /// Schulze method once it expires. If so, a tally is never
+    /// rejected early for lacking a winnable candidate.
+    pub resolve_cycles: bool,
 }
 
 #[cw_serde]
@@ -47,6 +55,8 @@ impl Tally {
         total_power: Uint128,
         start_height: u64,
         expiration: Expiration,
+        allow_revoting: bool,
+        resolve_cycles: bool,
     ) -> Self {
         let mut tally = Self {
             m: M::new(candidates),
@@ -54,7 +64,17 @@ impl Tally {
             winner: Winner::None,
             start_height,
             expiration,
+            allow_revoting,
+            resolve_cycles,
         };
+        if allow_revoting {
+            // remove and re-add a vote with no net effect so that
+            // creating a tally has the same compute cost as a revote.
+            let vote = Vote::new((0..candidates).collect(), candidates)
+                .expect("ballot ranking every candidate once is valid");
+            tally.remove_vote(&vote, Uint128::one());
+            tally.add_vote(vote, Uint128::one());
+        }
         // compute even though this will always be Winner::None so
         // that creating a tally has the same compute cost of adding a
         // vote which is needed so that gas(proposal_creation) >=
@@ -74,7 +94,8 @@ impl Tally {
     /// Records a vote in the tally. The tally must not be expired.
     ///
     ///  - `vote` a list of candidates sorted in order from most to
-    ///    least favored
+    ///    least favored. candidates not in the list are tied for
+    ///    last place.
     ///  - `power` the voting power of the voter
     ///
     /// Invariants:
@@ -82,22 +103,63 @@ impl Tally {
     /// - Voter has not already voted.
     /// - Tally is not expired.
     pub fn add_vote(&mut self, vote: Vote, power: Uint128) {
+        self.record_defeats(&vote, |m, defeat| m.decrement(defeat, power));
+        self.power_outstanding -= power;
+        self.winner = self.winner();
+    }
+
+    /// Removes a previously recorded vote from the tally, returning
+    /// its voting power to the outstanding power. Used when revoting
+    /// to undo a voter's prior ballot before recording their new one.
+    ///
+    /// Invariants:
+    ///
+    /// - `vote` and `power` are exactly what was previously passed to
+    ///   `add_vote`.
+    /// - Tally is not expired.
+    pub fn remove_vote(&mut self, vote: &Vote, power: Uint128) {
+        self.record_defeats(vote, |m, defeat| m.increment(defeat, power));
+        self.power_outstanding += power;
+        self.winner = self.winner();
+    }
+
+    /// Calls `record` with every (loser, winner) pair in `vote`.
+    fn record_defeats(&mut self, vote: &Vote, mut record: impl FnMut(&mut M, (u32, u32))) {
+        let mut ranked = vec![false; self.m.n as usize];
         for (index, preference) in vote.iter().enumerate() {
+            ranked[*preference as usize] = true;
             // an interesting property of the symetry of M is that in
             // recording all the defeats, we also record all of the
             // victories.
             for defeat in 0..index {
-                self.m.decrement((*preference, vote[defeat]), power)
+                record(&mut self.m, (*preference, vote[defeat]))
             }
         }
-        self.power_outstanding -= power;
-        self.winner = self.winner();
+        // every ranked candidate defeats the unranked ones, which
+        // are tied with each other.
+        for unranked in (0..self.m.n).filter(|c| !ranked[*c as usize]) {
+            for preference in vote.iter() {
+                record(&mut self.m, (unranked, *preference))
+            }
+        }
+    }
+
+    /// The winner of this tally according to the Schulze method, if
+    /// cycle resolution is enabled and there is a unique one. Only
+    /// meaningful once the tally has expired and there is no
+    /// Condorcet winner.
+    pub fn fallback_winner(&self) -> Option<u32> {
+        if self.resolve_cycles {
+            self.m.schulze_winner()
+        } else {
+            None
+        }
     }
 
     fn winner(&self) -> Winner {
         match self.m.stats(self.power_outstanding) {
             Stats::PositiveColumn { col, min_margin } => {
-                if min_margin > self.power_outstanding {
+                if min_margin > self.power_outstanding && !self.allow_revoting {
                     Winner::Undisputed(col)
                 } else {
                     Winner::Some(col)
@@ -106,7 +168,7 @@ impl Tally {
             Stats::NoPositiveColumn {
                 no_winnable_columns,
             } => {
-                if no_winnable_columns {
+                if no_winnable_columns && !self.allow_revoting && !self.resolve_cycles {
                     Winner::Never
                 } else {
                     Winner::None
@@ -0,0 +1,91 @@
+use dao_proposal_hook_counter::msg::QueryMsg as CounterQueryMsg;
+
+use crate::{msg::ExecuteMsg, proposal::Status, testing::suite::unimportant_message};
+
+use super::{is_error, suite::SuiteBuilder};
+
+#[test]
+fn test_only_dao_may_manage_hooks() {
+    let mut suite = SuiteBuilder::default().build();
+    let counter = suite.add_hook_counter();
+
+    assert_eq!(
+        suite.query_proposal_hooks().hooks,
+        vec![counter.to_string()]
+    );
+    assert_eq!(suite.query_vote_hooks().hooks, vec![counter.to_string()]);
+
+    let res = suite.execute_msg(
+        suite.sender(),
+        &ExecuteMsg::RemoveProposalHook {
+            address: counter.to_string(),
+        },
+    );
+    is_error!(res, "only the DAO my perform this action");
+
+    suite
+        .execute_msg(
+            suite.core.clone(),
+            &ExecuteMsg::RemoveVoteHook {
+                address: counter.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        suite.query_proposal_hooks().hooks,
+        vec![counter.to_string()]
+    );
+    assert!(suite.query_vote_hooks().hooks.is_empty());
+}
+
+#[test]
+fn test_hooks_fire_over_proposal_lifecycle() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("other", 10)])
+        .build();
+    let counter = suite.add_hook_counter();
+
+    suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::ProposalCounter {}),
+        1
+    );
+
+    suite.vote("sender", 1, vec![0, 1]).unwrap();
+    suite.vote("other", 1, vec![0, 1]).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::VoteCounter {}),
+        2
+    );
+
+    suite.a_day_passes();
+    suite.execute(suite.sender(), 1).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::StatusChangedCounter {}),
+        1
+    );
+
+    suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    // nobody votes and the proposal expires without reaching quorum.
+    suite.a_week_passes();
+    suite.close(suite.sender(), 2).unwrap();
+
+    let (_, status) = suite.query_winner_and_status(2);
+    assert_eq!(status, Status::Closed);
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::ProposalCounter {}),
+        2
+    );
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::VoteCounter {}),
+        2
+    );
+    assert_eq!(
+        suite.query_hook_count(&counter, CounterQueryMsg::StatusChangedCounter {}),
+        2
+    );
+}
@@ -1,6 +1,6 @@
-use cosmwasm_std::Decimal;
+use cosmwasm_std::{testing::mock_dependencies, Decimal};
 use cw_utils::Duration;
-use dao_voting::threshold::PercentageThreshold;
+use dao_voting::{executor::ExecutorPolicy, threshold::PercentageThreshold};
 
 use crate::config::UncheckedConfig;
 
@@ -13,7 +13,8 @@ fn test_instantiation() {
     let suite = SuiteBuilder::default().build();
     let config = suite.query_config();
 
-    assert_eq!(config, default_config.into_checked().unwrap())
+    let deps = mock_dependencies();
+    assert_eq!(config, default_config.into_checked(deps.as_ref()).unwrap())
 }
 
 #[test]
@@ -24,6 +25,10 @@ fn test_instantiate_conflicting_proposal_durations() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        resolve_cycles: false,
+        veto: None,
+        executor_policy: ExecutorPolicy::Members {},
     })
     .build();
 }
@@ -38,6 +43,10 @@ fn test_instantiate_conflicting_duration_types() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(9)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        resolve_cycles: false,
+        veto: None,
+        executor_policy: ExecutorPolicy::Members {},
     })
     .build();
 }
@@ -49,6 +58,10 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        resolve_cycles: false,
+        veto: None,
+        executor_policy: ExecutorPolicy::Members {},
     })
     .build();
     SuiteBuilder::with_config(UncheckedConfig {
@@ -56,6 +69,10 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
         close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        resolve_cycles: false,
+        veto: None,
+        executor_policy: ExecutorPolicy::Members {},
     })
     .build();
 }
@@ -1,7 +1,11 @@
+mod hooks;
 mod instantiation;
+mod pre_propose;
 mod proposals;
+mod queries;
 mod suite;
 mod tallying;
+mod veto;
 
 // Advantage to using a macro for this is that the error trace links
 // to the exact line that the error occured, instead of inside of a
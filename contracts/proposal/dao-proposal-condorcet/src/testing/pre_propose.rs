@@ -0,0 +1,64 @@
+use dao_voting::deposit::DepositRefundPolicy;
+
+use crate::{proposal::Status, testing::suite::unimportant_message, ContractError};
+
+use super::{is_error, suite::SuiteBuilder};
+
+#[test]
+fn test_deposit_refunded_on_completion() {
+    let mut suite = SuiteBuilder::default()
+        .with_deposit(10, DepositRefundPolicy::Always)
+        .with_proposal(1)
+        .build();
+    let pre_propose = suite.pre_propose.clone().unwrap();
+
+    assert_eq!(suite.balance(suite.sender()), 0);
+    assert_eq!(suite.balance(pre_propose.clone()), 10);
+    assert_eq!(suite.query_proposal(1).proposal.proposer, suite.sender());
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+    suite.execute(suite.sender(), 1).unwrap();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+    assert_eq!(suite.balance(suite.sender()), 10);
+    assert_eq!(suite.balance(pre_propose), 0);
+}
+
+#[test]
+fn test_deposit_kept_on_rejection() {
+    let mut suite = SuiteBuilder::default()
+        .with_deposit(10, DepositRefundPolicy::OnlyPassed)
+        .with_proposal(1)
+        .build();
+
+    suite.a_week_passes();
+    suite.close(suite.sender(), 1).unwrap();
+
+    // the deposit is sent to the DAO.
+    assert_eq!(suite.balance(suite.sender()), 0);
+    assert_eq!(suite.balance(suite.core.clone()), 10);
+}
+
+#[test]
+fn test_direct_proposal_rejected_with_module() {
+    let mut suite = SuiteBuilder::default()
+        .with_deposit(10, DepositRefundPolicy::Always)
+        .build();
+
+    let res = suite.propose(suite.sender(), vec![vec![unimportant_message()]]);
+    is_error!(res, &ContractError::Unauthorized {}.to_string());
+
+    // without paying the deposit the module won't create the proposal.
+    suite
+        .propose_with_deposit(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap_err();
+
+    suite.mint(suite.sender(), 10);
+    suite
+        .propose_with_deposit(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    assert_eq!(suite.query_next_proposal_id(), 2);
+    assert_eq!(suite.balance(suite.sender()), 0);
+}
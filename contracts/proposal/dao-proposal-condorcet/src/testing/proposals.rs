@@ -1,5 +1,6 @@
 use cosmwasm_std::{to_json_binary, WasmMsg};
 use cw_utils::Duration;
+use dao_voting::executor::ExecutorPolicy;
 
 use crate::{
     config::UncheckedConfig,
@@ -176,6 +177,10 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: None,
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    resolve_cycles: false,
+                    veto: None,
+                    executor_policy: ExecutorPolicy::Members {},
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -199,6 +204,10 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: Some(Duration::Height(10)),
                     close_proposals_on_execution_failure: false,
+                    allow_revoting: false,
+                    resolve_cycles: false,
+                    veto: None,
+                    executor_policy: ExecutorPolicy::Members {},
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -236,3 +245,123 @@ fn test_execution_fail_handling() {
     // no state changes get committed.
     suite.execute(suite.sender(), 1).unwrap_err();
 }
+
+#[test]
+fn test_revoting() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("other", 5)])
+        .with_revoting()
+        .with_proposal(2)
+        .build();
+
+    suite.vote("sender", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("other", 1, vec![0, 1, 2]).unwrap();
+
+    // with revoting enabled a winner is never undisputed as votes
+    // may still change.
+    let (winner, _) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Some(0));
+
+    let err = suite.vote("sender", 1, vec![0, 1, 2]);
+    is_error!(err, &ContractError::AlreadyCast {}.to_string());
+
+    suite.vote("sender", 1, vec![1, 0, 2]).unwrap();
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Some(1));
+    assert_eq!(status, Status::Open);
+
+    // the proposal only passes once its voting period ends.
+    suite.a_day_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Open);
+
+    suite.a_week_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 1 });
+
+    let err = suite.vote("other", 1, vec![1, 0, 2]);
+    is_error!(err, &ContractError::Expired {}.to_string());
+}
+
+#[test]
+fn test_revoting_disabled() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("other", 5)])
+        .with_proposal(2)
+        .build();
+
+    suite.vote("sender", 1, vec![0, 1, 2]).unwrap();
+    let err = suite.vote("sender", 1, vec![1, 0, 2]);
+    is_error!(err, &ContractError::Voted {}.to_string());
+}
+
+// a cycle with no condorcet winner is resolved with the schulze
+// method once the proposal expires.
+#[test]
+fn test_cycle_resolution() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[
+            ("blue", 10),
+            ("violet", 10),
+            ("magenta", 10),
+            ("gold", 10),
+            ("crimson", 10),
+            ("turquoise", 10),
+        ])
+        .with_cycle_resolution()
+        .with_proposal(2)
+        .build();
+
+    suite.vote("blue", 1, vec![0, 2, 1]).unwrap();
+    suite.vote("violet", 1, vec![1, 0, 2]).unwrap();
+    suite.vote("magenta", 1, vec![2, 1, 0]).unwrap();
+    suite.vote("gold", 1, vec![1, 0, 2]).unwrap();
+    suite.vote("crimson", 1, vec![0, 2]).unwrap();
+    suite.vote("turquoise", 1, vec![2, 0]).unwrap();
+
+    suite.a_day_passes();
+
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::None);
+    assert_eq!(status, Status::Open);
+
+    suite.a_week_passes();
+
+    // 0 and 1 tie, but 0 beats 2 which beats 1.
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::None);
+    assert_eq!(status, Status::Passed { winner: 0 });
+}
+
+#[test]
+fn test_executor_policy() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("member", 10)])
+        .with_executor_policy(ExecutorPolicy::Proposer {})
+        .with_proposal(1)
+        .build();
+
+    suite.vote("sender", 1, vec![0, 1]).unwrap();
+    suite.vote("member", 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    // members other than the proposer may not execute.
+    let err = suite.execute("member", 1);
+    is_error!(err, &ContractError::NotExecutor {}.to_string());
+    suite.execute(suite.sender(), 1).unwrap();
+
+    // an allowlisted address may execute without voting power.
+    let mut suite = SuiteBuilder::default()
+        .with_executor_policy(ExecutorPolicy::Allowlist {
+            addresses: vec!["bot".to_string()],
+        })
+        .with_proposal(1)
+        .build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &ContractError::NotExecutor {}.to_string());
+    suite.execute("bot", 1).unwrap();
+}
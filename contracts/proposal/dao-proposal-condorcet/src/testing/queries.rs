@@ -0,0 +1,80 @@
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::{
+    msg::QueryMsg,
+    proposal::ProposalListResponse,
+    testing::suite::unimportant_message,
+    vote::{VoteListResponse, VoteResponse},
+};
+
+use super::suite::SuiteBuilder;
+
+#[test]
+fn test_list_proposals() {
+    let mut suite = SuiteBuilder::default().build();
+    for _ in 0..3 {
+        suite
+            .propose(suite.sender(), vec![vec![unimportant_message()]])
+            .unwrap();
+    }
+
+    let count: u64 = suite.query(&QueryMsg::ProposalCount {});
+    assert_eq!(count, 3);
+
+    let ProposalListResponse { proposals } = suite.query(&QueryMsg::ListProposals {
+        start_after: Some(1),
+        limit: None,
+    });
+    let ids: Vec<u64> = proposals.iter().map(|p| p.proposal.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+
+    let ProposalListResponse { proposals } = suite.query(&QueryMsg::ReverseProposals {
+        start_before: None,
+        limit: Some(2),
+    });
+    let ids: Vec<u64> = proposals.iter().map(|p| p.proposal.id).collect();
+    assert_eq!(ids, vec![3, 2]);
+}
+
+#[test]
+fn test_list_votes() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("alice", 5), ("bob", 1)])
+        .with_proposal(2)
+        .build();
+
+    suite.vote("sender", 1, vec![0, 1, 2]).unwrap();
+    suite.vote("bob", 1, vec![2, 1, 0]).unwrap();
+
+    let VoteResponse { vote } = suite.query(&QueryMsg::GetVote {
+        proposal_id: 1,
+        voter: "bob".to_string(),
+    });
+    let vote = vote.unwrap();
+    assert_eq!(vote.voter, Addr::unchecked("bob"));
+    assert_eq!(vote.vote.to_string(), "2,1,0");
+    assert_eq!(vote.power, Uint128::new(1));
+
+    let VoteResponse { vote } = suite.query(&QueryMsg::GetVote {
+        proposal_id: 1,
+        voter: "alice".to_string(),
+    });
+    assert!(vote.is_none());
+
+    let VoteListResponse { votes } = suite.query(&QueryMsg::ListVotes {
+        proposal_id: 1,
+        start_after: None,
+        limit: None,
+    });
+    let voters: Vec<&str> = votes.iter().map(|v| v.voter.as_str()).collect();
+    assert_eq!(voters, vec!["bob", "sender"]);
+
+    let VoteListResponse { votes } = suite.query(&QueryMsg::ListVotes {
+        proposal_id: 1,
+        start_after: Some("bob".to_string()),
+        limit: Some(1),
+    });
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].voter, suite.sender());
+    assert_eq!(votes[0].power, Uint128::new(10));
+}
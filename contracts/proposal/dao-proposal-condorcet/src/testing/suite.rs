@@ -1,35 +1,49 @@
-use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal};
-use cw_multi_test::{next_block, App, Executor};
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty};
+use cw_denom::UncheckedDenom;
+use cw_hooks::HooksResponse;
+use cw_multi_test::{next_block, App, BankSudo, Executor, SudoMsg};
 use cw_utils::Duration;
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
     voting::InfoResponse,
 };
 use dao_testing::contracts::{
-    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, proposal_condorcet_contract,
+    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, pre_propose_condorcet_contract,
+    proposal_condorcet_contract,
+};
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::PercentageThreshold,
+    veto::VetoConfig,
 };
-use dao_voting::threshold::PercentageThreshold;
 use dao_voting_cw4::msg::GroupContract;
 
 use crate::{
     config::{Config, UncheckedConfig},
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
-    msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{Choice, ExecuteMsg, InstantiateMsg, ProposeMsg, QueryMsg},
     proposal::{ProposalResponse, Status},
     tally::Winner,
 };
 
+pub(crate) const DEPOSIT_DENOM: &str = "ujuno";
+
 pub(crate) struct Suite {
     app: App,
     sender: Addr,
     pub condorcet: Addr,
     pub core: Addr,
+    pub pre_propose: Option<Addr>,
 }
 
 pub(crate) struct SuiteBuilder {
-    pub instantiate: InstantiateMsg,
+    pub instantiate: UncheckedConfig,
     with_proposal: Option<u32>,
     with_voters: Vec<(String, u64)>,
+    with_deposit: Option<(u128, DepositRefundPolicy)>,
 }
 
 impl Default for SuiteBuilder {
@@ -40,9 +54,14 @@ impl Default for SuiteBuilder {
                 voting_period: Duration::Time(60 * 60 * 24 * 7),
                 min_voting_period: Some(Duration::Time(60 * 60 * 24)),
                 close_proposals_on_execution_failure: true,
+                allow_revoting: false,
+                resolve_cycles: false,
+                veto: None,
+                executor_policy: ExecutorPolicy::Members {},
             },
             with_proposal: None,
             with_voters: vec![("sender".to_string(), 10)],
+            with_deposit: None,
         }
     }
 }
@@ -65,6 +84,33 @@ impl SuiteBuilder {
         self
     }
 
+    pub fn with_revoting(mut self) -> Self {
+        self.instantiate.allow_revoting = true;
+        self
+    }
+
+    pub fn with_veto(mut self, veto: VetoConfig) -> Self {
+        self.instantiate.veto = Some(veto);
+        self
+    }
+
+    pub fn with_executor_policy(mut self, executor_policy: ExecutorPolicy) -> Self {
+        self.instantiate.executor_policy = executor_policy;
+        self
+    }
+
+    pub fn with_cycle_resolution(mut self) -> Self {
+        self.instantiate.resolve_cycles = true;
+        self
+    }
+
+    /// Attaches a pre-propose module requiring a deposit of `amount`
+    /// `DEPOSIT_DENOM` to create a proposal.
+    pub fn with_deposit(mut self, amount: u128, refund_policy: DepositRefundPolicy) -> Self {
+        self.with_deposit = Some((amount, refund_policy));
+        self
+    }
+
     pub fn build(self) -> Suite {
         let initial_members: Vec<_> = self
             .with_voters
@@ -79,6 +125,50 @@ impl SuiteBuilder {
         let cw4_id = app.store_code(cw4_group_contract());
         let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
 
+        let pre_propose_info = match self.with_deposit {
+            Some((amount, ref refund_policy)) => PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: app.store_code(pre_propose_condorcet_contract()),
+                    msg: to_json_binary(&dao_pre_propose_base::msg::InstantiateMsg {
+                        deposit_info: Some(UncheckedDepositInfo {
+                            denom: DepositToken::Token {
+                                denom: UncheckedDenom::Native(DEPOSIT_DENOM.to_string()),
+                            },
+                            amount: amount.into(),
+                            refund_policy: refund_policy.clone(),
+                            alternatives: vec![],
+                            pricing: None,
+                            keeper_share: None,
+                        }),
+                        open_proposal_submission: false,
+                        rate_limit: None,
+                        proposal_threshold: None,
+                        submission_roles: None,
+                        message_templates: None,
+                        extension: Empty::default(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    funds: vec![],
+                    label: "pre-propose module".to_string(),
+                },
+            },
+            None => PreProposeInfo::AnyoneMayPropose {},
+        };
+        let instantiate = InstantiateMsg {
+            quorum: self.instantiate.quorum,
+            voting_period: self.instantiate.voting_period,
+            min_voting_period: self.instantiate.min_voting_period,
+            close_proposals_on_execution_failure: self
+                .instantiate
+                .close_proposals_on_execution_failure,
+            allow_revoting: self.instantiate.allow_revoting,
+            resolve_cycles: self.instantiate.resolve_cycles,
+            veto: self.instantiate.veto.clone(),
+            executor_policy: self.instantiate.executor_policy.clone(),
+            pre_propose_info,
+        };
+
         let core_instantiate = dao_interface::msg::InstantiateMsg {
             admin: None,
             name: "core module".to_string(),
@@ -101,7 +191,7 @@ impl SuiteBuilder {
             },
             proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
                 code_id: condorcet_id,
-                msg: to_json_binary(&self.instantiate).unwrap(),
+                msg: to_json_binary(&instantiate).unwrap(),
                 admin: Some(Admin::CoreModule {}),
                 funds: vec![],
                 label: "condorcet module".to_string(),
@@ -133,25 +223,40 @@ impl SuiteBuilder {
 
         app.update_block(next_block);
 
+        let pre_propose = match app
+            .wrap()
+            .query_wasm_smart(&condorcet, &QueryMsg::ProposalCreationPolicy {})
+            .unwrap()
+        {
+            ProposalCreationPolicy::Anyone {} => None,
+            ProposalCreationPolicy::Module { addr } => Some(addr),
+        };
+
         let mut suite = Suite {
             app,
             sender,
             condorcet,
             core,
+            pre_propose,
         };
 
         let next_id = suite.query_next_proposal_id();
         assert_eq!(next_id, 1);
 
         if let Some(candidates) = self.with_proposal {
-            suite
-                .propose(
-                    &suite.sender(),
-                    (0..candidates)
-                        .map(|_| vec![unimportant_message()])
-                        .collect(),
-                )
-                .unwrap();
+            let sender = suite.sender();
+            let choices = (0..candidates)
+                .map(|_| vec![unimportant_message()])
+                .collect();
+            match self.with_deposit {
+                Some((amount, _)) => {
+                    suite.mint(&sender, amount);
+                    suite.propose_with_deposit(&sender, choices).unwrap();
+                }
+                None => {
+                    suite.propose(&sender, choices).unwrap();
+                }
+            }
             let next_id = suite.query_next_proposal_id();
             assert_eq!(next_id, 2);
         }
@@ -189,10 +294,73 @@ impl Suite {
     pub fn sender(&self) -> Addr {
         self.sender.clone()
     }
+
+    pub fn mint<S: Into<String>>(&mut self, addr: S, amount: u128) {
+        self.app
+            .sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: addr.into(),
+                amount: coins(amount, DEPOSIT_DENOM),
+            }))
+            .unwrap();
+    }
+
+    pub fn balance<S: Into<String>>(&self, addr: S) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(addr, DEPOSIT_DENOM)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    /// Instantiates a proposal hook counter and adds it as both a
+    /// proposal and vote hook receiver.
+    pub fn add_hook_counter(&mut self) -> Addr {
+        let code_id = self
+            .app
+            .store_code(Box::new(cw_multi_test::ContractWrapper::new(
+                dao_proposal_hook_counter::contract::execute,
+                dao_proposal_hook_counter::contract::instantiate,
+                dao_proposal_hook_counter::contract::query,
+            )));
+        let counter = self
+            .app
+            .instantiate_contract(
+                code_id,
+                self.core.clone(),
+                &dao_proposal_hook_counter::msg::InstantiateMsg {
+                    should_error: false,
+                },
+                &[],
+                "hook counter",
+                None,
+            )
+            .unwrap();
+        for msg in [
+            ExecuteMsg::AddProposalHook {
+                address: counter.to_string(),
+            },
+            ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        ] {
+            self.app
+                .execute_contract(self.core.clone(), self.condorcet.clone(), &msg, &[])
+                .unwrap();
+        }
+        counter
+    }
 }
 
 // query
 impl Suite {
+    pub fn query<T: DeserializeOwned>(&self, msg: &QueryMsg) -> T {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, msg)
+            .unwrap()
+    }
+
     pub fn query_config(&self) -> Config {
         self.app
             .wrap()
@@ -200,19 +368,19 @@ impl Suite {
             .unwrap()
     }
 
-    pub fn query_proposal(&self, id: u32) -> ProposalResponse {
+    pub fn query_proposal(&self, id: u64) -> ProposalResponse {
         self.app
             .wrap()
             .query_wasm_smart(&self.condorcet, &QueryMsg::Proposal { id })
             .unwrap()
     }
 
-    pub fn query_winner_and_status(&self, id: u32) -> (Winner, Status) {
+    pub fn query_winner_and_status(&self, id: u64) -> (Winner, Status) {
         let q = self.query_proposal(id);
         (q.tally.winner, q.proposal.last_status())
     }
 
-    pub fn query_next_proposal_id(&self) -> u32 {
+    pub fn query_next_proposal_id(&self) -> u64 {
         self.app
             .wrap()
             .query_wasm_smart(&self.condorcet, &QueryMsg::NextProposalId {})
@@ -226,6 +394,30 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_proposal_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::ProposalHooks {})
+            .unwrap()
+    }
+
+    pub fn query_vote_hooks(&self) -> HooksResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::VoteHooks {})
+            .unwrap()
+    }
+
+    pub fn query_hook_count(
+        &self,
+        counter: &Addr,
+        query: dao_proposal_hook_counter::msg::QueryMsg,
+    ) -> u64 {
+        let count: dao_proposal_hook_counter::msg::CountResponse =
+            self.app.wrap().query_wasm_smart(counter, &query).unwrap();
+        count.count
+    }
+
     pub fn query_info(&self) -> InfoResponse {
         self.app
             .wrap()
@@ -240,23 +432,61 @@ impl Suite {
         &mut self,
         sender: S,
         choices: Vec<Vec<CosmosMsg>>,
-    ) -> anyhow::Result<u32> {
+    ) -> anyhow::Result<u64> {
         let id = self.query_next_proposal_id();
         self.app.execute_contract(
             Addr::unchecked(sender),
             self.condorcet.clone(),
-            &ExecuteMsg::Propose {
+            &ExecuteMsg::Propose(ProposeMsg {
                 choices: choices.into_iter().map(|msgs| Choice { msgs }).collect(),
-            },
+                proposer: None,
+            }),
             &[],
         )?;
         Ok(id)
     }
 
+    /// Creates a proposal through the pre-propose module, paying its
+    /// deposit.
+    pub fn propose_with_deposit<S: Into<String>>(
+        &mut self,
+        sender: S,
+        choices: Vec<Vec<CosmosMsg>>,
+    ) -> anyhow::Result<u64> {
+        let id = self.query_next_proposal_id();
+        let pre_propose = self.pre_propose.clone().expect("no pre-propose module");
+        let config: dao_pre_propose_condorcet::Config = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &pre_propose,
+                &dao_pre_propose_condorcet::QueryMsg::Config {},
+            )
+            .unwrap();
+        let funds = match config.deposit_info {
+            Some(info) => vec![Coin {
+                denom: DEPOSIT_DENOM.to_string(),
+                amount: info.amount,
+            }],
+            None => vec![],
+        };
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose,
+            &dao_pre_propose_condorcet::ExecuteMsg::Propose {
+                msg: dao_pre_propose_condorcet::ProposeMessage::Propose {
+                    choices: choices.into_iter().map(|msgs| Choice { msgs }).collect(),
+                },
+            },
+            &funds,
+        )?;
+        Ok(id)
+    }
+
     pub fn vote<S: Into<String>>(
         &mut self,
         sender: S,
-        proposal_id: u32,
+        proposal_id: u64,
         vote: Vec<u32>,
     ) -> anyhow::Result<()> {
         self.app
@@ -269,7 +499,7 @@ impl Suite {
             .map(|_| ())
     }
 
-    pub fn execute<S: Into<String>>(&mut self, sender: S, proposal_id: u32) -> anyhow::Result<()> {
+    pub fn execute<S: Into<String>>(&mut self, sender: S, proposal_id: u64) -> anyhow::Result<()> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender),
@@ -280,7 +510,17 @@ impl Suite {
             .map(|_| ())
     }
 
-    pub fn close<S: Into<String>>(&mut self, sender: S, proposal_id: u32) -> anyhow::Result<()> {
+    pub fn execute_msg<S: Into<String>>(
+        &mut self,
+        sender: S,
+        msg: &ExecuteMsg,
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), self.condorcet.clone(), msg, &[])
+            .map(|_| ())
+    }
+
+    pub fn close<S: Into<String>>(&mut self, sender: S, proposal_id: u64) -> anyhow::Result<()> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender),
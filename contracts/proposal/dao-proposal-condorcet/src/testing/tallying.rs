@@ -9,7 +9,14 @@ use crate::{
 #[test]
 fn test_pair_election() {
     let candidates = 2;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
 
     tally.add_vote(Vote::new(vec![0, 1], candidates).unwrap(), Uint128::one());
     tally.add_vote(Vote::new(vec![1, 0], candidates).unwrap(), Uint128::one());
@@ -21,7 +28,14 @@ fn test_pair_election() {
 #[test]
 fn test_triplet_election() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
 
     tally.add_vote(
         Vote::new(vec![0, 1, 2], candidates).unwrap(),
@@ -45,7 +59,14 @@ fn test_triplet_election() {
 #[test]
 fn test_condorcet_paradox() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(6), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(6),
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
 
     tally.add_vote(
         Vote::new(vec![0, 2, 1], candidates).unwrap(),
@@ -96,7 +117,14 @@ fn test_condorcet_paradox() {
 #[test]
 fn test_tally_overflow() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::MAX, 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::MAX,
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -117,7 +145,14 @@ fn test_tally_overflow() {
 #[test]
 fn test_winner_none() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(9),
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -151,3 +186,68 @@ fn test_winner_none() {
     // -2 -2  2  2  4  \
     assert_eq!(tally.winner, Winner::None)
 }
+
+#[test]
+fn test_remove_vote() {
+    let candidates = 3;
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        true,
+        false,
+    );
+
+    tally.add_vote(
+        Vote::new(vec![0, 1, 2], candidates).unwrap(),
+        Uint128::new(2),
+    );
+    assert_eq!(tally.winner, Winner::Some(0));
+
+    let revoted = tally.clone();
+
+    tally.remove_vote(
+        &Vote::new(vec![0, 1, 2], candidates).unwrap(),
+        Uint128::new(2),
+    );
+    assert_eq!(tally.winner, Winner::None);
+    assert_eq!(tally.power_outstanding, Uint128::new(3));
+    assert_eq!(
+        tally,
+        Tally::new(
+            candidates,
+            Uint128::new(3),
+            0,
+            Expiration::Never {},
+            true,
+            false
+        )
+    );
+
+    tally.add_vote(
+        Vote::new(vec![0, 1, 2], candidates).unwrap(),
+        Uint128::new(2),
+    );
+    assert_eq!(tally, revoted);
+}
+
+#[test]
+fn test_partial_ranking() {
+    let candidates = 3;
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        false,
+        false,
+    );
+
+    // 2 is preferred to both unranked candidates, which tie.
+    tally.add_vote(Vote::new(vec![2], candidates).unwrap(), Uint128::one());
+    assert_eq!(tally.winner, Winner::Some(2));
+
+    tally.add_vote(Vote::new(vec![0], candidates).unwrap(), Uint128::new(2));
+    assert_eq!(tally.winner, Winner::Undisputed(0));
+}
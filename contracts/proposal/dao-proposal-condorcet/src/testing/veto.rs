@@ -0,0 +1,165 @@
+use cosmwasm_std::{testing::mock_env, Addr, Decimal, Uint128};
+use cw_utils::Duration;
+use dao_voting::{
+    executor::ExecutorPolicy,
+    threshold::PercentageThreshold,
+    veto::{VetoConfig, VetoError},
+};
+
+use crate::{
+    config::Config,
+    msg::{Choice, ExecuteMsg},
+    proposal::{Proposal, Status},
+    tally::Tally,
+    vote::Vote,
+    ContractError,
+};
+
+use super::{is_error, suite::SuiteBuilder};
+
+fn veto_config(early_execute: bool, veto_before_passed: bool) -> VetoConfig {
+    VetoConfig {
+        timelock_duration: Duration::Time(60 * 60 * 24),
+        vetoer: "vetoer".to_string(),
+        early_execute,
+        veto_before_passed,
+    }
+}
+
+#[test]
+fn test_timelock_then_execute() {
+    let mut suite = SuiteBuilder::default()
+        .with_veto(veto_config(false, false))
+        .with_proposal(1)
+        .build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_day_passes();
+
+    // the proposal has passed but is locked until a day after voting
+    // ends.
+    let (_, status) = suite.query_winner_and_status(1);
+    assert!(matches!(status, Status::VetoTimelock { winner: 0, .. }));
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &VetoError::Timelocked {}.to_string());
+
+    let err = suite.execute("vetoer", 1);
+    is_error!(err, &VetoError::NoEarlyExecute {}.to_string());
+
+    suite.a_week_passes();
+    suite.a_day_passes();
+
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 0 });
+
+    let err = suite.execute_msg("vetoer", &ExecuteMsg::Veto { proposal_id: 1 });
+    is_error!(err, &VetoError::TimelockExpired {}.to_string());
+
+    suite.execute(suite.sender(), 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::ExecutionFailed);
+}
+
+#[test]
+fn test_veto_timelocked_proposal() {
+    let mut suite = SuiteBuilder::default()
+        .with_veto(veto_config(false, false))
+        .with_proposal(1)
+        .build();
+
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+
+    // veto_before_passed is disabled.
+    let err = suite.execute_msg("vetoer", &ExecuteMsg::Veto { proposal_id: 1 });
+    is_error!(err, &VetoError::NoVetoBeforePassed {}.to_string());
+
+    suite.a_day_passes();
+
+    let err = suite.execute_msg(suite.sender(), &ExecuteMsg::Veto { proposal_id: 1 });
+    is_error!(err, &VetoError::Unauthorized {}.to_string());
+
+    suite
+        .execute_msg("vetoer", &ExecuteMsg::Veto { proposal_id: 1 })
+        .unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    let err = suite.execute(suite.sender(), 1);
+    is_error!(err, &ContractError::Unexecutable {}.to_string());
+}
+
+#[test]
+fn test_veto_early_execute_and_before_passed() {
+    let mut suite = SuiteBuilder::default()
+        .with_veto(veto_config(true, true))
+        .with_proposal(1)
+        .build();
+
+    suite
+        .execute_msg("vetoer", &ExecuteMsg::Veto { proposal_id: 1 })
+        .unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Vetoed);
+
+    suite.propose(suite.sender(), vec![vec![], vec![]]).unwrap();
+    suite.vote(suite.sender(), 2, vec![0, 1, 2]).unwrap();
+    suite.a_day_passes();
+
+    // the vetoer may execute early without voting power.
+    suite.execute("vetoer", 2).unwrap();
+    let (_, status) = suite.query_winner_and_status(2);
+    assert_eq!(status, Status::Executed);
+}
+
+#[test]
+#[should_panic(
+    expected = "The veto timelock duration must have the same units as the max_voting_period of the proposal (height or time)."
+)]
+fn test_veto_duration_units_must_match() {
+    SuiteBuilder::default()
+        .with_veto(VetoConfig {
+            timelock_duration: Duration::Height(10),
+            ..veto_config(false, false)
+        })
+        .build();
+}
+
+#[test]
+fn test_timelock_unit_mismatch_errors() {
+    // configs are validated so that the timelock and voting period
+    // units match, so this may only happen to a proposal constructed
+    // directly.
+    let block = mock_env().block;
+    let config = Config {
+        quorum: PercentageThreshold::Percent(Decimal::percent(15)),
+        voting_period: Duration::Height(10),
+        min_voting_period: None,
+        close_proposals_on_execution_failure: true,
+        allow_revoting: false,
+        resolve_cycles: false,
+        veto: Some(veto_config(false, false)),
+        executor_policy: ExecutorPolicy::Anyone {},
+    };
+    let mut tally = Tally::new(
+        2,
+        Uint128::new(10),
+        block.height,
+        config.voting_period.after(&block),
+        false,
+        false,
+    );
+    tally.add_vote(Vote::new(vec![0, 1], 2).unwrap(), Uint128::new(10));
+    let proposal = Proposal::new(
+        &block,
+        &config,
+        Addr::unchecked("ekez"),
+        1,
+        vec![Choice { msgs: vec![] }, Choice { msgs: vec![] }],
+        Uint128::new(10),
+    );
+
+    // the proposal has an undisputed winner, but must not skip the
+    // timelock because its expiration can not be computed.
+    proposal.status(&block, &tally).unwrap_err();
+}
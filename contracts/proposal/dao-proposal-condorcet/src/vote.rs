@@ -1,14 +1,43 @@
 use std::ops::Index;
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
 use thiserror::Error;
 
 #[cw_serde]
 pub struct Vote(Vec<u32>);
 
+/// A vote as stored alongside the voting power it was cast with so
+/// that it may be removed from the tally when revoting.
+#[cw_serde]
+pub struct Ballot {
+    pub vote: Vote,
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct VoteInfo {
+    pub voter: Addr,
+    pub vote: Vote,
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub vote: Option<VoteInfo>,
+}
+
+#[cw_serde]
+pub struct VoteListResponse {
+    pub votes: Vec<VoteInfo>,
+}
+
 impl Vote {
     pub(crate) fn new(vote: Vec<u32>, candidates: u32) -> Result<Self, VoteError> {
-        if vote.len() != candidates as usize {
+        if vote.is_empty() {
+            return Err(VoteError::Empty {});
+        }
+        if vote.len() > candidates as usize {
             return Err(VoteError::LenMissmatch {
                 got: vote.len() as u32,
                 expected: candidates,
@@ -32,6 +61,13 @@ impl Vote {
     }
 }
 
+impl std::fmt::Display for Vote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranking: Vec<String> = self.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", ranking.join(","))
+    }
+}
+
 impl Index<usize> for Vote {
     type Output = u32;
 
@@ -48,8 +84,11 @@ pub enum VoteError {
     #[error("no such candidate ({candidate})")]
     InvalidCandidate { candidate: u32 },
 
-    #[error("ballot has wrong number of candidates. got ({got}) expected ({expected})")]
+    #[error("ballot has too many candidates. got ({got}) expected at most ({expected})")]
     LenMissmatch { got: u32, expected: u32 },
+
+    #[error("ballot must rank at least one candidate")]
+    Empty {},
 }
 
 #[cfg(test)]
@@ -75,6 +114,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_partial_vote() {
+        assert_eq!(Vote::new(vec![], 2).unwrap_err(), VoteError::Empty {});
+        let vote = Vote::new(vec![2], 3).unwrap();
+        assert_eq!(vote.0, vec![2])
+    }
+
     #[test]
     fn test_vote_construction() {
         let vote = Vote::new(vec![0, 1, 2], 3).unwrap();
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Reply, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
@@ -15,9 +15,17 @@ use dao_hooks::proposal::{
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::{
-    multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy},
+    executor::ExecutorPolicy,
+    keeper::UncheckedKeeperBounty,
+    multiple_choice::{
+        budget_transfer_amount, MultipleChoiceOptionType, MultipleChoiceVote, MultipleChoiceVotes,
+        VotingStrategy,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    quadratic::{
+        quadratic_weight, spend_credits, QuadraticMode, QuadraticVoting, QuadraticVotingError,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
@@ -26,13 +34,18 @@ use dao_voting::{
     voting::{get_total_power, get_voting_power, validate_voting_period},
 };
 
-use crate::{msg::MigrateMsg, state::CREATION_POLICY};
+use crate::{
+    msg::MigrateMsg,
+    state::{CREATION_POLICY, LEGACY_CONFIG},
+};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
     query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    ranked_choice::{add_ranking, remove_ranking, tally_rankings},
     state::{
-        Ballot, Config, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+        Ballot, Config, BALLOTS, CONFIG, CREDITS_SPENT, KEEPER_BOUNTY, PROPOSALS, PROPOSAL_COUNT,
+        PROPOSAL_HOOKS, VOTE_HOOKS,
     },
     ContractError,
 };
@@ -50,6 +63,7 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.voting_strategy.validate()?;
+    validate_budget_denom(deps.as_ref(), &msg.voting_strategy)?;
 
     let dao = info.sender;
 
@@ -60,20 +74,27 @@ pub fn instantiate(
         .pre_propose_info
         .into_initial_policy_and_messages(dao.clone())?;
 
+    msg.executor_policy.validate(&deps.as_ref())?;
+
     // if veto is configured, validate its fields
     if let Some(veto_config) = &msg.veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(quadratic_voting) = &msg.quadratic_voting {
+        quadratic_voting.validate(&deps.as_ref())?;
+    }
+
     let config = Config {
         voting_strategy: msg.voting_strategy,
         min_voting_period,
         max_voting_period,
-        only_members_execute: msg.only_members_execute,
+        executor_policy: msg.executor_policy,
         allow_revoting: msg.allow_revoting,
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        quadratic_voting: msg.quadratic_voting,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -102,6 +123,33 @@ pub fn execute(
             vote,
             rationale,
         } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::VoteMultiple {
+            proposal_id,
+            option_ids,
+            rationale,
+        } => execute_vote_multiple(
+            deps,
+            env,
+            info.sender,
+            proposal_id,
+            option_ids,
+            rationale,
+            None,
+        ),
+        ExecuteMsg::VoteWithCredits {
+            proposal_id,
+            option_ids,
+            votes,
+            rationale,
+        } => execute_vote_multiple(
+            deps,
+            env,
+            info.sender,
+            proposal_id,
+            option_ids,
+            rationale,
+            Some(votes),
+        ),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
@@ -109,22 +157,24 @@ pub fn execute(
             voting_strategy,
             min_voting_period,
             max_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
         } => execute_update_config(
             deps,
             info,
             voting_strategy,
             min_voting_period,
             max_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -139,6 +189,9 @@ pub fn execute(
         ExecuteMsg::RemoveVoteHook { address } => {
             execute_remove_vote_hook(deps, env, info, address)
         }
+        ExecuteMsg::UpdateKeeperBounty { bounty } => {
+            execute_update_keeper_bounty(deps, env, info, bounty)
+        }
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
@@ -197,10 +250,46 @@ pub fn execute_propose(
 
     // Validate options.
     let checked_multiple_choice_options = choices.into_checked()?.options;
+    if let VotingStrategy::Budget { denom, budget, .. } = &config.voting_strategy {
+        for choice in checked_multiple_choice_options
+            .iter()
+            .filter(|choice| choice.option_type == MultipleChoiceOptionType::Standard)
+        {
+            match choice.requested_amount {
+                None => {
+                    return Err(ContractError::MissingRequestedAmount {
+                        option: choice.index,
+                    })
+                }
+                Some(amount) if amount > *budget => {
+                    return Err(ContractError::RequestExceedsBudget {
+                        option: choice.index,
+                        budget: *budget,
+                    })
+                }
+                Some(requested) => match budget_transfer_amount(denom, &choice.msgs) {
+                    Some(transferred) if transferred == requested => (),
+                    Some(transferred) => {
+                        return Err(ContractError::RequestedAmountMismatch {
+                            option: choice.index,
+                            requested,
+                            transferred,
+                        })
+                    }
+                    None => {
+                        return Err(ContractError::InvalidBudgetMessage {
+                            option: choice.index,
+                        })
+                    }
+                },
+            }
+        }
+    }
 
     let expiration = config.max_voting_period.after(&env.block);
     let total_power = get_total_power(deps.as_ref(), &config.dao, None)?;
 
+    let id = advance_proposal_id(deps.storage)?;
     let proposal = {
         // Limit mutability to this block.
         let mut proposal = MultipleChoiceProposal {
@@ -217,13 +306,16 @@ pub fn execute_propose(
             allow_revoting: config.allow_revoting,
             choices: checked_multiple_choice_options,
             veto: config.veto,
+            quadratic_voting: config.quadratic_voting,
+            turnout: Uint128::zero(),
+            runoff_result: None,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
+        tally_rankings(deps.storage, &env.block, id, &mut proposal)?;
         proposal.update_status(&env.block)?;
         proposal
     };
-    let id = advance_proposal_id(deps.storage)?;
 
     // Limit the size of proposals.
     //
@@ -301,6 +393,7 @@ pub fn execute_veto(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     // ensure status is up to date
+    tally_rankings(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
     let old_status = prop.status;
 
@@ -354,7 +447,7 @@ pub fn execute_veto(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status, None)?;
 
     Ok(Response::new()
         .add_attribute("action", "veto")
@@ -370,16 +463,58 @@ pub fn execute_vote(
     proposal_id: u64,
     vote: MultipleChoiceVote,
     rationale: Option<String>,
+) -> Result<Response<Empty>, ContractError> {
+    execute_vote_multiple(
+        deps,
+        env,
+        sender,
+        proposal_id,
+        vec![vote.option_id],
+        rationale,
+        None,
+    )
+}
+
+/// Records `sender`'s vote for `option_ids` on a proposal. `votes`
+/// is the number of votes to cast on proposals using credit
+/// quadratic voting.
+pub fn execute_vote_multiple(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    option_ids: Vec<u32>,
+    rationale: Option<String>,
+    votes: Option<Uint128>,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let mut prop = PROPOSALS
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
-    // Check that this is a valid vote.
-    if vote.option_id as usize >= prop.choices.len() {
+    // Check that this is a valid vote. Each option may only be
+    // selected once, and "None of the above" may not be selected
+    // along with other options.
+    let max_selections = prop.voting_strategy.max_selections();
+    if option_ids.len() > max_selections as usize {
+        return Err(ContractError::TooManySelections {
+            max: max_selections,
+        });
+    }
+    if option_ids.is_empty() {
         return Err(ContractError::InvalidVote {});
     }
+    for (i, option_id) in option_ids.iter().enumerate() {
+        let choice = prop
+            .choices
+            .get(*option_id as usize)
+            .ok_or(ContractError::InvalidVote {})?;
+        if option_ids[..i].contains(option_id)
+            || (choice.option_type == MultipleChoiceOptionType::None && option_ids.len() > 1)
+        {
+            return Err(ContractError::InvalidVote {});
+        }
+    }
 
     // Allow voting on proposals until they expire.
     // Voting on a non-open proposal will never change
@@ -402,38 +537,114 @@ pub fn execute_vote(
         return Err(ContractError::NotRegistered {});
     }
 
-    BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
-        Some(current_ballot) => {
-            if prop.allow_revoting {
-                if current_ballot.vote == vote {
-                    // Don't allow casting the same vote more than
-                    // once. This seems liable to be confusing
-                    // behavior.
-                    Err(ContractError::AlreadyCast {})
-                } else {
-                    // Remove the old vote if this is a re-vote.
-                    prop.votes
-                        .remove_vote(current_ballot.vote, current_ballot.power)?;
-                    Ok(Ballot {
-                        power: vote_power,
-                        vote,
-                        rationale: rationale.clone(),
-                    })
+    let current_ballot = BALLOTS.may_load(deps.storage, (proposal_id, &sender))?;
+    if current_ballot.is_none() && prop.tracks_turnout() {
+        prop.turnout += vote_power;
+    }
+
+    // Quadratic proposals count the square root of the voter's
+    // voting power, or the number of votes bought with their
+    // credits, provided they pass the identity gate. Credits are
+    // recorded as spent once the ballot is known to be valid.
+    let mut credits_spent = None;
+    let vote_power = match &prop.quadratic_voting {
+        Some(quadratic_voting) => {
+            quadratic_voting.check_identity(deps.as_ref(), &sender, prop.start_height)?;
+            match quadratic_voting.mode {
+                QuadraticMode::PerBallot {} => {
+                    if votes.is_some() {
+                        return Err(QuadraticVotingError::NotCreditVoting {}.into());
+                    }
+                    quadratic_weight(vote_power)
+                }
+                QuadraticMode::Credits {} => {
+                    let available =
+                        available_credits(deps.storage, &env, &sender, proposal_id, vote_power)?;
+                    let (votes, cost) = spend_credits(available, votes)?;
+                    credits_spent = Some(cost);
+                    votes
                 }
-            } else {
-                Err(ContractError::AlreadyVoted {})
             }
         }
-        None => Ok(Ballot {
-            vote,
-            power: vote_power,
-            rationale: rationale.clone(),
-        }),
-    })?;
+        None if votes.is_some() => {
+            return Err(QuadraticVotingError::NotCreditVoting {}.into());
+        }
+        None => vote_power,
+    };
+
+    let vote = MultipleChoiceVote {
+        option_id: option_ids[0],
+    };
+    let ballot = Ballot {
+        power: vote_power,
+        vote,
+        rationale: rationale.clone(),
+        option_ids: if option_ids.len() > 1 {
+            option_ids.clone()
+        } else {
+            vec![]
+        },
+    };
+
+    if let Some(current_ballot) = current_ballot {
+        if !prop.allow_revoting {
+            return Err(ContractError::AlreadyVoted {});
+        }
+        // The order of selected options is only meaningful for
+        // ranked choice proposals.
+        let same_selection = if prop.is_ranked_choice() {
+            current_ballot.selected_options() == option_ids
+        } else {
+            let normalize = |mut ids: Vec<u32>| {
+                ids.sort_unstable();
+                ids.dedup();
+                ids
+            };
+            normalize(current_ballot.selected_options()) == normalize(option_ids.clone())
+        };
+        if same_selection && current_ballot.power == vote_power {
+            // Don't allow casting the same vote more than
+            // once. This seems liable to be confusing
+            // behavior.
+            return Err(ContractError::AlreadyCast {});
+        }
+        // Remove the old vote if this is a re-vote.
+        let selected_options = current_ballot.selected_options();
+        for option_id in prop.counted_options(&selected_options) {
+            prop.votes.remove_vote(
+                MultipleChoiceVote {
+                    option_id: *option_id,
+                },
+                current_ballot.power,
+            )?;
+        }
+        if prop.is_ranked_choice() {
+            remove_ranking(
+                deps.storage,
+                proposal_id,
+                &selected_options,
+                current_ballot.power,
+            )?;
+        }
+    }
+    BALLOTS.save(deps.storage, (proposal_id, &sender), &ballot)?;
+    if let Some(cost) = credits_spent {
+        CREDITS_SPENT.save(deps.storage, (&sender, proposal_id), &cost)?;
+    }
 
     let old_status = prop.status;
 
-    prop.votes.add_vote(vote, vote_power)?;
+    for option_id in prop.counted_options(&option_ids) {
+        prop.votes.add_vote(
+            MultipleChoiceVote {
+                option_id: *option_id,
+            },
+            vote_power,
+        )?;
+    }
+    if prop.is_ranked_choice() {
+        add_ranking(deps.storage, proposal_id, &option_ids, vote_power)?;
+    }
     prop.update_status(&env.block)?;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
     let new_status = prop.status;
@@ -444,12 +655,17 @@ pub fn execute_vote(
         old_status.to_string(),
         new_status.to_string(),
     )?;
+    let position = option_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
     let vote_hooks = new_vote_hooks(
         VOTE_HOOKS,
         deps.storage,
         proposal_id,
         sender.to_string(),
-        vote.to_string(),
+        position.clone(),
     )?;
     Ok(Response::default()
         .add_submessages(change_hooks)
@@ -457,7 +673,7 @@ pub fn execute_vote(
         .add_attribute("action", "vote")
         .add_attribute("sender", sender)
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("position", vote.to_string())
+        .add_attribute("position", position)
         .add_attribute(
             "rationale",
             rationale.unwrap_or_else(|| "_none".to_string()),
@@ -465,6 +681,40 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Returns the credits `voter` may spend on `proposal_id`: their
+/// voting power less the credits spent on other proposals that are
+/// still open for voting. Spends on expired proposals are released
+/// and removed from storage. Credits already spent on `proposal_id`
+/// are not counted, as a revote replaces them.
+fn available_credits(
+    storage: &mut dyn Storage,
+    env: &Env,
+    voter: &Addr,
+    proposal_id: u64,
+    power: Uint128,
+) -> Result<Uint128, ContractError> {
+    let spends = CREDITS_SPENT
+        .prefix(voter)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut locked = Uint128::zero();
+    for (id, spent) in spends {
+        if id == proposal_id {
+            continue;
+        }
+        let expired = match PROPOSALS.may_load(storage, id)? {
+            Some(prop) => prop.expiration.is_expired(&env.block),
+            None => true,
+        };
+        if expired {
+            CREDITS_SPENT.remove(storage, (voter, id));
+        } else {
+            locked += spent;
+        }
+    }
+    Ok(power.saturating_sub(locked))
+}
+
 pub fn execute_execute(
     deps: DepsMut,
     env: Env,
@@ -478,23 +728,20 @@ pub fn execute_execute(
     let config = CONFIG.load(deps.storage)?;
 
     // determine if this sender can execute
-    let mut sender_can_execute = true;
-    if config.only_members_execute {
-        let power = get_voting_power(
-            deps.as_ref(),
-            info.sender.clone(),
-            &config.dao,
-            Some(prop.start_height),
-        )?;
-
-        sender_can_execute = !power.is_zero();
-    }
+    let sender_can_execute = config.executor_policy.can_execute(
+        deps.as_ref(),
+        &config.dao,
+        &info.sender,
+        &prop.proposer,
+        prop.start_height,
+    )?;
 
     // Check here that the proposal is passed or timelocked.
     // Allow it to be executed even if it is expired so long
     // as it passed during its voting period. Allow it to be
     // executed in timelock state if early_execute is enabled
     // and the sender is the vetoer.
+    tally_rankings(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
     let old_status = prop.status;
     match &prop.status {
@@ -533,54 +780,66 @@ pub fn execute_execute(
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
-    let vote_result = prop.calculate_vote_result()?;
-    match vote_result {
-        VoteResult::Tie => Err(ContractError::Tie {}), // We don't anticipate this case as the proposal would not be in passed state, checked above.
-        VoteResult::SingleWinner(winning_choice) => {
-            let response = if !winning_choice.msgs.is_empty() {
-                let execute_message = WasmMsg::Execute {
-                    contract_addr: config.dao.to_string(),
-                    msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
-                        msgs: winning_choice.msgs,
-                    })?,
-                    funds: vec![],
-                };
-                match config.close_proposal_on_execution_failure {
-                    true => {
-                        let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                        Response::default().add_submessage(SubMsg::reply_on_error(
-                            execute_message,
-                            masked_proposal_id,
-                        ))
-                    }
-                    false => Response::default().add_message(execute_message),
-                }
-            } else {
+    let msgs = match prop.calculate_vote_result()? {
+        // We don't anticipate this case as the proposal would not be
+        // in passed state, checked above.
+        VoteResult::Tie => return Err(ContractError::Tie {}),
+        VoteResult::SingleWinner(winning_choice) => winning_choice.msgs,
+        // Approval and budget allocation proposals execute the
+        // messages of every winning option in one call, in order of
+        // their votes.
+        VoteResult::Winners(winning_choices) => winning_choices
+            .into_iter()
+            .flat_map(|choice| choice.msgs)
+            .collect(),
+    };
+
+    let response = if !msgs.is_empty() {
+        let execute_message = WasmMsg::Execute {
+            contract_addr: config.dao.to_string(),
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            funds: vec![],
+        };
+        match config.close_proposal_on_execution_failure {
+            true => {
+                let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
                 Response::default()
-            };
+                    .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+            }
+            false => Response::default().add_message(execute_message),
+        }
+    } else {
+        Response::default()
+    };
 
-            let proposal_status_changed_hooks = proposal_status_changed_hooks(
-                PROPOSAL_HOOKS,
-                deps.storage,
-                proposal_id,
-                old_status.to_string(),
-                prop.status.to_string(),
-            )?;
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
 
-            // Add prepropose / deposit module hook which will handle deposit refunds.
-            let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-            let proposal_completed_hooks =
-                proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
-
-            Ok(response
-                .add_submessages(proposal_status_changed_hooks)
-                .add_submessages(proposal_completed_hooks)
-                .add_attribute("action", "execute")
-                .add_attribute("sender", info.sender)
-                .add_attribute("proposal_id", proposal_id.to_string())
-                .add_attribute("dao", config.dao))
-        }
-    }
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        proposal_id,
+        prop.status,
+        Some(&info.sender),
+    )?;
+
+    let (bounty_payout, bounty_attribute) = keeper_bounty(deps.as_ref(), &env, &info)?;
+
+    Ok(response
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_messages(bounty_payout)
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao)
+        .add_attribute("keeper_bounty", bounty_attribute))
 }
 
 pub fn execute_close(
@@ -591,6 +850,7 @@ pub fn execute_close(
 ) -> Result<Response<Empty>, ContractError> {
     let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
 
+    tally_rankings(deps.storage, &env.block, proposal_id, &mut prop)?;
     prop.update_status(&env.block)?;
     if prop.status != Status::Rejected {
         return Err(ContractError::WrongCloseStatus {});
@@ -612,15 +872,43 @@ pub fn execute_close(
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        proposal_id,
+        prop.status,
+        Some(&info.sender),
+    )?;
+
+    let (bounty_payout, bounty_attribute) = keeper_bounty(deps.as_ref(), &env, &info)?;
 
     Ok(Response::default()
         .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks)
+        .add_messages(bounty_payout)
         .add_attribute("action", "close")
         .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("keeper_bounty", bounty_attribute))
+}
+
+/// Returns the message paying the keeper bounty to the sender (if
+/// one is configured and this module's balance can cover it) along
+/// with the amount paid for use as an attribute.
+fn keeper_bounty(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+) -> StdResult<(Option<CosmosMsg>, String)> {
+    let payout = match KEEPER_BOUNTY.may_load(deps.storage)? {
+        Some(bounty) => bounty
+            .payout(deps, &env.contract.address, &info.sender, Uint128::zero())?
+            .map(|msg| (msg, bounty.amount)),
+        None => None,
+    };
+    Ok(match payout {
+        Some((msg, amount)) => (Some(msg), amount.to_string()),
+        None => (None, "_none".to_string()),
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -630,11 +918,12 @@ pub fn execute_update_config(
     voting_strategy: VotingStrategy,
     min_voting_period: Option<Duration>,
     max_voting_period: Duration,
-    only_members_execute: bool,
+    executor_policy: ExecutorPolicy,
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    quadratic_voting: Option<QuadraticVoting>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -644,28 +933,36 @@ pub fn execute_update_config(
     }
 
     voting_strategy.validate()?;
+    validate_budget_denom(deps.as_ref(), &voting_strategy)?;
 
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
 
+    executor_policy.validate(&deps.as_ref())?;
+
     // if veto is configured, validate its fields
     if let Some(veto_config) = &veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(quadratic_voting) = &quadratic_voting {
+        quadratic_voting.validate(&deps.as_ref())?;
+    }
+
     CONFIG.save(
         deps.storage,
         &Config {
             voting_strategy,
             min_voting_period,
             max_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
         },
     )?;
 
@@ -808,6 +1105,33 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_keeper_bounty(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    bounty: Option<UncheckedKeeperBounty>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let bounty = bounty
+        .map(|bounty| bounty.into_checked(deps.as_ref()))
+        .transpose()?;
+    match &bounty {
+        Some(bounty) => KEEPER_BOUNTY.save(deps.storage, bounty)?,
+        None => KEEPER_BOUNTY.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_keeper_bounty")
+        .add_attribute(
+            "keeper_bounty",
+            bounty.map_or_else(|| "_none".to_string(), |b| b.amount.to_string()),
+        ))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -830,6 +1154,18 @@ pub fn remove_hook(
     Ok(())
 }
 
+/// Checks that the budget of a budget allocation strategy is paid in
+/// a valid denom.
+fn validate_budget_denom(
+    deps: Deps,
+    voting_strategy: &VotingStrategy,
+) -> Result<(), ContractError> {
+    if let VotingStrategy::Budget { denom, .. } = voting_strategy {
+        denom.clone().into_checked(deps)?;
+    }
+    Ok(())
+}
+
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
     Ok(PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1)
 }
@@ -864,6 +1200,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::KeeperBounty {} => to_json_binary(&KEEPER_BOUNTY.may_load(deps.storage)?),
         QueryMsg::Dao {} => query_dao(deps),
     }
 }
@@ -879,7 +1216,8 @@ pub fn query_dao(deps: Deps) -> StdResult<Binary> {
 }
 
 pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
-    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+    tally_rankings(deps.storage, &env.block, id, &mut proposal)?;
     to_json_binary(&proposal.into_response(&env.block, id)?)
 }
 
@@ -943,6 +1281,7 @@ pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Bina
         vote: ballot.vote,
         power: ballot.power,
         rationale: ballot.rationale,
+        option_ids: ballot.option_ids,
     });
     to_json_binary(&VoteResponse { vote })
 }
@@ -970,6 +1309,7 @@ pub fn query_list_votes(
                 vote: ballot.vote,
                 power: ballot.power,
                 rationale: ballot.rationale,
+                option_ids: ballot.option_ids,
             })
         })
         .collect::<StdResult<Vec<_>>>()?;
@@ -1050,5 +1390,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Configs saved before executor policies were introduced store an
+    // `only_members_execute` flag in their place. A config in the
+    // current format will fail to load as a legacy config and is left
+    // as is.
+    if let Ok(legacy_config) = LEGACY_CONFIG.load(deps.storage) {
+        CONFIG.save(deps.storage, &legacy_config.into())?;
+    }
     Ok(Response::default())
 }
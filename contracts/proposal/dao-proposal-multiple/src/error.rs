@@ -1,7 +1,11 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
+use cw_denom::DenomError;
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
-use dao_voting::{reply::error::TagError, threshold::ThresholdError, veto::VetoError};
+use dao_voting::{
+    executor::ExecutorPolicyError, keeper::KeeperBountyError, quadratic::QuadraticVotingError,
+    reply::error::TagError, threshold::ThresholdError, veto::VetoError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -18,6 +22,18 @@ pub enum ContractError {
     #[error(transparent)]
     VetoError(#[from] VetoError),
 
+    #[error(transparent)]
+    ExecutorPolicyError(#[from] ExecutorPolicyError),
+
+    #[error(transparent)]
+    QuadraticVotingError(#[from] QuadraticVotingError),
+
+    #[error(transparent)]
+    KeeperBountyError(#[from] KeeperBountyError),
+
+    #[error(transparent)]
+    DenomError(#[from] DenomError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -78,6 +94,25 @@ pub enum ContractError {
     #[error("Invalid vote selected.")]
     InvalidVote {},
 
+    #[error("At most ({max}) options may be selected.")]
+    TooManySelections { max: u32 },
+
+    #[error("Option ({option}) must request an amount from the budget.")]
+    MissingRequestedAmount { option: u32 },
+
+    #[error("Option ({option}) requests more than the budget of ({budget}).")]
+    RequestExceedsBudget { option: u32, budget: Uint128 },
+
+    #[error("Option ({option}) requests ({requested}) but transfers ({transferred}) of the budget denom.")]
+    RequestedAmountMismatch {
+        option: u32,
+        requested: Uint128,
+        transferred: Uint128,
+    },
+
+    #[error("Option ({option}) may only transfer the budget denom.")]
+    InvalidBudgetMessage { option: u32 },
+
     #[error("Must have voting power to propose.")]
     MustHaveVotingPower {},
 
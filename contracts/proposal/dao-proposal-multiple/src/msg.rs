@@ -1,10 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
+    executor::ExecutorPolicy,
+    keeper::UncheckedKeeperBounty,
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg,
+    quadratic::QuadraticVoting,
     veto::VetoConfig,
 };
 
@@ -20,10 +24,8 @@ pub struct InstantiateMsg {
     pub min_voting_period: Option<Duration>,
     /// The amount of time a proposal can be voted on before expiring
     pub max_voting_period: Duration,
-    /// If set to true only members may execute passed
-    /// proposals. Otherwise, any address may execute a passed
-    /// proposal.
-    pub only_members_execute: bool,
+    /// Who may execute passed proposals.
+    pub executor_policy: ExecutorPolicy,
     /// Allows changing votes before the proposal expires. If this is
     /// enabled proposals will not be able to complete early as final
     /// vote information is not known until the time of proposal
@@ -45,6 +47,12 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, votes are
+    /// counted with the square root of the voter's voting power,
+    /// limiting the influence of large holders. May be combined with
+    /// an identity gate to make splitting voting power across many
+    /// addresses harder.
+    pub quadratic_voting: Option<QuadraticVoting>,
 }
 
 #[cw_serde]
@@ -63,6 +71,38 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Votes for several options on a proposal using the approval
+    /// or budget voting strategies, in which case each selected
+    /// option receives the sender's full voting power, or ranks
+    /// options on a ranked choice proposal.
+    VoteMultiple {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The options selected by the sender. At most the voting
+        /// strategy's `max_selections` options may be selected. For
+        /// ranked choice proposals, the options in order of
+        /// preference.
+        option_ids: Vec<u32>,
+        /// An optional rationale for why this vote was cast. This can
+        /// be updated, set, or removed later by the address casting
+        /// the vote.
+        rationale: Option<String>,
+    },
+    /// Votes on a proposal using credit quadratic voting, spending
+    /// `votes²` of the sender's credits to cast `votes` votes for
+    /// each of `option_ids`. A plain `Vote` or `VoteMultiple` on such
+    /// a proposal casts as many votes as the sender's credits
+    /// afford. Revoting replaces the previous spend.
+    VoteWithCredits {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The options selected by the sender, as in `VoteMultiple`.
+        option_ids: Vec<u32>,
+        /// The number of votes to cast.
+        votes: Uint128,
+        /// An optional rationale for why this vote was cast.
+        rationale: Option<String>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -96,10 +136,9 @@ pub enum ExecuteMsg {
         /// on before expiring. This will only apply to proposals
         /// created after the config update.
         max_voting_period: Duration,
-        /// If set to true only members may execute passed
-        /// proposals. Otherwise, any address may execute a passed
-        /// proposal. Applies to all outstanding and future proposals.
-        only_members_execute: bool,
+        /// Who may execute passed proposals. Applies to all
+        /// outstanding and future proposals.
+        executor_policy: ExecutorPolicy,
         /// Allows changing votes before the proposal expires. If this is
         /// enabled proposals will not be able to complete early as final
         /// vote information is not known until the time of proposal
@@ -119,6 +158,9 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// Optional quadratic voting configuration. This will only
+        /// apply to proposals created after the config update.
+        quadratic_voting: Option<QuadraticVoting>,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -143,6 +185,13 @@ pub enum ExecuteMsg {
     RemoveVoteHook {
         address: String,
     },
+    /// Sets the bounty paid to whoever executes or closes a
+    /// proposal. Bounties are paid out of this module's balance, so
+    /// the DAO must fund it for bounties to be paid. Setting this to
+    /// `None` disables bounties. Only the DAO may call this method.
+    UpdateKeeperBounty {
+        bounty: Option<UncheckedKeeperBounty>,
+    },
 }
 
 #[proposal_module_query]
@@ -190,6 +239,10 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the bounty paid to whoever executes or closes a
+    /// proposal, if any.
+    #[returns(Option<::dao_voting::keeper::KeeperBounty>)]
+    KeeperBounty {},
 }
 
 #[cw_serde]
@@ -7,12 +7,13 @@ use dao_voting::{
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
     },
+    quadratic::QuadraticVoting,
     status::Status,
     veto::VetoConfig,
     voting::does_vote_count_pass,
 };
 
-use crate::query::ProposalResponse;
+use crate::{query::ProposalResponse, ranked_choice::RunoffResult};
 
 #[cw_serde]
 pub struct MultipleChoiceProposal {
@@ -51,10 +52,27 @@ pub struct MultipleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, `votes` holds
+    /// the square root of each voter's voting power.
+    #[serde(default)]
+    pub quadratic_voting: Option<QuadraticVoting>,
+    /// The total (linear) voting power of addresses that have voted
+    /// on this proposal. Only tracked when quadratic voting is
+    /// enabled or voters may select several options, in which case
+    /// it is used to determine if quorum has been reached.
+    #[serde(default)]
+    pub turnout: Uint128,
+    /// The result of the instant runoff tally of a ranked choice
+    /// proposal. Set once the proposal has expired.
+    #[serde(default)]
+    pub runoff_result: Option<RunoffResult>,
 }
 
 pub enum VoteResult {
     SingleWinner(CheckedMultipleChoiceOption),
+    /// The winning options of an approval vote, or the funded options
+    /// of a budget allocation, ordered by number of votes.
+    Winners(Vec<CheckedMultipleChoiceOption>),
     Tie,
 }
 
@@ -66,8 +84,14 @@ impl MultipleChoiceProposal {
     /// the proposal expiring has changed its status. This method
     /// recomputes the status so that queries get accurate
     /// information.
+    ///
+    /// Expired ranked choice proposals that have not been tallied
+    /// keep their last status, as their outcome is not known until
+    /// the instant runoff tally runs.
     pub fn into_response(mut self, block: &BlockInfo, id: u64) -> StdResult<ProposalResponse> {
-        self.update_status(block)?;
+        if !self.needs_tally(block) {
+            self.update_status(block)?;
+        }
         Ok(ProposalResponse { id, proposal: self })
     }
 
@@ -112,6 +136,62 @@ impl MultipleChoiceProposal {
         Ok(())
     }
 
+    /// Whether the turnout of this proposal is tracked separately
+    /// from its votes. This is the case when votes are quadratic, as
+    /// they are not comparable with the total voting power, and when
+    /// voters may select several options, as the sum of the votes
+    /// counts their voting power more than once.
+    pub(crate) fn tracks_turnout(&self) -> bool {
+        self.quadratic_voting.is_some()
+            || matches!(
+                self.voting_strategy,
+                VotingStrategy::Approval { .. } | VotingStrategy::Budget { .. }
+            )
+    }
+
+    /// Whether this proposal uses ranked choice voting.
+    pub(crate) fn is_ranked_choice(&self) -> bool {
+        matches!(self.voting_strategy, VotingStrategy::RankedChoice { .. })
+    }
+
+    /// Whether this is a ranked choice proposal that has expired but
+    /// whose rankings have not been tallied yet.
+    pub(crate) fn needs_tally(&self, block: &BlockInfo) -> bool {
+        self.is_ranked_choice() && self.runoff_result.is_none() && self.expiration.is_expired(block)
+    }
+
+    /// The options of a ballot that its voting power is added to.
+    /// Ranked choice ballots are counted towards their first
+    /// preference, the remaining preferences are only used by the
+    /// instant runoff tally.
+    pub(crate) fn counted_options<'a>(&self, option_ids: &'a [u32]) -> &'a [u32] {
+        if self.is_ranked_choice() {
+            &option_ids[..1]
+        } else {
+            option_ids
+        }
+    }
+
+    /// The voting power used to determine if quorum has been
+    /// reached.
+    fn quorum_power(&self) -> Uint128 {
+        if self.tracks_turnout() {
+            self.turnout
+        } else {
+            self.votes.total()
+        }
+    }
+
+    /// Whether the outcome of this proposal may only be determined
+    /// once it has expired. This is the case when revoting is allowed
+    /// or turnout is tracked, as the weight of the remaining votes is
+    /// unknown, and for ranked choice proposals, which are tallied
+    /// once they expire.
+    fn is_outcome_deferred(&self, block: &BlockInfo) -> bool {
+        (self.allow_revoting || self.tracks_turnout() || self.is_ranked_choice())
+            && !self.expiration.is_expired(block)
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail). Passing in the case of multiple choice proposals
@@ -121,7 +201,7 @@ impl MultipleChoiceProposal {
     pub fn is_passed(&self, block: &BlockInfo) -> StdResult<bool> {
         // If re-voting is allowed nothing is known until the proposal
         // has expired.
-        if self.allow_revoting && !self.expiration.is_expired(block) {
+        if self.is_outcome_deferred(block) {
             return Ok(false);
         }
         // If the min voting period is set and not expired the
@@ -137,7 +217,7 @@ impl MultipleChoiceProposal {
 
         // Proposal can only pass if quorum has been met.
         if does_vote_count_pass(
-            self.votes.total(),
+            self.quorum_power(),
             self.total_power,
             self.voting_strategy.get_quorum(),
         ) {
@@ -145,6 +225,9 @@ impl MultipleChoiceProposal {
             match vote_result {
                 // Proposal is not passed if there is a tie.
                 VoteResult::Tie => return Ok(false),
+                // Approval and budget votes are only determined once the
+                // proposal has expired.
+                VoteResult::Winners(_) => return Ok(self.expiration.is_expired(block)),
                 VoteResult::SingleWinner(winning_choice) => {
                     // Proposal is not passed if winning choice is None.
                     if winning_choice.option_type != MultipleChoiceOptionType::None {
@@ -166,7 +249,7 @@ impl MultipleChoiceProposal {
     pub fn is_rejected(&self, block: &BlockInfo) -> StdResult<bool> {
         // If re-voting is allowed and the proposal is not expired no
         // information is known.
-        if self.allow_revoting && !self.expiration.is_expired(block) {
+        if self.is_outcome_deferred(block) {
             return Ok(false);
         }
 
@@ -179,10 +262,18 @@ impl MultipleChoiceProposal {
                     self.expiration.is_expired(block) || self.total_power == self.votes.total();
                 Ok(rejected)
             }
+            // Proposal is rejected if it has expired without reaching
+            // quorum.
+            VoteResult::Winners(_) => Ok(self.expiration.is_expired(block)
+                && !does_vote_count_pass(
+                    self.quorum_power(),
+                    self.total_power,
+                    self.voting_strategy.get_quorum(),
+                )),
             VoteResult::SingleWinner(winning_choice) => {
                 match (
                     does_vote_count_pass(
-                        self.votes.total(),
+                        self.quorum_power(),
                         self.total_power,
                         self.voting_strategy.get_quorum(),
                     ),
@@ -244,9 +335,108 @@ impl MultipleChoiceProposal {
                 }
                 Err(StdError::not_found("max vote weight"))
             }
+            VotingStrategy::Approval { winners, .. } => {
+                let (none_of_the_above, candidates) = self.approved_options()?;
+                if candidates.is_empty() {
+                    return Ok(VoteResult::SingleWinner(none_of_the_above));
+                }
+
+                // If the last winning option is tied with the first
+                // losing option the winners can not be determined.
+                let winners = winners as usize;
+                if candidates.len() > winners && candidates[winners - 1].1 == candidates[winners].1
+                {
+                    return Ok(VoteResult::Tie);
+                }
+
+                Ok(VoteResult::Winners(
+                    candidates
+                        .into_iter()
+                        .take(winners)
+                        .map(|(index, _)| self.choices[index].clone())
+                        .collect(),
+                ))
+            }
+            VotingStrategy::Budget { budget, .. } => {
+                let (none_of_the_above, candidates) = self.approved_options()?;
+
+                // Fund options in order of their votes, skipping
+                // those the remaining budget can not cover. Each
+                // funded option records the budget left before it
+                // was funded.
+                let mut remaining = budget;
+                let (mut funded, mut unfunded) = (vec![], vec![]);
+                for (index, weight) in candidates {
+                    let amount = self.choices[index].requested_amount.unwrap_or_default();
+                    if amount <= remaining {
+                        funded.push((index, weight, remaining));
+                        remaining -= amount;
+                    } else {
+                        unfunded.push((weight, amount));
+                    }
+                }
+                if funded.is_empty() {
+                    return Ok(VoteResult::SingleWinner(none_of_the_above));
+                }
+
+                // If a funded option is tied with an option that was
+                // not funded, but would have been had it been
+                // considered first, the allocation can not be
+                // determined.
+                if funded.iter().any(|(_, weight, available)| {
+                    unfunded
+                        .iter()
+                        .any(|(other, amount)| other == weight && amount <= available)
+                }) {
+                    return Ok(VoteResult::Tie);
+                }
+
+                Ok(VoteResult::Winners(
+                    funded
+                        .into_iter()
+                        .map(|(index, _, _)| self.choices[index].clone())
+                        .collect(),
+                ))
+            }
+            VotingStrategy::RankedChoice { .. } => match self.runoff_result {
+                Some(RunoffResult::Winner { option_id }) => Ok(VoteResult::SingleWinner(
+                    self.choices[option_id as usize].clone(),
+                )),
+                Some(RunoffResult::Tie {}) => Ok(VoteResult::Tie),
+                None => Err(StdError::generic_err(
+                    "ranked choice proposal has not been tallied",
+                )),
+            },
         }
     }
 
+    /// Returns the "None of the above" option and the index and
+    /// votes of every standard option with more votes than it, in
+    /// descending order of votes.
+    fn approved_options(&self) -> StdResult<(CheckedMultipleChoiceOption, Vec<(usize, Uint128)>)> {
+        let none_of_the_above = self
+            .choices
+            .iter()
+            .find(|choice| choice.option_type == MultipleChoiceOptionType::None)
+            .ok_or_else(|| StdError::not_found("none of the above option"))?;
+        let none_weight = self.votes.vote_weights[none_of_the_above.index as usize];
+
+        let mut candidates: Vec<(usize, Uint128)> = self
+            .votes
+            .vote_weights
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(index, weight)| {
+                self.choices[*index].option_type == MultipleChoiceOptionType::Standard
+                    && *weight > none_weight
+            })
+            .collect();
+        candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        Ok((none_of_the_above.clone(), candidates))
+    }
+
     /// Ensure that with the remaining vote power, the choice with the second highest votes
     /// cannot overtake the first choice.
     fn is_choice_unbeatable(
@@ -291,6 +481,7 @@ mod tests {
 
     use cosmwasm_std::testing::mock_env;
     use dao_voting::multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions};
+    use dao_voting::quadratic::QuadraticMode;
 
     fn create_proposal(
         block: &BlockInfo,
@@ -305,11 +496,13 @@ mod tests {
             MultipleChoiceOption {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 title: "title".to_string(),
             },
             MultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 title: "title".to_string(),
             },
         ];
@@ -336,6 +529,9 @@ mod tests {
             allow_revoting,
             min_voting_period: None,
             veto: None,
+            quadratic_voting: None,
+            turnout: Uint128::zero(),
+            runoff_result: None,
         }
     }
 
@@ -875,4 +1071,214 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    #[test]
+    fn test_quadratic_quorum_uses_turnout() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+        // A voter with 100 power votes for the second option, four
+        // voters with 9 power each vote for the first.
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(12), Uint128::new(10), Uint128::new(0)],
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(200),
+            false,
+            false,
+        );
+        prop.quadratic_voting = Some(QuadraticVoting {
+            identity_gate: None,
+            mode: QuadraticMode::PerBallot {},
+        });
+        prop.turnout = Uint128::new(136);
+
+        // Quadratic proposals are not determined until they expire.
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        prop.expiration = Expiration::AtHeight(env.block.height - 1);
+        assert!(prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        // The square root weighted votes alone would never reach
+        // quorum, the linear turnout is compared instead.
+        prop.turnout = Uint128::new(100);
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_approval_winners() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            max_selections: 2,
+            winners: 2,
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(30), Uint128::new(20), Uint128::new(5)],
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            false,
+            false,
+        );
+        prop.turnout = Uint128::new(55);
+
+        // Approval proposals are not determined until they expire.
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(!prop.is_rejected(&env.block).unwrap());
+
+        prop.expiration = Expiration::AtHeight(env.block.height - 1);
+        assert!(prop.is_passed(&env.block).unwrap());
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => assert_eq!(
+                winners.iter().map(|c| c.index).collect::<Vec<_>>(),
+                vec![0, 1]
+            ),
+            _ => panic!("expected winners"),
+        }
+
+        // Options with fewer votes than "None of the above" do not
+        // win, even if there are seats left.
+        prop.votes.vote_weights = vec![Uint128::new(30), Uint128::new(3), Uint128::new(5)];
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => {
+                assert_eq!(winners.iter().map(|c| c.index).collect::<Vec<_>>(), vec![0])
+            }
+            _ => panic!("expected winners"),
+        }
+
+        // If no option beats "None of the above" the proposal is
+        // rejected.
+        prop.votes.vote_weights = vec![Uint128::new(3), Uint128::new(2), Uint128::new(5)];
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::SingleWinner(CheckedMultipleChoiceOption {
+                option_type: MultipleChoiceOptionType::None,
+                ..
+            })
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // Quorum is measured with turnout.
+        prop.votes.vote_weights = vec![Uint128::new(30), Uint128::new(20), Uint128::new(5)];
+        prop.turnout = Uint128::new(40);
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_approval_tie_at_cutoff() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            max_selections: 2,
+            winners: 1,
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(30), Uint128::new(30), Uint128::new(5)],
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        prop.turnout = Uint128::new(60);
+
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Tie
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+
+        prop.voting_strategy = VotingStrategy::Approval {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            max_selections: 2,
+            winners: 2,
+        };
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Winners(_)
+        ));
+        assert!(prop.is_passed(&env.block).unwrap());
+    }
+
+    #[test]
+    fn test_budget_allocation() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::Budget {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+            denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+            budget: Uint128::new(100),
+            max_selections: 2,
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(20), Uint128::new(30), Uint128::new(5)],
+        };
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            true,
+            false,
+        );
+        prop.turnout = Uint128::new(55);
+        prop.choices[0].requested_amount = Some(Uint128::new(40));
+        prop.choices[1].requested_amount = Some(Uint128::new(70));
+
+        // The option with the most votes is funded first, leaving too
+        // little for the second.
+        assert!(prop.is_passed(&env.block).unwrap());
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => {
+                assert_eq!(winners.iter().map(|c| c.index).collect::<Vec<_>>(), vec![1])
+            }
+            _ => panic!("expected winners"),
+        }
+
+        // Every option the remaining budget covers is funded.
+        prop.choices[1].requested_amount = Some(Uint128::new(60));
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => assert_eq!(
+                winners.iter().map(|c| c.index).collect::<Vec<_>>(),
+                vec![1, 0]
+            ),
+            _ => panic!("expected winners"),
+        }
+
+        // A funded option tied with an unfunded one is a tie.
+        prop.choices[1].requested_amount = Some(Uint128::new(70));
+        prop.votes.vote_weights = vec![Uint128::new(30), Uint128::new(30), Uint128::new(5)];
+        assert!(matches!(
+            prop.calculate_vote_result().unwrap(),
+            VoteResult::Tie
+        ));
+        assert!(!prop.is_passed(&env.block).unwrap());
+        assert!(prop.is_rejected(&env.block).unwrap());
+
+        // A tie with an option the budget could never have covered
+        // does not change the allocation.
+        prop.choices[1].requested_amount = Some(Uint128::new(150));
+        match prop.calculate_vote_result().unwrap() {
+            VoteResult::Winners(winners) => {
+                assert_eq!(winners.iter().map(|c| c.index).collect::<Vec<_>>(), vec![0])
+            }
+            _ => panic!("expected winners"),
+        }
+        assert!(prop.is_passed(&env.block).unwrap());
+    }
 }
@@ -27,6 +27,9 @@ pub struct VoteInfo {
     pub power: Uint128,
     /// The rationale behind the vote.
     pub rationale: Option<String>,
+    /// Every option selected by the vote, if more than one option was
+    /// selected.
+    pub option_ids: Vec<u32>,
 }
 
 #[cw_serde]
@@ -0,0 +1,245 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Order, StdError, StdResult, Storage, Uint128};
+
+use crate::{proposal::MultipleChoiceProposal, state::RANKINGS};
+
+/// The outcome of an instant runoff tally.
+#[cw_serde]
+pub enum RunoffResult {
+    /// The option that received a majority of the votes remaining
+    /// after eliminations.
+    Winner { option_id: u32 },
+    /// Every remaining option received the same number of votes.
+    Tie {},
+}
+
+fn ranking_key(ranking: &[u32]) -> String {
+    ranking
+        .iter()
+        .map(|option_id| option_id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_ranking_key(key: &str) -> StdResult<Vec<u32>> {
+    key.split(',')
+        .map(|option_id| {
+            option_id
+                .parse()
+                .map_err(|_| StdError::parse_err("u32", format!("invalid ranking ({key})")))
+        })
+        .collect()
+}
+
+/// Adds `power` to the voting power behind `ranking` on a proposal.
+pub(crate) fn add_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    ranking: &[u32],
+    power: Uint128,
+) -> StdResult<()> {
+    RANKINGS.update(storage, (proposal_id, ranking_key(ranking)), |total| {
+        total
+            .unwrap_or_default()
+            .checked_add(power)
+            .map_err(StdError::overflow)
+    })?;
+    Ok(())
+}
+
+/// Removes `power` from the voting power behind `ranking` on a
+/// proposal.
+pub(crate) fn remove_ranking(
+    storage: &mut dyn Storage,
+    proposal_id: u64,
+    ranking: &[u32],
+    power: Uint128,
+) -> StdResult<()> {
+    let key = (proposal_id, ranking_key(ranking));
+    let total = RANKINGS
+        .load(storage, key.clone())?
+        .checked_sub(power)
+        .map_err(StdError::overflow)?;
+    if total.is_zero() {
+        RANKINGS.remove(storage, key);
+    } else {
+        RANKINGS.save(storage, key, &total)?;
+    }
+    Ok(())
+}
+
+/// Loads every distinct ranking on a proposal and the voting power
+/// behind it.
+fn load_rankings(storage: &dyn Storage, proposal_id: u64) -> StdResult<Vec<(Vec<u32>, Uint128)>> {
+    RANKINGS
+        .prefix(proposal_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (key, power) = item?;
+            Ok((parse_ranking_key(&key)?, power))
+        })
+        .collect()
+}
+
+/// Tallies the rankings of a ranked choice proposal once it has
+/// expired, if they have not been tallied yet. The result is stored
+/// on the proposal, so once the proposal is saved the tally is not
+/// run again.
+pub(crate) fn tally_rankings(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    proposal_id: u64,
+    proposal: &mut MultipleChoiceProposal,
+) -> StdResult<()> {
+    if !proposal.needs_tally(block) {
+        return Ok(());
+    }
+    let rankings = load_rankings(storage, proposal_id)?;
+    proposal.runoff_result = Some(instant_runoff(proposal.choices.len(), &rankings));
+    Ok(())
+}
+
+/// Determines the winner of a ranked choice vote by instant runoff.
+///
+/// Each ranking is counted towards its highest ranked option that has
+/// not been eliminated. If an option has a majority of the counted
+/// votes it wins, otherwise the options with the fewest votes are
+/// eliminated and the rankings counted again. Rankings whose options
+/// have all been eliminated are no longer counted.
+///
+/// There are at most as many rounds as options, and each round reads
+/// every ranking once, so the tally is bounded by the number of
+/// options times the number of distinct rankings. As ballots rank at
+/// most `MAX_RANKED_CHOICES` options, the number of distinct rankings
+/// does not grow with the number of voters.
+pub fn instant_runoff(num_options: usize, rankings: &[(Vec<u32>, Uint128)]) -> RunoffResult {
+    let mut eliminated = vec![false; num_options];
+    loop {
+        let mut votes = vec![Uint128::zero(); num_options];
+        for (ranking, power) in rankings {
+            if let Some(option_id) = ranking.iter().find(|id| !eliminated[**id as usize]) {
+                votes[*option_id as usize] += *power;
+            }
+        }
+        let total: Uint128 = votes.iter().sum();
+        let remaining: Vec<usize> = (0..num_options).filter(|i| !eliminated[*i]).collect();
+
+        if let Some(winner) = remaining.iter().find(|i| votes[**i] > total - votes[**i]) {
+            return RunoffResult::Winner {
+                option_id: *winner as u32,
+            };
+        }
+
+        // If every remaining option has the fewest votes, none of
+        // them may be eliminated.
+        let fewest = remaining.iter().map(|i| votes[*i]).min();
+        let losers: Vec<usize> = remaining
+            .iter()
+            .copied()
+            .filter(|i| Some(votes[*i]) == fewest)
+            .collect();
+        if losers.len() == remaining.len() {
+            return RunoffResult::Tie {};
+        }
+        for loser in losers {
+            eliminated[loser] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use dao_voting::multiple_choice::{MAX_NUM_CHOICES, MAX_RANKED_CHOICES};
+
+    use super::*;
+
+    fn ranking(option_ids: &[u32], power: u128) -> (Vec<u32>, Uint128) {
+        (option_ids.to_vec(), Uint128::new(power))
+    }
+
+    #[test]
+    fn test_first_round_majority() {
+        let rankings = vec![ranking(&[0, 1], 6), ranking(&[1, 0], 4)];
+        assert_eq!(
+            instant_runoff(3, &rankings),
+            RunoffResult::Winner { option_id: 0 }
+        );
+    }
+
+    #[test]
+    fn test_eliminated_votes_transfer() {
+        // Option 0 leads on first preferences, but voters for
+        // options 2 and 3 prefer option 1 to it.
+        let rankings = vec![
+            ranking(&[0], 40),
+            ranking(&[1, 0], 32),
+            ranking(&[2, 1], 23),
+            ranking(&[3, 2, 1], 5),
+        ];
+        assert_eq!(
+            instant_runoff(5, &rankings),
+            RunoffResult::Winner { option_id: 1 }
+        );
+    }
+
+    #[test]
+    fn test_exhausted_rankings() {
+        // Once option 2 is eliminated its votes are exhausted and
+        // option 0 has a majority of the remaining votes.
+        let rankings = vec![ranking(&[0], 40), ranking(&[1], 35), ranking(&[2], 25)];
+        assert_eq!(
+            instant_runoff(4, &rankings),
+            RunoffResult::Winner { option_id: 0 }
+        );
+    }
+
+    #[test]
+    fn test_tie() {
+        let rankings = vec![ranking(&[0, 2], 10), ranking(&[1, 2], 10)];
+        assert_eq!(instant_runoff(3, &rankings), RunoffResult::Tie {});
+        assert_eq!(instant_runoff(3, &[]), RunoffResult::Tie {});
+    }
+
+    #[test]
+    fn test_worst_case_rankings() {
+        // Store every ranking a voter may submit on a proposal with
+        // the maximum number of options. "None of the above" may only
+        // be ranked alone.
+        let standard = MAX_NUM_CHOICES;
+        let mut storage = MockStorage::new();
+        let mut rankings: Vec<Vec<u32>> = vec![vec![]];
+        for _ in 0..MAX_RANKED_CHOICES {
+            rankings = rankings
+                .into_iter()
+                .flat_map(|ranking| {
+                    (0..standard)
+                        .filter(|option_id| !ranking.contains(option_id))
+                        .map(|option_id| [ranking.clone(), vec![option_id]].concat())
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            for ranking in &rankings {
+                // Weight rankings by their first preference so that
+                // the tally runs for many rounds.
+                let power = Uint128::from(ranking[0] + 1);
+                add_ranking(&mut storage, 1, ranking, power).unwrap();
+            }
+        }
+        add_ranking(&mut storage, 1, &[standard], Uint128::one()).unwrap();
+
+        let stored = load_rankings(&storage, 1).unwrap();
+        assert_eq!(stored.len(), 20 + 20 * 19 + 20 * 19 * 18 + 1);
+        assert!(matches!(
+            instant_runoff(standard as usize + 1, &stored),
+            RunoffResult::Winner { .. }
+        ));
+    }
+
+    #[test]
+    fn test_ranking_key() {
+        let key = ranking_key(&[3, 0, 12]);
+        assert_eq!(key, "3,0,12");
+        assert_eq!(parse_ranking_key(&key).unwrap(), vec![3, 0, 12]);
+    }
+}
@@ -5,8 +5,11 @@ use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
+    executor::ExecutorPolicy,
+    keeper::KeeperBounty,
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
+    quadratic::QuadraticVoting,
     veto::VetoConfig,
 };
 
@@ -24,10 +27,8 @@ pub struct Config {
     /// The default maximum amount of time a proposal may be voted on
     /// before expiring.
     pub max_voting_period: Duration,
-    /// If set to true only members may execute passed
-    /// proposals. Otherwise, any address may execute a passed
-    /// proposal.
-    pub only_members_execute: bool,
+    /// Who may execute passed proposals.
+    pub executor_policy: ExecutorPolicy,
     /// Allows changing votes before the proposal expires. If this is
     /// enabled proposals will not be able to complete early as final
     /// vote information is not known until the time of proposal
@@ -47,6 +48,9 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, votes are
+    /// counted with the square root of the voter's voting power.
+    pub quadratic_voting: Option<QuadraticVoting>,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -54,17 +58,77 @@ pub struct Config {
 pub struct Ballot {
     /// The amount of voting power behind the vote.
     pub power: Uint128,
-    /// The position.
+    /// The position. If several options were selected, this is the
+    /// first of them.
     pub vote: MultipleChoiceVote,
     /// An optional rationale for why this vote was cast.
     pub rationale: Option<String>,
+    /// Every option selected by this ballot, for voting strategies
+    /// that allow selecting more than one option. Empty if `vote` is
+    /// the only option selected.
+    #[serde(default)]
+    pub option_ids: Vec<u32>,
+}
+
+impl Ballot {
+    /// The options selected by this ballot.
+    pub fn selected_options(&self) -> Vec<u32> {
+        if self.option_ids.is_empty() {
+            vec![self.vote.option_id]
+        } else {
+            self.option_ids.clone()
+        }
+    }
+}
+
+/// The module's configuration as saved before executor policies were
+/// introduced. Used to migrate the stored config.
+#[cw_serde]
+pub struct LegacyConfig {
+    pub voting_strategy: VotingStrategy,
+    pub min_voting_period: Option<Duration>,
+    pub max_voting_period: Duration,
+    pub only_members_execute: bool,
+    pub allow_revoting: bool,
+    pub dao: Addr,
+    pub close_proposal_on_execution_failure: bool,
+    pub veto: Option<VetoConfig>,
+    pub quadratic_voting: Option<QuadraticVoting>,
+}
+
+impl From<LegacyConfig> for Config {
+    fn from(legacy: LegacyConfig) -> Self {
+        Config {
+            voting_strategy: legacy.voting_strategy,
+            min_voting_period: legacy.min_voting_period,
+            max_voting_period: legacy.max_voting_period,
+            executor_policy: legacy.only_members_execute.into(),
+            allow_revoting: legacy.allow_revoting,
+            dao: legacy.dao,
+            close_proposal_on_execution_failure: legacy.close_proposal_on_execution_failure,
+            veto: legacy.veto,
+            quadratic_voting: legacy.quadratic_voting,
+        }
+    }
 }
 
 /// The current top level config for the module.
 pub const CONFIG: Item<Config> = Item::new("config");
+/// The config as saved before executor policies were introduced. Shares
+/// its key with `CONFIG`.
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The total voting power behind each distinct ranking cast on a
+/// ranked choice proposal, keyed by the ranked option IDs joined with
+/// commas. Identical rankings are aggregated so the instant runoff
+/// tally reads at most one entry per distinct ranking.
+pub const RANKINGS: Map<(u64, String), Uint128> = Map::new("rankings");
+/// The credits each voter has spent on proposals using credit
+/// quadratic voting. Entries are removed once the proposal they were
+/// spent on expires.
+pub const CREDITS_SPENT: Map<(&Addr, u64), Uint128> = Map::new("credits_spent");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -72,3 +136,6 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The bounty paid to whoever executes or closes a proposal (if
+/// any).
+pub const KEEPER_BOUNTY: Item<KeeperBounty> = Item::new("keeper_bounty");
@@ -15,6 +15,7 @@ use cw_multi_test::{next_block, App, Executor};
 use cw_utils::Duration;
 use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     multiple_choice::{
         MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
     },
@@ -43,11 +44,13 @@ fn setup_test(_messages: Vec<CosmosMsg>) -> CommonTest {
             title: "title 1".to_string(),
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
         },
         MultipleChoiceOption {
             title: "title 2".to_string(),
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
         },
     ];
 
@@ -268,7 +271,7 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
         },
         max_voting_period: Duration::Time(604800),
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(
             &mut app,
@@ -278,10 +281,14 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
                 },
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     };
 
@@ -322,11 +329,13 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
                 funds: vec![],
             }
             .into()],
+            requested_amount: None,
         },
         MultipleChoiceOption {
             title: "title 2".to_string(),
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
         },
     ];
 
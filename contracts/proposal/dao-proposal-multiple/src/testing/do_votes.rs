@@ -6,6 +6,7 @@ use dao_interface::state::ProposalModule;
 use dao_testing::ShouldExecute;
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
     multiple_choice::{
         MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
     },
@@ -125,10 +126,11 @@ where
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info,
         veto: None,
     };
@@ -194,11 +196,13 @@ where
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -276,6 +280,7 @@ where
                             _ => weight,
                         },
                         rationale: None,
+                        option_ids: vec![],
                     }),
                 };
                 assert_eq!(vote, expected)
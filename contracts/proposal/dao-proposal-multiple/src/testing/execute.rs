@@ -38,11 +38,7 @@ pub fn make_proposal(
         } => {
             let deposit_config = query_pre_proposal_multiple_config(app, pre_propose);
             match deposit_config.deposit_info {
-                Some(CheckedDepositInfo {
-                    denom,
-                    amount,
-                    refund_policy: _,
-                }) => match denom {
+                Some(CheckedDepositInfo { denom, amount, .. }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
                         // Give an allowance, no funds.
@@ -11,6 +11,7 @@ use dao_testing::contracts::{
 };
 use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     multiple_choice::VotingStrategy,
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, ActiveThreshold::AbsoluteCount, PercentageThreshold},
@@ -35,6 +36,10 @@ fn get_pre_propose_info(
             msg: to_json_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -53,7 +58,7 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         voting_strategy,
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(
             app,
@@ -63,10 +68,14 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
                 },
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     }
 }
@@ -80,10 +89,11 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         voting_strategy,
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     }
 }
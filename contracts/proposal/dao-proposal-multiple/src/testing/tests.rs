@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg,
 };
 use cw20::Cw20Coin;
 use cw_denom::{CheckedDenom, UncheckedDenom};
@@ -15,6 +15,8 @@ use dao_voting::{
         CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo,
         VotingModuleTokenType,
     },
+    executor::ExecutorPolicy,
+    keeper::{KeeperBounty, UncheckedKeeperBounty},
     multiple_choice::{
         CheckedMultipleChoiceOption, MultipleChoiceOption, MultipleChoiceOptionType,
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
@@ -22,6 +24,7 @@ use dao_voting::{
     },
     pre_propose::PreProposeInfo,
     proposal::MultipleChoiceProposeMsg as ProposeMsg,
+    quadratic::{QuadraticMode, QuadraticVoting, QuadraticVotingError},
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
 };
@@ -30,8 +33,9 @@ use std::panic;
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    proposal::MultipleChoiceProposal,
+    proposal::{MultipleChoiceProposal, VoteResult},
     query::{ProposalListResponse, ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    ranked_choice::RunoffResult,
     state::Config,
     testing::{
         do_votes::do_test_votes_cw20_balances,
@@ -102,6 +106,10 @@ pub fn get_pre_propose_info(
             msg: to_json_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -124,11 +132,12 @@ fn test_propose() {
 
     let instantiate = InstantiateMsg {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -140,12 +149,13 @@ fn test_propose() {
     let config: Config = query_proposal_config(&app, &govmod);
     let expected = Config {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: core_addr,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     };
     assert_eq!(config, expected);
@@ -154,11 +164,13 @@ fn test_propose() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -188,6 +200,9 @@ fn test_propose() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        runoff_result: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -207,8 +222,9 @@ fn test_propose_wrong_num_choices() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -223,8 +239,9 @@ fn test_propose_wrong_num_choices() {
     let expected = Config {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: core_addr,
         voting_strategy,
@@ -254,6 +271,7 @@ fn test_propose_wrong_num_choices() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         };
         std::convert::TryInto::try_into(MAX_NUM_CHOICES + 1).unwrap()
@@ -289,7 +307,8 @@ fn test_proposal_count_initialized_to_zero() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
-        only_members_execute: true,
+        quadratic_voting: None,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
@@ -325,11 +344,12 @@ fn test_propose_auto_vote_winner() {
 
     let instantiate = InstantiateMsg {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -341,12 +361,13 @@ fn test_propose_auto_vote_winner() {
     let config: Config = query_proposal_config(&app, &govmod);
     let expected = Config {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: core_addr,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     };
     assert_eq!(config, expected);
@@ -355,11 +376,13 @@ fn test_propose_auto_vote_winner() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -398,6 +421,9 @@ fn test_propose_auto_vote_winner() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        runoff_result: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -416,11 +442,12 @@ fn test_propose_auto_vote_reject() {
 
     let instantiate = InstantiateMsg {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -432,12 +459,13 @@ fn test_propose_auto_vote_reject() {
     let config: Config = query_proposal_config(&app, &govmod);
     let expected = Config {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: core_addr,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     };
     assert_eq!(config, expected);
@@ -446,11 +474,13 @@ fn test_propose_auto_vote_reject() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -489,6 +519,9 @@ fn test_propose_auto_vote_reject() {
         allow_revoting: false,
         min_voting_period: None,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        runoff_result: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -508,11 +541,12 @@ fn test_propose_non_member_auto_vote_fail() {
 
     let instantiate = InstantiateMsg {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -524,12 +558,13 @@ fn test_propose_non_member_auto_vote_fail() {
     let config: Config = query_proposal_config(&app, &govmod);
     let expected = Config {
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: core_addr,
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         veto: None,
     };
     assert_eq!(config, expected);
@@ -538,11 +573,13 @@ fn test_propose_non_member_auto_vote_fail() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -572,9 +609,10 @@ fn test_no_early_pass_with_min_duration() {
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(2)),
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -607,11 +645,13 @@ fn test_no_early_pass_with_min_duration() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -669,7 +709,8 @@ fn test_propose_with_messages() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
-        only_members_execute: true,
+        quadratic_voting: None,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
@@ -705,8 +746,9 @@ fn test_propose_with_messages() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period: cw_utils::Duration::Height(20),
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: "dao".to_string(),
         veto: None,
@@ -722,11 +764,13 @@ fn test_propose_with_messages() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![CosmosMsg::Wasm(wasm_msg)],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -790,9 +834,10 @@ fn test_min_duration_units_missmatch() {
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(2)),
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -823,9 +868,10 @@ fn test_min_duration_larger_than_proposal_duration() {
         },
         max_voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -855,9 +901,10 @@ fn test_min_duration_same_as_proposal_duration() {
         },
         max_voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -890,11 +937,13 @@ fn test_min_duration_same_as_proposal_duration() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -968,8 +1017,9 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -980,6 +1030,9 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1008,7 +1061,10 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
         Some(CheckedDepositInfo {
             denom: CheckedDenom::Cw20(token),
             amount: Uint128::new(1),
-            refund_policy: DepositRefundPolicy::OnlyPassed
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         })
     )
 }
@@ -1044,8 +1100,9 @@ fn test_different_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -1056,6 +1113,9 @@ fn test_different_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1106,8 +1166,9 @@ fn test_bad_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -1118,6 +1179,9 @@ fn test_bad_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1139,8 +1203,9 @@ fn test_take_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -1151,6 +1216,9 @@ fn test_take_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1179,11 +1247,13 @@ fn test_take_proposal_deposit() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -1247,8 +1317,9 @@ fn test_take_native_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -1259,6 +1330,9 @@ fn test_take_native_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1287,11 +1361,13 @@ fn test_take_native_proposal_deposit() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -1342,9 +1418,10 @@ fn test_native_proposal_deposit() {
         },
         max_voting_period,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -1353,6 +1430,9 @@ fn test_native_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1392,11 +1472,13 @@ fn test_native_proposal_deposit() {
                 MultipleChoiceOption {
                     description: "multiple choice option 1".to_string(),
                     msgs: vec![],
+                    requested_amount: None,
                     title: "title".to_string(),
                 },
                 MultipleChoiceOption {
                     description: "multiple choice option 2".to_string(),
                     msgs: vec![],
+                    requested_amount: None,
                     title: "title".to_string(),
                 },
             ],
@@ -1497,6 +1579,9 @@ fn test_deposit_return_on_execute() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -1640,12 +1725,14 @@ fn test_query_list_votes() {
             vote: MultipleChoiceVote { option_id: 0 },
             power: Uint128::new(10),
             rationale: None,
+            option_ids: vec![],
         },
         VoteInfo {
             voter: Addr::unchecked("note"),
             vote: MultipleChoiceVote { option_id: 1 },
             power: Uint128::new(20),
             rationale: None,
+            option_ids: vec![],
         },
     ];
 
@@ -1772,8 +1859,9 @@ fn test_cant_propose_zero_power() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: get_pre_propose_info(
@@ -1784,6 +1872,9 @@ fn test_cant_propose_zero_power() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -1818,11 +1909,13 @@ fn test_cant_propose_zero_power() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -1904,6 +1997,9 @@ fn test_cant_vote_not_registered() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -1939,7 +2035,7 @@ fn test_cant_vote_not_registered() {
 
 #[test]
 fn test_cant_execute_not_member() {
-    // Create proposal with only_members_execute: true
+    // Create proposal with executor_policy: Members
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
 
@@ -1951,8 +2047,9 @@ fn test_cant_execute_not_member() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -1974,11 +2071,13 @@ fn test_cant_execute_not_member() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -2030,7 +2129,7 @@ fn test_cant_execute_not_member() {
 
 #[test]
 fn test_cant_execute_not_member_when_proposal_created() {
-    // Create proposal with only_members_execute: true and ensure member cannot
+    // Create proposal with executor_policy: Members and ensure member cannot
     // execute if they were not a member when the proposal was created
     let mut app = App::default();
     let _govmod_id = app.store_code(proposal_multiple_contract());
@@ -2043,8 +2142,9 @@ fn test_cant_execute_not_member_when_proposal_created() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -2066,11 +2166,13 @@ fn test_cant_execute_not_member_when_proposal_created() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -2163,9 +2265,10 @@ fn test_open_proposal_submission() {
         },
         max_voting_period,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
         veto: None,
     };
@@ -2181,11 +2284,13 @@ fn test_open_proposal_submission() {
                 MultipleChoiceOption {
                     description: "multiple choice option 1".to_string(),
                     msgs: vec![],
+                    requested_amount: None,
                     title: "title".to_string(),
                 },
                 MultipleChoiceOption {
                     description: "multiple choice option 2".to_string(),
                     msgs: vec![],
+                    requested_amount: None,
                     title: "title".to_string(),
                 },
             ],
@@ -2212,6 +2317,7 @@ fn test_open_proposal_submission() {
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::Standard,
                 vote_count: Uint128::zero(),
                 index: 0,
@@ -2220,6 +2326,7 @@ fn test_open_proposal_submission() {
             CheckedMultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::Standard,
                 vote_count: Uint128::zero(),
                 index: 1,
@@ -2228,6 +2335,7 @@ fn test_open_proposal_submission() {
             CheckedMultipleChoiceOption {
                 description: "None of the above".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 option_type: MultipleChoiceOptionType::None,
                 vote_count: Uint128::zero(),
                 index: 2,
@@ -2238,6 +2346,9 @@ fn test_open_proposal_submission() {
             vote_weights: vec![Uint128::zero(); 3],
         },
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        runoff_result: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -2264,6 +2375,9 @@ fn test_close_open_proposal() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -2314,6 +2428,93 @@ fn test_close_open_proposal() {
     };
 }
 
+#[test]
+fn test_keeper_bounty() {
+    let (mut app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 2 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+        None,
+        false,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let bounty = UncheckedKeeperBounty {
+        denom: UncheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(10),
+    };
+
+    // Only the DAO may set the bounty.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("blue"),
+            govmod.clone(),
+            &ExecuteMsg::UpdateKeeperBounty {
+                bounty: Some(bounty.clone()),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        core_addr,
+        govmod.clone(),
+        &ExecuteMsg::UpdateKeeperBounty {
+            bounty: Some(bounty),
+        },
+        &[],
+    )
+    .unwrap();
+    let bounty: Option<KeeperBounty> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::KeeperBounty {})
+        .unwrap();
+    assert_eq!(
+        bounty,
+        Some(KeeperBounty {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+        })
+    );
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: govmod.to_string(),
+        amount: vec![Coin::new(10, "ujuno")],
+    }))
+    .unwrap();
+
+    // Make the proposal expire and close it. The closer is paid the
+    // bounty out of the proposal module's balance.
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked("keeper"),
+        govmod.clone(),
+        &ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        query_balance_native(&app, "keeper", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_balance_native(&app, govmod.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+}
+
 #[test]
 fn test_no_refund_failed_proposal() {
     let (mut app, core_addr) = do_test_votes_cw20_balances(
@@ -2334,6 +2535,9 @@ fn test_no_refund_failed_proposal() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -2414,6 +2618,9 @@ fn test_deposit_return_on_close() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -2464,8 +2671,9 @@ fn test_execute_expired_proposal() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -2500,11 +2708,13 @@ fn test_execute_expired_proposal() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -2629,8 +2839,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            quadratic_voting: None,
             max_voting_period: cw_utils::Duration::Height(10),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             dao: dao.to_string(),
             veto: None,
@@ -2649,8 +2860,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            quadratic_voting: None,
             max_voting_period: cw_utils::Duration::Height(10),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
@@ -2667,8 +2879,9 @@ fn test_update_config() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period: cw_utils::Duration::Height(10),
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         dao: Addr::unchecked(CREATOR_ADDR),
         veto: None,
@@ -2686,8 +2899,9 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            quadratic_voting: None,
             max_voting_period: cw_utils::Duration::Height(10),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             dao: Addr::unchecked(CREATOR_ADDR).to_string(),
             veto: None,
@@ -2717,6 +2931,9 @@ fn test_no_return_if_no_refunds() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         true,
     );
@@ -2763,8 +2980,9 @@ fn test_query_list_proposals() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: voting_strategy.clone(),
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -2797,11 +3015,13 @@ fn test_query_list_proposals() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -2851,6 +3071,9 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            quadratic_voting: None,
+            turnout: Uint128::zero(),
+            runoff_result: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2880,6 +3103,9 @@ fn test_query_list_proposals() {
             allow_revoting: false,
             min_voting_period: None,
             veto: None,
+            quadratic_voting: None,
+            turnout: Uint128::zero(),
+            runoff_result: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2900,8 +3126,9 @@ fn test_hooks() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3027,8 +3254,9 @@ fn test_active_threshold_absolute() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3070,11 +3298,13 @@ fn test_active_threshold_absolute() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3158,8 +3388,9 @@ fn test_active_threshold_percent() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3202,11 +3433,13 @@ fn test_active_threshold_percent() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3290,8 +3523,9 @@ fn test_active_threshold_none() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         max_voting_period,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -3337,11 +3571,13 @@ fn test_active_threshold_none() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3401,12 +3637,13 @@ fn test_revoting() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3428,11 +3665,13 @@ fn test_revoting() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3523,9 +3762,246 @@ fn test_revoting() {
     assert_eq!(proposal.proposal.votes.vote_weights[1], Uint128::new(0),);
 }
 
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: true,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: Some(QuadraticVoting {
+                identity_gate: None,
+                mode: QuadraticMode::PerBallot {},
+            }),
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "whale".to_string(),
+                amount: Uint128::new(100),
+            },
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(49),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(36),
+            },
+        ]),
+    );
+
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = vec![
+        MultipleChoiceOption {
+            description: "multiple choice option 1".to_string(),
+            msgs: vec![],
+            requested_amount: None,
+            title: "title".to_string(),
+        },
+        MultipleChoiceOption {
+            description: "multiple choice option 2".to_string(),
+            msgs: vec![],
+            requested_amount: None,
+            title: "title".to_string(),
+        },
+    ];
+    let mc_options = MultipleChoiceOptions { options };
+
+    app.execute_contract(
+        Addr::unchecked("whale"),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: mc_options,
+            proposer: None,
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    for (voter, option_id) in [("whale", 0), ("a-1", 0), ("a-2", 1)] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id: 1,
+                vote: MultipleChoiceVote { option_id },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(17), Uint128::new(6), Uint128::zero()]
+    );
+    assert_eq!(proposal.proposal.turnout, Uint128::new(185));
+
+    // Revoting moves the square root weighted vote and leaves the
+    // turnout unchanged.
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![Uint128::new(10), Uint128::new(13), Uint128::zero()]
+    );
+    assert_eq!(proposal.proposal.turnout, Uint128::new(185));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    app.update_block(|b| b.height += 6);
+
+    // The whale holds more power than everyone else combined but is
+    // outvoted.
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    let result = proposal.proposal.calculate_vote_result().unwrap();
+    match result {
+        VoteResult::SingleWinner(choice) => assert_eq!(choice.index, 1),
+        _ => panic!("expected a single winner"),
+    }
+}
+
 /// Tests that revoting is stored at a per-proposal level.
 /// Proposals created while revoting is enabled should not
 /// have it disabled if a config change turns if off.
+#[test]
+fn test_quadratic_voting_credits() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: Some(QuadraticVoting {
+                identity_gate: None,
+                mode: QuadraticMode::Credits {},
+            }),
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![Cw20Coin {
+            address: "whale".to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App| {
+        let options = (0..2)
+            .map(|i| MultipleChoiceOption {
+                description: format!("option {i}"),
+                msgs: vec![],
+                requested_amount: None,
+                title: "title".to_string(),
+            })
+            .collect();
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "A simple text proposal".to_string(),
+                description: "A simple text proposal".to_string(),
+                choices: MultipleChoiceOptions { options },
+                proposer: None,
+                vote: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    };
+    let vote_with_credits = |app: &mut App, proposal_id: u64, votes: u128| {
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            govmod.clone(),
+            &ExecuteMsg::VoteWithCredits {
+                proposal_id,
+                option_ids: vec![0],
+                votes: Uint128::new(votes),
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    propose(&mut app);
+    propose(&mut app);
+
+    // Seven votes cost 49 of the whale's 100 credits.
+    vote_with_credits(&mut app, 1, 7).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::new(7));
+    assert_eq!(proposal.proposal.turnout, Uint128::new(100));
+
+    let err: ContractError = vote_with_credits(&mut app, 2, 8)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::InsufficientCredits {
+            available: Uint128::new(51),
+            cost: Uint128::new(64),
+        })
+    );
+
+    // A plain vote casts as many votes as the remaining credits afford.
+    app.execute_contract(
+        Addr::unchecked("whale"),
+        govmod.clone(),
+        &ExecuteMsg::Vote {
+            proposal_id: 2,
+            vote: MultipleChoiceVote { option_id: 1 },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 2);
+    assert_eq!(proposal.proposal.votes.vote_weights[1], Uint128::new(7));
+
+    // Credits are returned once the proposals they were spent on
+    // expire.
+    app.update_block(|b| b.height += 6);
+    propose(&mut app);
+    vote_with_credits(&mut app, 3, 10).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 3);
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::new(10));
+}
+
 #[test]
 fn test_allow_revoting_config_changes() {
     let mut app = App::default();
@@ -3535,12 +4011,13 @@ fn test_allow_revoting_config_changes() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3562,11 +4039,13 @@ fn test_allow_revoting_config_changes() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3594,13 +4073,14 @@ fn test_allow_revoting_config_changes() {
         &ExecuteMsg::UpdateConfig {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             dao: core_addr.to_string(),
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             veto: None,
         },
         &[],
@@ -3690,12 +4170,13 @@ fn test_revoting_same_vote_twice() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3717,11 +4198,13 @@ fn test_revoting_same_vote_twice() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3786,12 +4269,13 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -3813,11 +4297,13 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -3929,6 +4415,9 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
             },
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -3973,20 +4462,66 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
 }
 
 #[test]
-fn test_close_failed_proposal() {
-    let mut app = App::default();
-    let _govmod_id = app.store_code(proposal_multiple_contract());
+fn test_keeper_share_of_forfeited_deposit() {
+    let (mut app, core_addr) = do_test_votes_cw20_balances(
+        vec![TestMultipleChoiceVote {
+            voter: "blue".to_string(),
+            position: MultipleChoiceVote { option_id: 2 },
+            weight: Uint128::new(10),
+            should_execute: ShouldExecute::Yes,
+        }],
+        VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        Status::Open,
+        Some(Uint128::new(100)),
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: Some(Decimal::percent(35)),
+        }),
+        false,
+    );
+    let proposal_multiple = query_multiple_proposal_module(&app, &core_addr);
 
-    let quorum = PercentageThreshold::Majority {};
-    let voting_strategy = VotingStrategy::SingleChoice { quorum };
-    let max_voting_period = cw_utils::Duration::Height(6);
+    // Make the proposal expire. It has now failed.
+    app.update_block(|block| block.height += 10);
+
+    // Whoever closes the proposal receives their share of the
+    // deposit, rounded down, and the DAO the rest.
+    app.execute_contract(
+        Addr::unchecked("keeper"),
+        proposal_multiple,
+        &ExecuteMsg::Close { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    let balance = |app: &App, address: &str| app.wrap().query_balance(address, "ujuno").unwrap();
+    assert_eq!(balance(&app, "keeper").amount, Uint128::new(3));
+    assert_eq!(balance(&app, core_addr.as_str()).amount, Uint128::new(7));
+}
+
+#[test]
+fn test_close_failed_proposal() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+
+    let quorum = PercentageThreshold::Majority {};
+    let voting_strategy = VotingStrategy::SingleChoice { quorum };
+    let max_voting_period = cw_utils::Duration::Height(6);
     let instantiate = InstantiateMsg {
         max_voting_period,
         voting_strategy,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -4044,11 +4579,13 @@ fn test_close_failed_proposal() {
                 funds: vec![],
             }
             .into()],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "Don't burn".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -4120,21 +4657,24 @@ fn test_close_failed_proposal() {
                                     voting_strategy: VotingStrategy::SingleChoice { quorum },
                                     max_voting_period: original.max_voting_period,
                                     min_voting_period: original.min_voting_period,
-                                    only_members_execute: original.only_members_execute,
+                                    executor_policy: original.executor_policy,
                                     allow_revoting: false,
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
+                                    quadratic_voting: None,
                                     veto: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
                             }
                             .into()],
+                            requested_amount: None,
                             title: "title".to_string(),
                         },
                         MultipleChoiceOption {
                             description: "Don't disable".to_string(),
                             msgs: vec![],
+                            requested_amount: None,
                             title: "title".to_string(),
                         },
                     ],
@@ -4226,9 +4766,10 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         voting_strategy,
         max_voting_period,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -4240,6 +4781,9 @@ fn test_no_double_refund_on_execute_fail_and_close() {
                 // that we don't get a second refund on close. Refunds on
                 // close only happen if this is true.
                 refund_policy: DepositRefundPolicy::Always,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
@@ -4337,11 +4881,13 @@ fn test_no_double_refund_on_execute_fail_and_close() {
                     funds: vec![],
                 }
                 .into()],
+                requested_amount: None,
                 title: "title".to_string(),
             },
             MultipleChoiceOption {
                 description: "hi there".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 title: "title".to_string(),
             },
         ],
@@ -4415,13 +4961,14 @@ pub fn test_not_allow_voting_on_expired_proposal() {
     let _govmod_id = app.store_code(proposal_multiple_contract());
     let instantiate = InstantiateMsg {
         max_voting_period: Duration::Height(6),
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         voting_strategy: VotingStrategy::SingleChoice {
             quorum: PercentageThreshold::Majority {},
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         veto: None,
     };
@@ -4446,11 +4993,13 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -4509,12 +5058,13 @@ fn test_next_proposal_id() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4542,11 +5092,13 @@ fn test_next_proposal_id() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -4582,12 +5134,13 @@ fn test_vote_with_rationale() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4616,11 +5169,13 @@ fn test_vote_with_rationale() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 1".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 2".to_string(),
         },
     ];
@@ -4680,12 +5235,13 @@ fn test_revote_with_rationale() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true, // Enable revoting
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4714,11 +5270,13 @@ fn test_revote_with_rationale() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 1".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 2".to_string(),
         },
     ];
@@ -4836,12 +5394,13 @@ fn test_update_rationale() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: true, // Enable revoting
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -4870,11 +5429,13 @@ fn test_update_rationale() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 1".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title 2".to_string(),
         },
     ];
@@ -4975,12 +5536,13 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5009,11 +5571,13 @@ fn test_open_proposal_passes_with_zero_timelock_veto_duration() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5084,12 +5648,13 @@ fn test_veto_non_existing_prop_id() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5130,12 +5695,13 @@ fn test_veto_with_no_veto_configuration() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: None,
         },
@@ -5163,11 +5729,13 @@ fn test_veto_with_no_veto_configuration() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5222,12 +5790,13 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5256,11 +5825,13 @@ fn test_veto_open_prop_with_veto_before_passed_disabled() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5330,12 +5901,13 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5364,11 +5936,13 @@ fn test_veto_when_veto_timelock_expired() -> anyhow::Result<()> {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5445,12 +6019,13 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5479,11 +6054,13 @@ fn test_veto_sets_prop_status_to_vetoed() -> anyhow::Result<()> {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5556,12 +6133,13 @@ fn test_veto_from_catchall_state() {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5590,11 +6168,13 @@ fn test_veto_from_catchall_state() {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5677,12 +6257,13 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: true,
+            executor_policy: ExecutorPolicy::Members {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5711,11 +6292,13 @@ fn test_veto_timelock_early_execute_happy() -> anyhow::Result<()> {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5801,12 +6384,13 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: false,
+            executor_policy: ExecutorPolicy::Anyone {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5835,11 +6419,13 @@ fn test_veto_timelock_expires_happy() -> anyhow::Result<()> {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -5914,12 +6500,13 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         InstantiateMsg {
             min_voting_period: None,
             max_voting_period: Duration::Height(6),
-            only_members_execute: true,
+            executor_policy: ExecutorPolicy::Members {},
             allow_revoting: false,
             voting_strategy: VotingStrategy::SingleChoice {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
             veto: Some(veto_config),
         },
@@ -5948,11 +6535,13 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
         MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
         MultipleChoiceOption {
             description: "multiple choice option 2".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         },
     ];
@@ -6026,3 +6615,614 @@ fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_approval_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::Approval {
+                quorum: PercentageThreshold::Majority {},
+                max_selections: 2,
+                winners: 2,
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "a-3".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(30),
+        }],
+    }))
+    .unwrap();
+
+    // Each option pays its title 10 ujuno.
+    let options = ["one", "two", "three"]
+        .into_iter()
+        .map(|recipient| MultipleChoiceOption {
+            description: "multiple choice option".to_string(),
+            msgs: vec![BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: "ujuno".to_string(),
+                    amount: Uint128::new(10),
+                }],
+            }
+            .into()],
+            requested_amount: None,
+            title: recipient.to_string(),
+        })
+        .collect();
+    let mc_options = MultipleChoiceOptions { options };
+
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: mc_options,
+            proposer: None,
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    // Selecting more than the allowed number of options fails.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("a-1"),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids: vec![0, 1, 2],
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TooManySelections { max: 2 });
+
+    // Options may not be selected twice, and "None of the above"
+    // may not be selected with other options.
+    for option_ids in [vec![0, 0], vec![0, 3], vec![], vec![4]] {
+        let err: ContractError = app
+            .execute_contract(
+                Addr::unchecked("a-1"),
+                govmod.clone(),
+                &ExecuteMsg::VoteMultiple {
+                    proposal_id: 1,
+                    option_ids,
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidVote {});
+    }
+
+    for (voter, option_ids) in [("a-1", vec![0, 1]), ("a-2", vec![1, 2]), ("a-3", vec![2])] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let vote: VoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            govmod.clone(),
+            &QueryMsg::GetVote {
+                proposal_id: 1,
+                voter: "a-1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(vote.vote.unwrap().option_ids, vec![0, 1]);
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![
+            Uint128::new(40),
+            Uint128::new(75),
+            Uint128::new(60),
+            Uint128::zero()
+        ]
+    );
+    assert_eq!(proposal.proposal.turnout, Uint128::new(100));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    app.update_block(|b| b.height += 6);
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // The messages of both winning options are executed.
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    for (recipient, amount) in [("one", 0), ("two", 10), ("three", 10)] {
+        let balance = app.wrap().query_balance(recipient, "ujuno").unwrap();
+        assert_eq!(balance.amount, Uint128::new(amount));
+    }
+}
+
+#[test]
+fn test_approval_revote_same_options() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: true,
+            voting_strategy: VotingStrategy::Approval {
+                quorum: PercentageThreshold::Majority {},
+                max_selections: 2,
+                winners: 1,
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        None,
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let options = (0..3)
+        .map(|i| MultipleChoiceOption {
+            description: format!("option {i}"),
+            msgs: vec![],
+            requested_amount: None,
+            title: "title".to_string(),
+        })
+        .collect();
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let vote = |app: &mut App, option_ids: Vec<u32>| {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids,
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    vote(&mut app, vec![0, 1]).unwrap();
+
+    // Selecting the same options in a different order is not a new
+    // vote.
+    let err: ContractError = vote(&mut app, vec![1, 0]).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::AlreadyCast {});
+
+    // Duplicate selections are rejected rather than collapsed.
+    let err: ContractError = vote(&mut app, vec![1, 1]).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::InvalidVote {});
+
+    vote(&mut app, vec![1, 2]).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::zero());
+    assert_eq!(
+        proposal.proposal.votes.vote_weights[1],
+        proposal.proposal.votes.vote_weights[2]
+    );
+}
+
+#[test]
+fn test_budget_allocation() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: false,
+            voting_strategy: VotingStrategy::Budget {
+                quorum: PercentageThreshold::Majority {},
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+                budget: Uint128::new(50),
+                max_selections: 3,
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "a-3".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(50),
+        }],
+    }))
+    .unwrap();
+
+    // Each option requests an amount from the budget and pays it to
+    // the grantee named by its title.
+    let grant = |recipient: &str, amount: Option<u128>| MultipleChoiceOption {
+        description: "grant application".to_string(),
+        msgs: vec![BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(amount.unwrap_or_default()),
+            }],
+        }
+        .into()],
+        title: recipient.to_string(),
+        requested_amount: amount.map(Uint128::new),
+    };
+    let propose = |app: &mut App, options: Vec<MultipleChoiceOption>| {
+        app.execute_contract(
+            Addr::unchecked("a-1"),
+            govmod.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "Quarterly grants".to_string(),
+                description: "Grant round".to_string(),
+                choices: MultipleChoiceOptions { options },
+                proposer: None,
+                vote: None,
+            }),
+            &[],
+        )
+    };
+
+    // Every option must request an amount no greater than the budget.
+    let err: ContractError = propose(&mut app, vec![grant("one", Some(10)), grant("two", None)])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::MissingRequestedAmount { option: 1 });
+    let err: ContractError = propose(
+        &mut app,
+        vec![grant("one", Some(51)), grant("two", Some(10))],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::RequestExceedsBudget {
+            option: 0,
+            budget: Uint128::new(50)
+        }
+    );
+
+    // Options must transfer exactly the amount they request, and may
+    // not move anything other than the budget denom.
+    let mut underpriced = grant("two", Some(10));
+    underpriced.requested_amount = Some(Uint128::new(1));
+    let err: ContractError = propose(&mut app, vec![grant("one", Some(10)), underpriced])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::RequestedAmountMismatch {
+            option: 1,
+            requested: Uint128::new(1),
+            transferred: Uint128::new(10),
+        }
+    );
+    let mut other_denom = grant("two", Some(10));
+    other_denom.msgs.push(
+        BankMsg::Send {
+            to_address: "two".to_string(),
+            amount: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: Uint128::new(1000),
+            }],
+        }
+        .into(),
+    );
+    let err: ContractError = propose(&mut app, vec![grant("one", Some(10)), other_denom])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidBudgetMessage { option: 1 });
+    let mut drain = grant("two", Some(10));
+    drain.msgs.push(
+        WasmMsg::Execute {
+            contract_addr: "treasury".to_string(),
+            msg: to_json_binary(&"withdraw").unwrap(),
+            funds: vec![],
+        }
+        .into(),
+    );
+    let err: ContractError = propose(&mut app, vec![grant("one", Some(10)), drain])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidBudgetMessage { option: 1 });
+
+    propose(
+        &mut app,
+        vec![
+            grant("one", Some(30)),
+            grant("two", Some(30)),
+            grant("three", Some(20)),
+        ],
+    )
+    .unwrap();
+
+    for (voter, option_ids) in [
+        ("a-1", vec![0, 1]),
+        ("a-2", vec![1, 2]),
+        ("a-3", vec![0, 2]),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.update_block(|b| b.height += 6);
+
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    // "two" has the most votes and is funded first. "one" has more
+    // votes than "three" but the remaining budget only covers
+    // "three".
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    for (recipient, amount) in [("one", 0), ("two", 30), ("three", 20)] {
+        let balance = app.wrap().query_balance(recipient, "ujuno").unwrap();
+        assert_eq!(balance.amount, Uint128::new(amount));
+    }
+}
+
+#[test]
+fn test_ranked_choice_voting() {
+    let mut app = App::default();
+    let _govmod_id = app.store_code(proposal_multiple_contract());
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            min_voting_period: None,
+            max_voting_period: Duration::Height(6),
+            executor_policy: ExecutorPolicy::Anyone {},
+            allow_revoting: true,
+            voting_strategy: VotingStrategy::RankedChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            veto: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "a-1".to_string(),
+                amount: Uint128::new(40),
+            },
+            Cw20Coin {
+                address: "a-2".to_string(),
+                amount: Uint128::new(35),
+            },
+            Cw20Coin {
+                address: "a-3".to_string(),
+                amount: Uint128::new(25),
+            },
+        ]),
+    );
+
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: vec![Coin {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(10),
+        }],
+    }))
+    .unwrap();
+
+    // Each option pays its title 10 ujuno.
+    let options = ["one", "two", "three"]
+        .into_iter()
+        .map(|recipient| MultipleChoiceOption {
+            description: "multiple choice option".to_string(),
+            msgs: vec![BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![Coin {
+                    denom: "ujuno".to_string(),
+                    amount: Uint128::new(10),
+                }],
+            }
+            .into()],
+            title: recipient.to_string(),
+            requested_amount: None,
+        })
+        .collect();
+
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "A simple text proposal".to_string(),
+            description: "A simple text proposal".to_string(),
+            choices: MultipleChoiceOptions { options },
+            proposer: None,
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+
+    for (voter, option_ids) in [
+        ("a-1", vec![0]),
+        ("a-2", vec![1]),
+        ("a-3", vec![2, 0]),
+        // Revoting replaces the voter's previous ranking.
+        ("a-3", vec![2, 1]),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Votes are counted towards each voter's first preference until
+    // the proposal is tallied.
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(
+        proposal.proposal.votes.vote_weights,
+        vec![
+            Uint128::new(40),
+            Uint128::new(35),
+            Uint128::new(25),
+            Uint128::zero()
+        ]
+    );
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(proposal.proposal.runoff_result, None);
+
+    // At most three options may be ranked.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("a-1"),
+            govmod.clone(),
+            &ExecuteMsg::VoteMultiple {
+                proposal_id: 1,
+                option_ids: vec![0, 1, 2, 3],
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::TooManySelections { max: 3 });
+
+    app.update_block(|b| b.height += 6);
+
+    // Listing proposals does not run the tally.
+    let proposals: ProposalListResponse = query_list_proposals(&app, &govmod, None, None);
+    assert_eq!(proposals.proposals[0].proposal.status, Status::Open);
+    assert_eq!(proposals.proposals[0].proposal.runoff_result, None);
+
+    // "one" has the most first preferences, but once "three" is
+    // eliminated "two" has a majority.
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 1);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    assert_eq!(
+        proposal.proposal.runoff_result,
+        Some(RunoffResult::Winner { option_id: 1 })
+    );
+
+    app.execute_contract(
+        Addr::unchecked("a-1"),
+        govmod.clone(),
+        &ExecuteMsg::Execute { proposal_id: 1 },
+        &[],
+    )
+    .unwrap();
+    for (recipient, amount) in [("one", 0), ("two", 10), ("three", 0)] {
+        let balance = app.wrap().query_balance(recipient, "ujuno").unwrap();
+        assert_eq!(balance.amount, Uint128::new(amount));
+    }
+}
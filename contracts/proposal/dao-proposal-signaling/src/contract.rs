@@ -0,0 +1,234 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
+use dao_voting::{
+    proposal::DEFAULT_LIMIT,
+    voting::{get_total_power, get_voting_power},
+};
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    poll::{selections, Poll, PollListResponse, VoteResponse, MAX_OPTIONS},
+    state::{Config, BALLOTS, CONFIG, DAO, POLLS, PROPOSAL_COUNT},
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-signaling";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    DAO.save(deps.storage, &info.sender)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            voting_period: validate_voting_period(msg.voting_period)?,
+        },
+    )?;
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            options,
+            max_selections,
+        } => execute_propose(deps, env, info, title, description, options, max_selections),
+        ExecuteMsg::Vote {
+            proposal_id,
+            selections,
+        } => execute_vote(deps, env, info, proposal_id, selections),
+        ExecuteMsg::UpdateConfig { voting_period } => {
+            execute_update_config(deps, info, voting_period)
+        }
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    options: Vec<String>,
+    max_selections: u32,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    let power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+    if power.is_zero() {
+        return Err(ContractError::ZeroVotingPower {});
+    }
+    if options.len() < 2 || options.len() > MAX_OPTIONS as usize {
+        return Err(ContractError::InvalidOptions { max: MAX_OPTIONS });
+    }
+    if max_selections == 0 || max_selections as usize > options.len() {
+        return Err(ContractError::InvalidMaxSelections {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let id = PROPOSAL_COUNT.load(deps.storage)? + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    let poll = Poll {
+        title,
+        description,
+        proposer: info.sender.clone(),
+        start_height: env.block.height,
+        expiration: config.voting_period.after(&env.block),
+        votes: vec![Uint128::zero(); options.len()],
+        options,
+        max_selections,
+        total_power: get_total_power(deps.as_ref(), &dao, Some(env.block.height))?,
+        turnout: Uint128::zero(),
+    };
+    POLLS.save(deps.storage, id, &poll)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("proposer", info.sender))
+}
+
+fn execute_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    selections: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let mut poll = POLLS.load(deps.storage, proposal_id)?;
+    if poll.expiration.is_expired(&env.block) {
+        return Err(ContractError::Closed {});
+    }
+    if BALLOTS.has(deps.storage, (proposal_id, &info.sender)) {
+        return Err(ContractError::Voted {});
+    }
+    let power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &DAO.load(deps.storage)?,
+        Some(poll.start_height),
+    )?;
+    if power.is_zero() {
+        return Err(ContractError::ZeroVotingPower {});
+    }
+
+    let ballot = poll.ballot(&selections)?;
+    poll.add_vote(ballot, power);
+    POLLS.save(deps.storage, proposal_id, &poll)?;
+    BALLOTS.save(deps.storage, (proposal_id, &info.sender), &ballot)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("power", power))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    voting_period: Duration,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::NotDao {});
+    }
+    let voting_period = validate_voting_period(voting_period)?;
+    CONFIG.save(deps.storage, &Config { voting_period })?;
+    Ok(Response::default()
+        .add_attribute("method", "update_config")
+        .add_attribute("updater", info.sender))
+}
+
+/// Polls with a zero voting period would expire as soon as they are
+/// created.
+fn validate_voting_period(voting_period: Duration) -> Result<Duration, ContractError> {
+    match voting_period {
+        Duration::Height(0) | Duration::Time(0) => Err(ContractError::ZeroVotingPeriod {}),
+        _ => Ok(voting_period),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Proposal { proposal_id } => to_json_binary(
+            &POLLS
+                .load(deps.storage, proposal_id)?
+                .into_response(&env.block, proposal_id),
+        ),
+        QueryMsg::ListProposals { start_after, limit } => to_json_binary(&list_polls(
+            deps,
+            &env,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+            limit,
+        )?),
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => to_json_binary(&list_polls(
+            deps,
+            &env,
+            None,
+            start_before.map(Bound::exclusive),
+            Order::Descending,
+            limit,
+        )?),
+        QueryMsg::ProposalCount {} => to_json_binary(&PROPOSAL_COUNT.load(deps.storage)?),
+        QueryMsg::GetVote { proposal_id, voter } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let selections = BALLOTS
+                .may_load(deps.storage, (proposal_id, &voter))?
+                .map(selections);
+            to_json_binary(&VoteResponse { selections })
+        }
+        QueryMsg::NextProposalId {} => to_json_binary(&(PROPOSAL_COUNT.load(deps.storage)? + 1)),
+        QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Info {} => to_json_binary(&dao_interface::voting::InfoResponse {
+            info: cw2::get_contract_version(deps.storage)?,
+        }),
+    }
+}
+
+fn list_polls(
+    deps: Deps,
+    env: &Env,
+    min: Option<Bound<u64>>,
+    max: Option<Bound<u64>>,
+    order: Order,
+    limit: Option<u64>,
+) -> StdResult<PollListResponse> {
+    let polls = POLLS
+        .range(deps.storage, min, max, order)
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| item.map(|(id, poll)| poll.into_response(&env.block, id)))
+        .collect::<StdResult<Vec<_>>>()?;
+    Ok(PollListResponse { polls })
+}
@@ -0,0 +1,38 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("non-zero voting power required to perform this action")]
+    ZeroVotingPower {},
+
+    #[error("only the DAO may perform this action")]
+    NotDao {},
+
+    #[error("voting period must be non-zero")]
+    ZeroVotingPeriod {},
+
+    #[error("already voted")]
+    Voted {},
+
+    #[error("only open polls may be voted on")]
+    Closed {},
+
+    #[error("polls must have between 2 and {max} options")]
+    InvalidOptions { max: u32 },
+
+    #[error("max selections must be between 1 and the number of options")]
+    InvalidMaxSelections {},
+
+    #[error("must select between 1 and {max} options")]
+    InvalidSelectionCount { max: u32 },
+
+    #[error("no such option ({option})")]
+    InvalidOption { option: u32 },
+
+    #[error("option ({option}) selected more than once")]
+    DuplicateOption { option: u32 },
+}
@@ -0,0 +1,69 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::Duration;
+
+use dao_dao_macros::proposal_module_query;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The amount of time polls are open for voting.
+    pub voting_period: Duration,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a poll. The sender must have voting power.
+    Propose {
+        title: String,
+        description: String,
+        /// The options that may be voted for. Must contain between
+        /// two and `MAX_OPTIONS` options.
+        options: Vec<String>,
+        /// The maximum number of options a voter may select. `1`
+        /// creates a single choice poll.
+        max_selections: u32,
+    },
+    /// Votes for one or more options on an open poll.
+    Vote {
+        proposal_id: u64,
+        /// The indices of the selected options.
+        selections: Vec<u32>,
+    },
+    /// Updates the voting period used for new polls. Only the DAO
+    /// may call this method.
+    UpdateConfig { voting_period: Duration },
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets a poll and its current results.
+    #[returns(crate::poll::PollResponse)]
+    Proposal { proposal_id: u64 },
+    /// Lists polls in ascending order of ID.
+    #[returns(crate::poll::PollListResponse)]
+    ListProposals {
+        /// The poll ID to start listing polls after.
+        start_after: Option<u64>,
+        /// The maximum number of polls to return. If no limit is set
+        /// a max of 30 polls will be returned.
+        limit: Option<u64>,
+    },
+    /// Lists polls in descending order of ID.
+    #[returns(crate::poll::PollListResponse)]
+    ReverseProposals {
+        /// The poll ID to start listing polls before.
+        start_before: Option<u64>,
+        /// The maximum number of polls to return. If no limit is set
+        /// a max of 30 polls will be returned.
+        limit: Option<u64>,
+    },
+    /// Returns the number of polls that have been created.
+    #[returns(::std::primitive::u64)]
+    ProposalCount {},
+    /// Returns the options a voter selected on a poll.
+    #[returns(crate::poll::VoteResponse)]
+    GetVote { proposal_id: u64, voter: String },
+}
@@ -0,0 +1,117 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Uint128};
+use cw_utils::Expiration;
+use dao_voting::status::Status;
+
+use crate::ContractError;
+
+/// The maximum number of options a poll may have. Ballots are stored
+/// as a bitmask with one bit per option.
+pub const MAX_OPTIONS: u32 = u32::BITS;
+
+#[cw_serde]
+pub struct Poll {
+    pub title: String,
+    pub description: String,
+    pub proposer: Addr,
+    /// The block height voting power is snapshotted at.
+    pub start_height: u64,
+    /// When voting on this poll ends.
+    pub expiration: Expiration,
+    pub options: Vec<String>,
+    pub max_selections: u32,
+    /// The total voting power at `start_height`.
+    pub total_power: Uint128,
+    /// The voting power that has selected each option, indexed in
+    /// the same order as `options`.
+    pub votes: Vec<Uint128>,
+    /// The voting power that has voted on this poll.
+    pub turnout: Uint128,
+}
+
+#[cw_serde]
+pub struct PollResponse {
+    pub id: u64,
+    pub poll: Poll,
+    /// `Open` until the voting period ends, and `Closed` afterwards.
+    pub status: Status,
+    /// The options with the most votes. Empty if nobody has voted.
+    pub leading: Vec<u32>,
+}
+
+#[cw_serde]
+pub struct PollListResponse {
+    pub polls: Vec<PollResponse>,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    /// The options the voter selected, or `None` if they have not
+    /// voted.
+    pub selections: Option<Vec<u32>>,
+}
+
+impl Poll {
+    pub fn status(&self, block: &BlockInfo) -> Status {
+        if self.expiration.is_expired(block) {
+            Status::Closed
+        } else {
+            Status::Open
+        }
+    }
+
+    /// The options with the most votes.
+    pub fn leading(&self) -> Vec<u32> {
+        let max = self.votes.iter().max().copied().unwrap_or_default();
+        if max.is_zero() {
+            return vec![];
+        }
+        (0..self.votes.len() as u32)
+            .filter(|i| self.votes[*i as usize] == max)
+            .collect()
+    }
+
+    pub fn into_response(self, block: &BlockInfo, id: u64) -> PollResponse {
+        PollResponse {
+            id,
+            status: self.status(block),
+            leading: self.leading(),
+            poll: self,
+        }
+    }
+
+    /// Validates `selections` and returns them as a ballot bitmask.
+    pub(crate) fn ballot(&self, selections: &[u32]) -> Result<u32, ContractError> {
+        if selections.is_empty() || selections.len() > self.max_selections as usize {
+            return Err(ContractError::InvalidSelectionCount {
+                max: self.max_selections,
+            });
+        }
+        let mut ballot = 0u32;
+        for &option in selections {
+            if option as usize >= self.options.len() {
+                return Err(ContractError::InvalidOption { option });
+            }
+            if ballot & (1 << option) != 0 {
+                return Err(ContractError::DuplicateOption { option });
+            }
+            ballot |= 1 << option;
+        }
+        Ok(ballot)
+    }
+
+    /// Adds `power` to each option selected in `ballot`.
+    pub(crate) fn add_vote(&mut self, ballot: u32, power: Uint128) {
+        for option in selections(ballot) {
+            self.votes[option as usize] += power;
+        }
+        self.turnout += power;
+    }
+}
+
+/// The options selected in a ballot bitmask.
+pub fn selections(ballot: u32) -> Vec<u32> {
+    (0..MAX_OPTIONS)
+        .filter(|i| ballot & (1 << i) != 0)
+        .collect()
+}
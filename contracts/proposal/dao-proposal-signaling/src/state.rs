@@ -0,0 +1,19 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+
+use crate::poll::Poll;
+
+#[cw_serde]
+pub struct Config {
+    pub voting_period: Duration,
+}
+
+pub(crate) const DAO: Item<Addr> = Item::new("dao");
+pub(crate) const CONFIG: Item<Config> = Item::new("config");
+pub(crate) const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+pub(crate) const POLLS: Map<u64, Poll> = Map::new("polls");
+/// Ballots stored as a bitmask of the selected options.
+pub(crate) const BALLOTS: Map<(u64, &Addr), u32> = Map::new("ballots");
@@ -0,0 +1,143 @@
+use cosmwasm_std::Uint128;
+use cw_utils::Duration;
+use dao_voting::status::Status;
+
+use crate::{poll::MAX_OPTIONS, ContractError};
+
+use super::{is_error, suite::SuiteBuilder};
+
+#[test]
+fn test_single_choice_poll() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("alice", 5), ("bob", 5)])
+        .build();
+    assert_eq!(suite.query_dao(), suite.core);
+
+    let id = suite.propose("sender", &["yes", "no", "later"], 1).unwrap();
+    assert_eq!(id, 1);
+
+    suite.vote("sender", id, vec![1]).unwrap();
+    suite.vote("alice", id, vec![0]).unwrap();
+
+    let err = suite.vote("bob", id, vec![0, 1]);
+    is_error!(
+        err,
+        &ContractError::InvalidSelectionCount { max: 1 }.to_string()
+    );
+    let err = suite.vote("sender", id, vec![0]);
+    is_error!(err, &ContractError::Voted {}.to_string());
+
+    let poll = suite.query_poll(id);
+    assert_eq!(poll.status, Status::Open);
+    assert_eq!(
+        poll.poll.votes,
+        vec![Uint128::new(5), Uint128::new(10), Uint128::zero()]
+    );
+    assert_eq!(poll.poll.turnout, Uint128::new(15));
+    assert_eq!(poll.poll.total_power, Uint128::new(20));
+    assert_eq!(poll.leading, vec![1]);
+    assert_eq!(suite.query_vote(id, "sender"), Some(vec![1]));
+    assert_eq!(suite.query_vote(id, "bob"), None);
+
+    // polls close on their own once voting ends.
+    suite.voting_period_passes();
+    let poll = suite.query_poll(id);
+    assert_eq!(poll.status, Status::Closed);
+
+    let err = suite.vote("bob", id, vec![0]);
+    is_error!(err, &ContractError::Closed {}.to_string());
+}
+
+#[test]
+fn test_multiple_choice_poll() {
+    let mut suite = SuiteBuilder::default()
+        .with_voters(&[("sender", 10), ("alice", 10)])
+        .build();
+
+    let id = suite
+        .propose("sender", &["red", "green", "blue", "gold"], 2)
+        .unwrap();
+
+    suite.vote("sender", id, vec![3, 0]).unwrap();
+    suite.vote("alice", id, vec![2, 3]).unwrap();
+
+    let poll = suite.query_poll(id);
+    assert_eq!(
+        poll.poll.votes,
+        vec![
+            Uint128::new(10),
+            Uint128::zero(),
+            Uint128::new(10),
+            Uint128::new(20)
+        ]
+    );
+    assert_eq!(poll.poll.turnout, Uint128::new(20));
+    assert_eq!(poll.leading, vec![3]);
+    assert_eq!(suite.query_vote(id, "sender"), Some(vec![0, 3]));
+}
+
+#[test]
+fn test_invalid_polls_and_votes() {
+    let mut suite = SuiteBuilder::default().build();
+
+    let err = suite.propose("sender", &["only"], 1);
+    is_error!(
+        err,
+        &ContractError::InvalidOptions { max: MAX_OPTIONS }.to_string()
+    );
+    let many: Vec<String> = (0..MAX_OPTIONS + 1).map(|i| i.to_string()).collect();
+    let many: Vec<&str> = many.iter().map(|s| s.as_str()).collect();
+    let err = suite.propose("sender", &many, 1);
+    is_error!(
+        err,
+        &ContractError::InvalidOptions { max: MAX_OPTIONS }.to_string()
+    );
+    let err = suite.propose("sender", &["a", "b"], 3);
+    is_error!(err, &ContractError::InvalidMaxSelections {}.to_string());
+    let err = suite.propose("nobody", &["a", "b"], 1);
+    is_error!(err, &ContractError::ZeroVotingPower {}.to_string());
+
+    let id = suite
+        .propose("sender", &many[..MAX_OPTIONS as usize], 2)
+        .unwrap();
+    let err = suite.vote("sender", id, vec![MAX_OPTIONS]);
+    is_error!(
+        err,
+        &ContractError::InvalidOption {
+            option: MAX_OPTIONS
+        }
+        .to_string()
+    );
+    let err = suite.vote("sender", id, vec![4, 4]);
+    is_error!(
+        err,
+        &ContractError::DuplicateOption { option: 4 }.to_string()
+    );
+    let err = suite.vote("nobody", id, vec![0]);
+    is_error!(err, &ContractError::ZeroVotingPower {}.to_string());
+
+    // the highest option fits in the ballot bitmask.
+    suite.vote("sender", id, vec![MAX_OPTIONS - 1]).unwrap();
+    assert_eq!(suite.query_vote(id, "sender"), Some(vec![MAX_OPTIONS - 1]));
+
+    let polls = suite.query_list_polls(None).polls;
+    assert_eq!(polls.len(), 1);
+    assert_eq!(polls[0].leading, vec![MAX_OPTIONS - 1]);
+}
+
+#[test]
+fn test_zero_voting_period() {
+    let mut suite = SuiteBuilder::default().build();
+
+    let err = suite.instantiate_signaling("dao", Duration::Time(0));
+    is_error!(err, &ContractError::ZeroVotingPeriod {}.to_string());
+    let err = suite.instantiate_signaling("dao", Duration::Height(0));
+    is_error!(err, &ContractError::ZeroVotingPeriod {}.to_string());
+
+    let core = suite.core.clone();
+    let err = suite.update_config(core.clone(), Duration::Height(0));
+    is_error!(err, &ContractError::ZeroVotingPeriod {}.to_string());
+    let err = suite.update_config("sender", Duration::Height(10));
+    is_error!(err, &ContractError::NotDao {}.to_string());
+    suite.update_config(core, Duration::Height(10)).unwrap();
+}
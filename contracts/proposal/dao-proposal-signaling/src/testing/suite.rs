@@ -0,0 +1,246 @@
+use cosmwasm_std::{to_json_binary, Addr};
+use cw_multi_test::{next_block, App, Executor};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_testing::contracts::{
+    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, proposal_signaling_contract,
+};
+use dao_voting_cw4::msg::GroupContract;
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    poll::{PollListResponse, PollResponse, VoteResponse},
+};
+
+pub(crate) const VOTING_PERIOD: u64 = 60 * 60 * 24;
+
+pub(crate) struct Suite {
+    app: App,
+    pub signaling: Addr,
+    pub core: Addr,
+}
+
+pub(crate) struct SuiteBuilder {
+    with_voters: Vec<(String, u64)>,
+}
+
+impl Default for SuiteBuilder {
+    fn default() -> Self {
+        Self {
+            with_voters: vec![("sender".to_string(), 10)],
+        }
+    }
+}
+
+impl SuiteBuilder {
+    pub fn with_voters(mut self, voters: &[(&str, u64)]) -> Self {
+        self.with_voters = voters.iter().map(|(a, p)| (a.to_string(), *p)).collect();
+        self
+    }
+
+    pub fn build(self) -> Suite {
+        let initial_members: Vec<_> = self
+            .with_voters
+            .into_iter()
+            .map(|(addr, weight)| cw4::Member { addr, weight })
+            .collect();
+
+        let mut app = App::default();
+        let signaling_id = app.store_code(proposal_signaling_contract());
+        let core_id = app.store_code(dao_dao_contract());
+        let cw4_id = app.store_code(cw4_group_contract());
+        let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
+
+        let core_instantiate = dao_interface::msg::InstantiateMsg {
+            admin: None,
+            name: "core module".to_string(),
+            description: "core module".to_string(),
+            image_url: None,
+            automatically_add_cw20s: false,
+            automatically_add_cw721s: false,
+            voting_module_instantiate_info: ModuleInstantiateInfo {
+                code_id: cw4_voting_id,
+                msg: to_json_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                    group_contract: GroupContract::New {
+                        cw4_group_code_id: cw4_id,
+                        initial_members,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "voting module".to_string(),
+            },
+            proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+                code_id: signaling_id,
+                msg: to_json_binary(&InstantiateMsg {
+                    voting_period: Duration::Time(VOTING_PERIOD),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "signaling module".to_string(),
+            }],
+            initial_items: None,
+            dao_uri: None,
+        };
+        let core = app
+            .instantiate_contract(
+                core_id,
+                Addr::unchecked("creator"),
+                &core_instantiate,
+                &[],
+                "core module".to_string(),
+                None,
+            )
+            .unwrap();
+        let modules: Vec<dao_interface::state::ProposalModule> = app
+            .wrap()
+            .query_wasm_smart(
+                &core,
+                &dao_interface::msg::QueryMsg::ProposalModules {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let signaling = modules.into_iter().next().unwrap().address;
+
+        app.update_block(next_block);
+
+        Suite {
+            app,
+            signaling,
+            core,
+        }
+    }
+}
+
+impl Suite {
+    pub fn voting_period_passes(&mut self) {
+        self.app
+            .update_block(|b| b.time = b.time.plus_seconds(VOTING_PERIOD))
+    }
+
+    pub fn propose<S: Into<String>>(
+        &mut self,
+        sender: S,
+        options: &[&str],
+        max_selections: u32,
+    ) -> anyhow::Result<u64> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.signaling.clone(),
+            &ExecuteMsg::Propose {
+                title: "poll".to_string(),
+                description: "a temperature check".to_string(),
+                options: options.iter().map(|o| o.to_string()).collect(),
+                max_selections,
+            },
+            &[],
+        )?;
+        Ok(self.query_proposal_count())
+    }
+
+    pub fn update_config<S: Into<String>>(
+        &mut self,
+        sender: S,
+        voting_period: Duration,
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.signaling.clone(),
+                &ExecuteMsg::UpdateConfig { voting_period },
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    /// Instantiates a new signaling module owned by `sender`.
+    pub fn instantiate_signaling<S: Into<String>>(
+        &mut self,
+        sender: S,
+        voting_period: Duration,
+    ) -> anyhow::Result<Addr> {
+        let signaling_id = self.app.store_code(proposal_signaling_contract());
+        self.app.instantiate_contract(
+            signaling_id,
+            Addr::unchecked(sender),
+            &InstantiateMsg { voting_period },
+            &[],
+            "signaling module",
+            None,
+        )
+    }
+
+    pub fn vote<S: Into<String>>(
+        &mut self,
+        sender: S,
+        proposal_id: u64,
+        selections: Vec<u32>,
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.signaling.clone(),
+                &ExecuteMsg::Vote {
+                    proposal_id,
+                    selections,
+                },
+                &[],
+            )
+            .map(|_| ())
+    }
+}
+
+impl Suite {
+    pub fn query_poll(&self, proposal_id: u64) -> PollResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.signaling, &QueryMsg::Proposal { proposal_id })
+            .unwrap()
+    }
+
+    pub fn query_list_polls(&self, start_after: Option<u64>) -> PollListResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.signaling,
+                &QueryMsg::ListProposals {
+                    start_after,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn query_proposal_count(&self) -> u64 {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.signaling, &QueryMsg::ProposalCount {})
+            .unwrap()
+    }
+
+    pub fn query_vote(&self, proposal_id: u64, voter: &str) -> Option<Vec<u32>> {
+        let vote: VoteResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.signaling,
+                &QueryMsg::GetVote {
+                    proposal_id,
+                    voter: voter.to_string(),
+                },
+            )
+            .unwrap();
+        vote.selections
+    }
+
+    pub fn query_dao(&self) -> Addr {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.signaling, &QueryMsg::Dao {})
+            .unwrap()
+    }
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{Addr, Binary};
+use cw_utils::Duration;
+use dao_voting::voting::Vote;
+use sha2::{Digest, Sha256};
+
+use crate::ContractError;
+
+/// The commitment a voter submits during the commit phase of a
+/// commit-reveal proposal: the SHA-256 hash of
+/// `"{proposal_id}:{voter}:{vote}:{salt}"`, where `vote` is one of
+/// `yes`, `no`, or `abstain`. Binding the commitment to the voter
+/// prevents other voters from copying it.
+pub fn vote_commitment(proposal_id: u64, voter: &Addr, vote: Vote, salt: &str) -> Binary {
+    Binary::from(Sha256::digest(format!("{proposal_id}:{voter}:{vote}:{salt}")).to_vec())
+}
+
+/// Checks that the reveal period is non-zero and measured in the
+/// same units as the voting period it follows.
+pub fn validate_reveal_period(
+    max_voting_period: &Duration,
+    reveal_period: &Option<Duration>,
+) -> Result<(), ContractError> {
+    match (max_voting_period, reveal_period) {
+        (_, None) => Ok(()),
+        (Duration::Height(_), Some(Duration::Height(reveal)))
+        | (Duration::Time(_), Some(Duration::Time(reveal))) => {
+            if *reveal == 0 {
+                Err(ContractError::InvalidRevealPeriod {})
+            } else {
+                Ok(())
+            }
+        }
+        _ => Err(ContractError::InvalidRevealPeriod {}),
+    }
+}
@@ -1,10 +1,13 @@
+use std::ops::Add;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    MessageInfo, Order, Reply, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_denom::CheckedDenom;
 use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
@@ -13,10 +16,15 @@ use dao_hooks::proposal::{
 };
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
+use dao_voting::executor::ExecutorPolicy;
+use dao_voting::keeper::UncheckedKeeperBounty;
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
     SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
 };
+use dao_voting::quadratic::{
+    quadratic_weight, spend_credits, QuadraticMode, QuadraticVoting, QuadraticVotingError,
+};
 use dao_voting::reply::{
     failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
 };
@@ -25,9 +33,11 @@ use dao_voting::threshold::Threshold;
 use dao_voting::veto::{VetoConfig, VetoError};
 use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
 
+use crate::commit_reveal::{validate_reveal_period, vote_commitment};
 use crate::msg::MigrateMsg;
 use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::signed_vote::SignedVote;
+use crate::state::{Config, CREATION_POLICY, LEGACY_CONFIG};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
@@ -37,7 +47,10 @@ use crate::{
     proposal::advance_proposal_id,
     query::ProposalListResponse,
     query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
-    state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
+    state::{
+        Ballot, BALLOTS, CONFIG, CREDITS_SPENT, ESCROWED, KEEPER_BOUNTY, PROPOSALS, PROPOSAL_COUNT,
+        PROPOSAL_HOOKS, SIGNED_VOTE_NONCES, VOTE_COMMITMENTS, VOTE_HOOKS,
+    },
 };
 use cw_proposal_single_v1 as v1;
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
@@ -63,20 +76,31 @@ pub fn instantiate(
         .pre_propose_info
         .into_initial_policy_and_messages(dao.clone())?;
 
+    msg.executor_policy.validate(&deps.as_ref())?;
+
     // if veto is configured, validate its fields
     if let Some(veto_config) = &msg.veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(quadratic_voting) = &msg.quadratic_voting {
+        quadratic_voting.validate(&deps.as_ref())?;
+        quadratic_voting.validate_threshold(&msg.threshold)?;
+    }
+
+    validate_reveal_period(&max_voting_period, &msg.reveal_period)?;
+
     let config = Config {
         threshold: msg.threshold,
         max_voting_period,
         min_voting_period,
-        only_members_execute: msg.only_members_execute,
+        executor_policy: msg.executor_policy,
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
         veto: msg.veto,
+        quadratic_voting: msg.quadratic_voting,
+        reveal_period: msg.reveal_period,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -99,12 +123,28 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info.sender, propose_msg),
+        ExecuteMsg::Propose(propose_msg) => {
+            execute_propose(deps, env, info.sender, info.funds, propose_msg)
+        }
         ExecuteMsg::Vote {
             proposal_id,
             vote,
             rationale,
-        } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale, None),
+        ExecuteMsg::VoteWithCredits {
+            proposal_id,
+            vote,
+            votes,
+            rationale,
+        } => execute_vote(
+            deps,
+            env,
+            info.sender,
+            proposal_id,
+            vote,
+            rationale,
+            Some(votes),
+        ),
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
@@ -115,22 +155,26 @@ pub fn execute(
             threshold,
             max_voting_period,
             min_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
+            reveal_period,
         } => execute_update_config(
             deps,
             info,
             threshold,
             max_voting_period,
             min_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
+            reveal_period,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -146,6 +190,22 @@ pub fn execute(
             execute_remove_vote_hook(deps, env, info, address)
         }
         ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateKeeperBounty { bounty } => {
+            execute_update_keeper_bounty(deps, env, info, bounty)
+        }
+        ExecuteMsg::SubmitSignedVotes { votes } => {
+            execute_submit_signed_votes(deps, env, info, votes)
+        }
+        ExecuteMsg::CommitVote {
+            proposal_id,
+            commitment,
+        } => execute_commit_vote(deps, env, info, proposal_id, commitment),
+        ExecuteMsg::RevealVote {
+            proposal_id,
+            vote,
+            salt,
+            rationale,
+        } => execute_reveal_vote(deps, env, info, proposal_id, vote, salt, rationale),
     }
 }
 
@@ -153,6 +213,7 @@ pub fn execute_propose(
     deps: DepsMut,
     env: Env,
     sender: Addr,
+    funds: Vec<Coin>,
     ProposeMsg {
         title,
         description,
@@ -198,7 +259,18 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    let expiration = config.max_voting_period.after(&env.block);
+    // Commit-reveal proposals are voted on for the maximum voting
+    // period, and then revealed for the reveal period.
+    let (commit_expiration, expiration) = match config.reveal_period {
+        Some(reveal_period) => {
+            let commit_expiration = config.max_voting_period.after(&env.block);
+            (
+                Some(commit_expiration),
+                commit_expiration.add(reveal_period)?,
+            )
+        }
+        None => (None, config.max_voting_period.after(&env.block)),
+    };
 
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
 
@@ -218,6 +290,10 @@ pub fn execute_propose(
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
             veto: config.veto,
+            quadratic_voting: config.quadratic_voting,
+            turnout: Uint128::zero(),
+            commit_expiration,
+            escrow: funds,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -249,12 +325,25 @@ pub fn execute_propose(
     }
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    for coin in &proposal.escrow {
+        ESCROWED.update(deps.storage, &coin.denom, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().checked_add(coin.amount)?)
+        })?;
+    }
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
     // Auto cast vote if given.
     let (vote_hooks, vote_attributes) = if let Some(vote) = vote {
-        let response = execute_vote(deps, env, proposer, id, vote.vote, vote.rationale.clone())?;
+        let response = execute_vote(
+            deps,
+            env,
+            proposer,
+            id,
+            vote.vote,
+            vote.rationale.clone(),
+            None,
+        )?;
         (
             response.messages,
             vec![
@@ -333,6 +422,7 @@ pub fn execute_veto(
     // Update proposal status to vetoed
     prop.status = Status::Vetoed;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    let refund = refund_escrow(deps.storage, &prop)?;
 
     // Add proposal status change hooks
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
@@ -346,11 +436,12 @@ pub fn execute_veto(
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status, None)?;
 
     Ok(Response::new()
         .add_attribute("action", "veto")
         .add_attribute("proposal_id", proposal_id.to_string())
+        .add_messages(refund)
         .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks))
 }
@@ -368,17 +459,13 @@ pub fn execute_execute(
     let config = CONFIG.load(deps.storage)?;
 
     // determine if this sender can execute
-    let mut sender_can_execute = true;
-    if config.only_members_execute {
-        let power = get_voting_power(
-            deps.as_ref(),
-            info.sender.clone(),
-            &config.dao,
-            Some(prop.start_height),
-        )?;
-
-        sender_can_execute = !power.is_zero();
-    }
+    let sender_can_execute = config.executor_policy.can_execute(
+        deps.as_ref(),
+        &config.dao,
+        &info.sender,
+        &prop.proposer,
+        prop.start_height,
+    )?;
 
     // Check here that the proposal is passed or timelocked.
     // Allow it to be executed even if it is expired so long
@@ -424,14 +511,22 @@ pub fn execute_execute(
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
+    // The bounty is computed before the escrow is released so that
+    // it may not be paid out of the funds sent to the DAO.
+    let (bounty_payout, bounty_attribute) = keeper_bounty(deps.as_ref(), &env, &info)?;
+    release_escrow(deps.storage, &prop.escrow)?;
+
     let response = {
-        if !prop.msgs.is_empty() {
+        // Escrowed funds are sent along with the proposal's messages
+        // so that they are available to them, and are returned to
+        // this module if execution fails.
+        if !prop.msgs.is_empty() || !prop.escrow.is_empty() {
             let execute_message = WasmMsg::Execute {
                 contract_addr: config.dao.to_string(),
                 msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
                     msgs: prop.msgs,
                 })?,
-                funds: vec![],
+                funds: prop.escrow,
             };
             match config.close_proposal_on_execution_failure {
                 true => {
@@ -457,16 +552,22 @@ pub fn execute_execute(
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        proposal_id,
+        prop.status,
+        Some(&info.sender),
+    )?;
 
     Ok(response
         .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks)
+        .add_messages(bounty_payout)
         .add_attribute("action", "execute")
         .add_attribute("sender", info.sender)
         .add_attribute("proposal_id", proposal_id.to_string())
-        .add_attribute("dao", config.dao))
+        .add_attribute("dao", config.dao)
+        .add_attribute("keeper_bounty", bounty_attribute))
 }
 
 pub fn execute_vote(
@@ -476,6 +577,108 @@ pub fn execute_vote(
     proposal_id: u64,
     vote: Vote,
     rationale: Option<String>,
+    votes: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    let prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Votes on commit-reveal proposals are only counted once
+    // revealed.
+    if prop.commit_expiration.is_some() {
+        return Err(ContractError::CommitRevealProposal { id: proposal_id });
+    }
+
+    cast_vote(deps, env, sender, proposal_id, vote, rationale, votes)
+}
+
+pub fn execute_commit_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    commitment: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let commit_expiration = prop
+        .commit_expiration
+        .ok_or(ContractError::NotCommitReveal { id: proposal_id })?;
+    if commit_expiration.is_expired(&env.block) {
+        return Err(ContractError::CommitPhaseEnded { id: proposal_id });
+    }
+
+    if commitment.len() != 32 {
+        return Err(ContractError::InvalidCommitment {});
+    }
+
+    let vote_power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?;
+    if vote_power.is_zero() {
+        return Err(ContractError::NotRegistered {});
+    }
+
+    VOTE_COMMITMENTS.save(deps.storage, (proposal_id, &info.sender), &commitment)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "commit_vote")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_reveal_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: Vote,
+    salt: String,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    let prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let commit_expiration = prop
+        .commit_expiration
+        .ok_or(ContractError::NotCommitReveal { id: proposal_id })?;
+    if !commit_expiration.is_expired(&env.block) {
+        return Err(ContractError::CommitPhaseOpen { id: proposal_id });
+    }
+
+    let commitment = VOTE_COMMITMENTS
+        .may_load(deps.storage, (proposal_id, &info.sender))?
+        .ok_or_else(|| ContractError::NoSuchCommitment {
+            id: proposal_id,
+            voter: info.sender.to_string(),
+        })?;
+    if commitment != vote_commitment(proposal_id, &info.sender, vote, &salt) {
+        return Err(ContractError::CommitmentMismatch {});
+    }
+    VOTE_COMMITMENTS.remove(deps.storage, (proposal_id, &info.sender));
+
+    cast_vote(deps, env, info.sender, proposal_id, vote, rationale, None)
+}
+
+/// Records `sender`'s vote on a proposal, updating its tally and
+/// status. `votes` is the number of votes to cast on proposals using
+/// credit quadratic voting.
+fn cast_vote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    vote: Vote,
+    rationale: Option<String>,
+    votes: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let mut prop = PROPOSALS
@@ -503,10 +706,43 @@ pub fn execute_vote(
         return Err(ContractError::NotRegistered {});
     }
 
+    // Quadratic proposals count the square root of the voter's
+    // voting power, or the number of votes bought with their
+    // credits, provided they pass the identity gate. Credits are
+    // recorded as spent once the ballot is known to be valid.
+    let mut credits_spent = None;
+    let vote_power = match &prop.quadratic_voting {
+        Some(quadratic_voting) => {
+            quadratic_voting.check_identity(deps.as_ref(), &sender, prop.start_height)?;
+            if !BALLOTS.has(deps.storage, (proposal_id, &sender)) {
+                prop.turnout += vote_power;
+            }
+            match quadratic_voting.mode {
+                QuadraticMode::PerBallot {} => {
+                    if votes.is_some() {
+                        return Err(QuadraticVotingError::NotCreditVoting {}.into());
+                    }
+                    quadratic_weight(vote_power)
+                }
+                QuadraticMode::Credits {} => {
+                    let available =
+                        available_credits(deps.storage, &env, &sender, proposal_id, vote_power)?;
+                    let (votes, cost) = spend_credits(available, votes)?;
+                    credits_spent = Some(cost);
+                    votes
+                }
+            }
+        }
+        None if votes.is_some() => {
+            return Err(QuadraticVotingError::NotCreditVoting {}.into());
+        }
+        None => vote_power,
+    };
+
     BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
-                if current_ballot.vote == vote {
+                if current_ballot.vote == vote && current_ballot.power == vote_power {
                     // Don't allow casting the same vote more than
                     // once. This seems liable to be confusing
                     // behavior.
@@ -534,6 +770,9 @@ pub fn execute_vote(
             rationale: rationale.clone(),
         }),
     })?;
+    if let Some(cost) = credits_spent {
+        CREDITS_SPENT.save(deps.storage, (&sender, proposal_id), &cost)?;
+    }
 
     let old_status = prop.status;
 
@@ -573,6 +812,103 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Returns the credits `voter` may spend on `proposal_id`: their
+/// voting power less the credits spent on other proposals that are
+/// still open for voting. Spends on expired proposals are released
+/// and removed from storage. Credits already spent on `proposal_id`
+/// are not counted, as a revote replaces them.
+fn available_credits(
+    storage: &mut dyn Storage,
+    env: &Env,
+    voter: &Addr,
+    proposal_id: u64,
+    power: Uint128,
+) -> Result<Uint128, ContractError> {
+    let spends = CREDITS_SPENT
+        .prefix(voter)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut locked = Uint128::zero();
+    for (id, spent) in spends {
+        if id == proposal_id {
+            continue;
+        }
+        let expired = match PROPOSALS.may_load(storage, id)? {
+            Some(prop) => prop.expiration.is_expired(&env.block),
+            None => true,
+        };
+        if expired {
+            CREDITS_SPENT.remove(storage, (voter, id));
+        } else {
+            locked += spent;
+        }
+    }
+    Ok(power.saturating_sub(locked))
+}
+
+pub fn execute_submit_signed_votes(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<SignedVote>,
+) -> Result<Response, ContractError> {
+    let mut response = Response::default()
+        .add_attribute("action", "submit_signed_votes")
+        .add_attribute("relayer", info.sender)
+        .add_attribute("count", votes.len().to_string());
+
+    // A vote that fails does not fail the batch. Its outcome is
+    // reported in an `outcome` attribute, one per vote in order.
+    let mut counted = 0;
+    for signed_vote in votes {
+        let outcome = match cast_signed_vote(deps.branch(), &env, signed_vote) {
+            Ok(vote_response) => {
+                counted += 1;
+                response = response
+                    .add_submessages(vote_response.messages)
+                    .add_attributes(vote_response.attributes);
+                "counted".to_string()
+            }
+            Err(err) => format!("skipped: {err}"),
+        };
+        response = response.add_attribute("outcome", outcome);
+    }
+
+    Ok(response.add_attribute("counted", counted.to_string()))
+}
+
+/// Verifies and casts a signed vote. The voter's nonce is consumed
+/// once the signature and nonce are verified, even if casting the
+/// vote fails, so that a failed vote can not be replayed later.
+fn cast_signed_vote(
+    deps: DepsMut,
+    env: &Env,
+    signed_vote: SignedVote,
+) -> Result<Response, ContractError> {
+    let (voter, payload) = signed_vote.verify(deps.as_ref(), env)?;
+
+    let nonce = SIGNED_VOTE_NONCES
+        .may_load(deps.storage, &voter)?
+        .unwrap_or_default();
+    if payload.nonce != nonce {
+        return Err(ContractError::WrongNonce {
+            expected: nonce,
+            actual: payload.nonce,
+        });
+    }
+    SIGNED_VOTE_NONCES.save(deps.storage, &voter, &(nonce + 1))?;
+
+    execute_vote(
+        deps,
+        env.clone(),
+        voter,
+        payload.proposal_id,
+        payload.vote,
+        payload.rationale,
+        None,
+    )
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -623,6 +959,9 @@ pub fn execute_close(
     prop.status = Status::Closed;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
+    let (bounty_payout, bounty_attribute) = keeper_bounty(deps.as_ref(), &env, &info)?;
+    let refund = refund_escrow(deps.storage, &prop)?;
+
     // Add proposal status change hooks
     let proposal_status_changed_hooks = proposal_status_changed_hooks(
         PROPOSAL_HOOKS,
@@ -634,15 +973,82 @@ pub fn execute_close(
 
     // Add prepropose / deposit module hook which will handle deposit refunds.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
-    let proposal_completed_hooks =
-        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+    let proposal_completed_hooks = proposal_completed_hooks(
+        proposal_creation_policy,
+        proposal_id,
+        prop.status,
+        Some(&info.sender),
+    )?;
 
     Ok(Response::default()
+        .add_messages(refund)
         .add_submessages(proposal_status_changed_hooks)
         .add_submessages(proposal_completed_hooks)
+        .add_messages(bounty_payout)
         .add_attribute("action", "close")
         .add_attribute("sender", info.sender)
-        .add_attribute("proposal_id", proposal_id.to_string()))
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("keeper_bounty", bounty_attribute))
+}
+
+/// Returns the message paying the keeper bounty to the sender (if
+/// one is configured and this module's balance can cover it) along
+/// with the amount paid for use as an attribute.
+fn keeper_bounty(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+) -> StdResult<(Option<CosmosMsg>, String)> {
+    let payout = match KEEPER_BOUNTY.may_load(deps.storage)? {
+        Some(bounty) => {
+            // Funds escrowed with proposals may not be used to pay
+            // bounties.
+            let reserved = match &bounty.denom {
+                CheckedDenom::Native(denom) => {
+                    ESCROWED.may_load(deps.storage, denom)?.unwrap_or_default()
+                }
+                CheckedDenom::Cw20(_) => Uint128::zero(),
+            };
+            bounty
+                .payout(deps, &env.contract.address, &info.sender, reserved)?
+                .map(|msg| (msg, bounty.amount))
+        }
+        None => None,
+    };
+    Ok(match payout {
+        Some((msg, amount)) => (Some(msg), amount.to_string()),
+        None => (None, "_none".to_string()),
+    })
+}
+
+/// Removes funds that are leaving this module from the total held in
+/// escrow.
+fn release_escrow(storage: &mut dyn Storage, escrow: &[Coin]) -> StdResult<()> {
+    for coin in escrow {
+        ESCROWED.update(storage, &coin.denom, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().checked_sub(coin.amount)?)
+        })?;
+    }
+    Ok(())
+}
+
+/// Releases a proposal's escrow and returns the message refunding it
+/// to the proposer (if anything was escrowed).
+fn refund_escrow(
+    storage: &mut dyn Storage,
+    prop: &SingleChoiceProposal,
+) -> StdResult<Option<CosmosMsg>> {
+    if prop.escrow.is_empty() {
+        return Ok(None);
+    }
+    release_escrow(storage, &prop.escrow)?;
+    Ok(Some(
+        BankMsg::Send {
+            to_address: prop.proposer.to_string(),
+            amount: prop.escrow.clone(),
+        }
+        .into(),
+    ))
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -652,11 +1058,13 @@ pub fn execute_update_config(
     threshold: Threshold,
     max_voting_period: Duration,
     min_voting_period: Option<Duration>,
-    only_members_execute: bool,
+    executor_policy: ExecutorPolicy,
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
     veto: Option<VetoConfig>,
+    quadratic_voting: Option<QuadraticVoting>,
+    reveal_period: Option<Duration>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -670,22 +1078,33 @@ pub fn execute_update_config(
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
 
+    executor_policy.validate(&deps.as_ref())?;
+
     // if veto is configured, validate its fields
     if let Some(veto_config) = &veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
     };
 
+    if let Some(quadratic_voting) = &quadratic_voting {
+        quadratic_voting.validate(&deps.as_ref())?;
+        quadratic_voting.validate_threshold(&threshold)?;
+    }
+
+    validate_reveal_period(&max_voting_period, &reveal_period)?;
+
     CONFIG.save(
         deps.storage,
         &Config {
             threshold,
             max_voting_period,
             min_voting_period,
-            only_members_execute,
+            executor_policy,
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
             veto,
+            quadratic_voting,
+            reveal_period,
         },
     )?;
 
@@ -820,6 +1239,33 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_keeper_bounty(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    bounty: Option<UncheckedKeeperBounty>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let bounty = bounty
+        .map(|bounty| bounty.into_checked(deps.as_ref()))
+        .transpose()?;
+    match &bounty {
+        Some(bounty) => KEEPER_BOUNTY.save(deps.storage, bounty)?,
+        None => KEEPER_BOUNTY.remove(deps.storage),
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "update_keeper_bounty")
+        .add_attribute(
+            "keeper_bounty",
+            bounty.map_or_else(|| "_none".to_string(), |b| b.amount.to_string()),
+        ))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -845,6 +1291,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::KeeperBounty {} => to_json_binary(&KEEPER_BOUNTY.may_load(deps.storage)?),
+        QueryMsg::SignedVoteNonce { voter } => query_signed_vote_nonce(deps, voter),
+        QueryMsg::VoteCommitment { proposal_id, voter } => {
+            query_vote_commitment(deps, proposal_id, voter)
+        }
     }
 }
 
@@ -957,6 +1408,20 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_signed_vote_nonce(deps: Deps, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    to_json_binary(
+        &SIGNED_VOTE_NONCES
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default(),
+    )
+}
+
+pub fn query_vote_commitment(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    to_json_binary(&VOTE_COMMITMENTS.may_load(deps.storage, (proposal_id, &voter))?)
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -997,11 +1462,13 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     threshold: v1_threshold_to_v2(current_config.threshold),
                     max_voting_period,
                     min_voting_period: current_config.min_voting_period.map(v1_duration_to_v2),
-                    only_members_execute: current_config.only_members_execute,
+                    executor_policy: current_config.only_members_execute.into(),
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
                     veto,
+                    quadratic_voting: None,
+                    reveal_period: None,
                 },
             )?;
 
@@ -1047,6 +1514,10 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
                         veto: None,
+                        quadratic_voting: None,
+                        turnout: Uint128::zero(),
+                        commit_expiration: None,
+                        escrow: vec![],
                     };
 
                     PROPOSALS
@@ -1059,9 +1530,19 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                 .add_attribute("from", "v1")
                 .add_submessages(pre_propose_messages))
         }
-        MigrateMsg::FromCompatible {} => Ok(Response::default()
-            .add_attribute("action", "migrate")
-            .add_attribute("from", "compatible")),
+        MigrateMsg::FromCompatible {} => {
+            // Configs saved before executor policies were introduced
+            // store an `only_members_execute` flag in their place. A
+            // config in the current format will fail to load as a
+            // legacy config and is left as is.
+            if let Ok(legacy_config) = LEGACY_CONFIG.load(deps.storage) {
+                CONFIG.save(deps.storage, &legacy_config.into())?;
+            }
+
+            Ok(Response::default()
+                .add_attribute("action", "migrate")
+                .add_attribute("from", "compatible"))
+        }
     }
 }
 
@@ -1070,7 +1551,7 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
-            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+            let prop = PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
 
@@ -1079,7 +1560,16 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 None => Err(ContractError::NoSuchProposal { id: proposal_id }),
             })?;
 
+            // The escrow was returned to this module when execution
+            // failed and was already released, so it is refunded
+            // directly.
+            let refund = (!prop.escrow.is_empty()).then(|| BankMsg::Send {
+                to_address: prop.proposer.to_string(),
+                amount: prop.escrow,
+            });
+
             Ok(Response::new()
+                .add_messages(refund)
                 .add_attribute("proposal_execution_failed", proposal_id.to_string())
                 .add_attribute("error", msg.result.into_result().err().unwrap_or_default()))
         }
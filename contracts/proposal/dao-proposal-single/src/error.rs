@@ -1,7 +1,10 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, VerificationError};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
-use dao_voting::{reply::error::TagError, veto::VetoError};
+use dao_voting::{
+    executor::ExecutorPolicyError, keeper::KeeperBountyError, quadratic::QuadraticVotingError,
+    reply::error::TagError, veto::VetoError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -18,9 +21,57 @@ pub enum ContractError {
     #[error(transparent)]
     VetoError(#[from] VetoError),
 
+    #[error(transparent)]
+    ExecutorPolicyError(#[from] ExecutorPolicyError),
+
+    #[error(transparent)]
+    QuadraticVotingError(#[from] QuadraticVotingError),
+
+    #[error(transparent)]
+    KeeperBountyError(#[from] KeeperBountyError),
+
     #[error("unauthorized")]
     Unauthorized {},
 
+    #[error(transparent)]
+    VerificationError(#[from] VerificationError),
+
+    #[error("reveal period must be non-zero and have the same units as max_voting_period")]
+    InvalidRevealPeriod {},
+
+    #[error("proposal ({id}) uses commit-reveal voting. commit and reveal your vote instead")]
+    CommitRevealProposal { id: u64 },
+
+    #[error("proposal ({id}) does not use commit-reveal voting")]
+    NotCommitReveal { id: u64 },
+
+    #[error("the commit phase of proposal ({id}) has ended")]
+    CommitPhaseEnded { id: u64 },
+
+    #[error("votes on proposal ({id}) may not be revealed until the commit phase ends")]
+    CommitPhaseOpen { id: u64 },
+
+    #[error("vote commitments must be a 32 byte SHA-256 hash")]
+    InvalidCommitment {},
+
+    #[error("no vote commitment exists for proposal ({id}) and voter ({voter})")]
+    NoSuchCommitment { id: u64, voter: String },
+
+    #[error("revealed vote does not match commitment")]
+    CommitmentMismatch {},
+
+    #[error("signed vote's public key does not match the voter's address")]
+    SignerMismatch {},
+
+    #[error("invalid signature on signed vote")]
+    InvalidSignature {},
+
+    #[error("signed vote was signed for a different chain or proposal module")]
+    WrongSigningDomain {},
+
+    #[error("signed vote has nonce ({actual}), expected ({expected})")]
+    WrongNonce { expected: u64, actual: u64 },
+
     #[error(transparent)]
     ThresholdError(#[from] dao_voting::threshold::ThresholdError),
 
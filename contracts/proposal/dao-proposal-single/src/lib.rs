@@ -1,10 +1,12 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+pub mod commit_reveal;
 pub mod contract;
 mod error;
 pub mod msg;
 pub mod proposal;
 pub mod query;
+pub mod signed_vote;
 
 #[cfg(test)]
 mod testing;
@@ -1,11 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Uint128};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
+    executor::ExecutorPolicy, keeper::UncheckedKeeperBounty, pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg, quadratic::QuadraticVoting, threshold::Threshold,
     veto::VetoConfig, voting::Vote,
 };
 
+use crate::signed_vote::SignedVote;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The threshold a proposal must reach to complete.
@@ -19,10 +23,8 @@ pub struct InstantiateMsg {
     /// preventing governance attacks wherein an attacker aquires a
     /// large number of tokens and forces a proposal through.
     pub min_voting_period: Option<Duration>,
-    /// If set to true only members may execute passed
-    /// proposals. Otherwise, any address may execute a passed
-    /// proposal.
-    pub only_members_execute: bool,
+    /// Who may execute passed proposals.
+    pub executor_policy: ExecutorPolicy,
     /// Allows changing votes before the proposal expires. If this is
     /// enabled proposals will not be able to complete early as final
     /// vote information is not known until the time of proposal
@@ -44,11 +46,26 @@ pub struct InstantiateMsg {
     /// During this period an oversight account (`veto.vetoer`) can
     /// veto the proposal.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, votes are
+    /// counted with the square root of the voter's voting power,
+    /// limiting the influence of large holders. May be combined with
+    /// an identity gate to make splitting voting power across many
+    /// addresses harder.
+    pub quadratic_voting: Option<QuadraticVoting>,
+    /// If set, proposals use commit-reveal voting. Voters submit a
+    /// hash of their vote for `max_voting_period` and reveal it
+    /// during the `reveal_period` that follows, hiding running
+    /// tallies while voting is open. Only revealed votes are
+    /// counted. Must have the same units as `max_voting_period`.
+    pub reveal_period: Option<Duration>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Creates a proposal in the module.
+    /// Creates a proposal in the module. Native tokens sent with
+    /// this message are escrowed with the proposal. They are sent to
+    /// the DAO before the proposal's messages when it is executed,
+    /// and refunded to the proposer if it is closed or vetoed.
     Propose(SingleChoiceProposeMsg),
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
@@ -62,6 +79,20 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Votes on a proposal using credit quadratic voting, spending
+    /// `votes²` of the sender's credits to cast `votes` votes. A
+    /// plain `Vote` on such a proposal casts as many votes as the
+    /// sender's credits afford. Revoting replaces the previous spend.
+    VoteWithCredits {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The senders position on the proposal.
+        vote: Vote,
+        /// The number of votes to cast.
+        votes: Uint128,
+        /// An optional rationale for why this vote was cast.
+        rationale: Option<String>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
@@ -101,10 +132,9 @@ pub enum ExecuteMsg {
         /// preventing governance attacks wherein an attacker aquires a
         /// large number of tokens and forces a proposal through.
         min_voting_period: Option<Duration>,
-        /// If set to true only members may execute passed
-        /// proposals. Otherwise, any address may execute a passed
-        /// proposal. Applies to all outstanding and future proposals.
-        only_members_execute: bool,
+        /// Who may execute passed proposals. Applies to all
+        /// outstanding and future proposals.
+        executor_policy: ExecutorPolicy,
         /// Allows changing votes before the proposal expires. If this is
         /// enabled proposals will not be able to complete early as final
         /// vote information is not known until the time of proposal
@@ -124,6 +154,12 @@ pub enum ExecuteMsg {
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
+        /// Optional quadratic voting configuration. This will only
+        /// apply to proposals created after the config update.
+        quadratic_voting: Option<QuadraticVoting>,
+        /// Optional commit-reveal voting reveal period. This will
+        /// only apply to proposals created after the config update.
+        reveal_period: Option<Duration>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -143,6 +179,41 @@ pub enum ExecuteMsg {
     AddVoteHook { address: String },
     /// Removed a consumer of vote hooks.
     RemoveVoteHook { address: String },
+    /// Sets the bounty paid to whoever executes or closes a
+    /// proposal. Bounties are paid out of this module's balance, so
+    /// the DAO must fund it for bounties to be paid. Setting this to
+    /// `None` disables bounties. Only the DAO may call this method.
+    UpdateKeeperBounty {
+        bounty: Option<UncheckedKeeperBounty>,
+    },
+    /// Casts votes that were signed off-chain by voters, allowing
+    /// voters without gas tokens to have a relayer submit their
+    /// votes. Invalid votes are skipped, and the outcome of each vote
+    /// is reported in an `outcome` attribute.
+    SubmitSignedVotes { votes: Vec<SignedVote> },
+    /// Commits to a vote on a commit-reveal proposal during its
+    /// commit phase. `commitment` is the hash described in
+    /// `commit_reveal::vote_commitment`. Committing again replaces
+    /// the previous commitment.
+    CommitVote {
+        /// The ID of the proposal to commit a vote on.
+        proposal_id: u64,
+        /// The hash of the sender's vote and salt.
+        commitment: Binary,
+    },
+    /// Reveals a vote committed to with `CommitVote` once the commit
+    /// phase of the proposal has ended. The vote is counted with the
+    /// sender's voting power at the proposal's start height.
+    RevealVote {
+        /// The ID of the proposal to reveal a vote on.
+        proposal_id: u64,
+        /// The committed position on the proposal.
+        vote: Vote,
+        /// The salt used when computing the commitment.
+        salt: String,
+        /// An optional rationale for why this vote was cast.
+        rationale: Option<String>,
+    },
 }
 
 #[proposal_module_query]
@@ -209,6 +280,17 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the bounty paid to whoever executes or closes a
+    /// proposal, if any.
+    #[returns(Option<::dao_voting::keeper::KeeperBounty>)]
+    KeeperBounty {},
+    /// Returns the nonce that `voter`'s next signed vote must have.
+    #[returns(::std::primitive::u64)]
+    SignedVoteNonce { voter: String },
+    /// Returns `voter`'s unrevealed vote commitment on a commit-reveal
+    /// proposal, if any.
+    #[returns(Option<::cosmwasm_std::Binary>)]
+    VoteCommitment { proposal_id: u64, voter: String },
 }
 
 #[cw_serde]
@@ -3,8 +3,9 @@ use std::ops::Add;
 use crate::query::ProposalResponse;
 use crate::state::PROPOSAL_COUNT;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
+use cosmwasm_std::{Addr, BlockInfo, Coin, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_utils::Expiration;
+use dao_voting::quadratic::QuadraticVoting;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::veto::VetoConfig;
@@ -46,6 +47,27 @@ pub struct SingleChoiceProposal {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, `votes` holds
+    /// the square root of each voter's voting power.
+    #[serde(default)]
+    pub quadratic_voting: Option<QuadraticVoting>,
+    /// The total (linear) voting power of addresses that have voted
+    /// on this proposal. Only tracked when quadratic voting is
+    /// enabled, in which case it is used to determine if quorum has
+    /// been reached.
+    #[serde(default)]
+    pub turnout: Uint128,
+    /// For commit-reveal proposals, the time at which the commit
+    /// phase ends. Votes are committed until then and revealed until
+    /// `expiration`.
+    #[serde(default)]
+    pub commit_expiration: Option<Expiration>,
+    /// Native tokens attached to the proposal by its proposer. Held
+    /// by this module until the proposal is executed, in which case
+    /// they are sent to the DAO, or closed or vetoed, in which case
+    /// they are refunded to the proposer.
+    #[serde(default)]
+    pub escrow: Vec<Coin>,
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -112,13 +134,33 @@ impl SingleChoiceProposal {
         Ok(())
     }
 
+    /// The voting power used to determine if quorum has been
+    /// reached. As quadratic votes are not comparable with the total
+    /// voting power, the linear turnout is used for them instead.
+    fn quorum_power(&self) -> Uint128 {
+        match self.quadratic_voting {
+            Some(_) => self.turnout,
+            None => self.votes.total(),
+        }
+    }
+
+    /// Whether the outcome of this proposal may only be determined
+    /// once it has expired. This is the case when revoting is allowed,
+    /// when votes are quadratic, as the remaining quadratic voting
+    /// power is unknown, and when votes are committed before being
+    /// revealed, as committed votes may never be revealed.
+    fn is_outcome_deferred(&self, block: &BlockInfo) -> bool {
+        (self.allow_revoting || self.quadratic_voting.is_some() || self.commit_expiration.is_some())
+            && !self.expiration.is_expired(block)
+    }
+
     /// Returns true iff this proposal is sure to pass (even before
     /// expiration if no future sequence of possible votes can cause
     /// it to fail).
     pub fn is_passed(&self, block: &BlockInfo) -> bool {
         // If re-voting is allowed nothing is known until the proposal
         // has expired.
-        if self.allow_revoting && !self.expiration.is_expired(block) {
+        if self.is_outcome_deferred(block) {
             return false;
         }
         // If the min voting period is set and not expired the
@@ -138,7 +180,7 @@ impl SingleChoiceProposal {
                 does_vote_count_pass(self.votes.yes, options, percentage)
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
-                if !does_vote_count_pass(self.votes.total(), self.total_power, quorum) {
+                if !does_vote_count_pass(self.quorum_power(), self.total_power, quorum) {
                     return false;
                 }
 
@@ -163,7 +205,7 @@ impl SingleChoiceProposal {
     pub fn is_rejected(&self, block: &BlockInfo) -> bool {
         // If re-voting is allowed and the proposal is not expired no
         // information is known.
-        if self.allow_revoting && !self.expiration.is_expired(block) {
+        if self.is_outcome_deferred(block) {
             return false;
         }
 
@@ -197,7 +239,7 @@ impl SingleChoiceProposal {
             }
             Threshold::ThresholdQuorum { threshold, quorum } => {
                 match (
-                    does_vote_count_pass(self.votes.total(), self.total_power, quorum),
+                    does_vote_count_pass(self.quorum_power(), self.total_power, quorum),
                     self.expiration.is_expired(block),
                 ) {
                     // Has met quorum and is expired.
@@ -279,6 +321,7 @@ mod test {
         testing::{mock_dependencies, mock_env},
         Decimal,
     };
+    use dao_voting::quadratic::QuadraticMode;
 
     fn setup_prop(
         threshold: Threshold,
@@ -312,6 +355,10 @@ mod test {
             veto: None,
             total_power,
             votes,
+            quadratic_voting: None,
+            turnout: Uint128::zero(),
+            commit_expiration: None,
+            escrow: vec![],
         };
         (prop, block)
     }
@@ -1126,6 +1173,71 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_quadratic_quorum_uses_turnout() {
+        // Quadratic votes are compared against each other to
+        // determine if the threshold is met, while the linear turnout
+        // is compared against the total power to determine if quorum
+        // is met.
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+        };
+        // A voter with 100 power votes no, four voters with 4 power
+        // each vote yes.
+        let votes = Votes {
+            yes: Uint128::new(8),
+            no: Uint128::new(10),
+            abstain: Uint128::zero(),
+        };
+
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(200), true, true, false);
+        prop.quadratic_voting = Some(QuadraticVoting {
+            identity_gate: None,
+            mode: QuadraticMode::PerBallot {},
+        });
+        prop.turnout = Uint128::new(116);
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        // Turnout below quorum. Had the quadratic votes been used to
+        // determine quorum this would have been rejected regardless.
+        prop.turnout = Uint128::new(99);
+        prop.votes.yes = Uint128::new(12);
+        assert!(!prop.is_passed(&block));
+
+        prop.turnout = Uint128::new(100);
+        assert!(prop.is_passed(&block));
+    }
+
+    #[test]
+    fn test_quadratic_no_early_pass() {
+        // The quadratic weight of the outstanding voting power is not
+        // known, so quadratic proposals may not complete early.
+        let threshold = Threshold::AbsoluteCount {
+            threshold: Uint128::new(10),
+        };
+        let votes = Votes {
+            yes: Uint128::new(10),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+        };
+
+        let (mut prop, block) = setup_prop(threshold, votes, Uint128::new(200), false, true, false);
+        assert!(prop.is_passed(&block));
+
+        prop.quadratic_voting = Some(QuadraticVoting {
+            identity_gate: None,
+            mode: QuadraticMode::PerBallot {},
+        });
+        prop.turnout = Uint128::new(100);
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_rejected(&block));
+
+        prop.expiration = Expiration::AtHeight(block.height - 1);
+        assert!(prop.is_passed(&block));
+    }
+
     #[test]
     fn test_proposal_ids_advance() {
         // do they advance, lets find out!
@@ -0,0 +1,97 @@
+use bech32::FromBase32;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Addr, Binary, Deps, Env};
+use dao_voting::voting::Vote;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::ContractError;
+
+/// A vote signed off-chain by a voter and submitted on their behalf
+/// by a relayer.
+///
+/// The voter signs `data` using [ADR-036] arbitrary message signing,
+/// which is supported by most Cosmos wallets. The signed sign doc
+/// has an empty chain id and a single `sign/MsgSignData` message
+/// whose signer is `voter` and whose data is `data`.
+///
+/// [ADR-036]: https://docs.cosmos.network/main/build/architecture/adr-036-arbitrary-signature
+#[cw_serde]
+pub struct SignedVote {
+    /// The JSON encoded `SignedVotePayload` that was signed.
+    pub data: Binary,
+    /// The address of the voter. This must be the address of
+    /// `pubkey`.
+    pub voter: String,
+    /// The voter's compressed secp256k1 public key.
+    pub pubkey: Binary,
+    /// The voter's signature over the ADR-036 sign doc.
+    pub signature: Binary,
+}
+
+/// The vote a voter signs. `chain_id` and `contract` bind the
+/// signature to this proposal module, and `nonce` must equal the
+/// voter's current nonce (see the `SignedVoteNonce` query) so that a
+/// signature can not be replayed.
+#[cw_serde]
+pub struct SignedVotePayload {
+    pub chain_id: String,
+    pub contract: String,
+    pub nonce: u64,
+    pub proposal_id: u64,
+    pub rationale: Option<String>,
+    pub vote: Vote,
+}
+
+impl SignedVote {
+    /// Verifies the signature and returns the voter's address and
+    /// the signed payload. Checking the nonce is left to the caller.
+    pub fn verify(
+        &self,
+        deps: Deps,
+        env: &Env,
+    ) -> Result<(Addr, SignedVotePayload), ContractError> {
+        let voter = deps.api.addr_validate(&self.voter)?;
+        if pubkey_address_bytes(&self.pubkey) != address_bytes(voter.as_str())? {
+            return Err(ContractError::SignerMismatch {});
+        }
+
+        let hash = Sha256::digest(sign_doc(&self.data, voter.as_str()));
+        if !deps
+            .api
+            .secp256k1_verify(&hash, &self.signature, &self.pubkey)?
+        {
+            return Err(ContractError::InvalidSignature {});
+        }
+
+        let payload: SignedVotePayload = from_json(&self.data)?;
+        if payload.chain_id != env.block.chain_id || payload.contract != env.contract.address {
+            return Err(ContractError::WrongSigningDomain {});
+        }
+
+        Ok((voter, payload))
+    }
+}
+
+/// The ADR-036 sign doc for `data` signed by `signer`. Keys are
+/// sorted and there is no whitespace, as in the amino JSON signing
+/// format.
+fn sign_doc(data: &Binary, signer: &str) -> String {
+    format!(
+        r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+        data.to_base64(),
+        signer
+    )
+}
+
+/// The address bytes of a secp256k1 public key, as used by Cosmos
+/// SDK accounts.
+fn pubkey_address_bytes(pubkey: &[u8]) -> Vec<u8> {
+    Ripemd160::digest(Sha256::digest(pubkey)).to_vec()
+}
+
+/// The bytes encoded in a bech32 address.
+fn address_bytes(address: &str) -> Result<Vec<u8>, ContractError> {
+    let (_, data, _) = bech32::decode(address).map_err(|_| ContractError::SignerMismatch {})?;
+    Vec::<u8>::from_base32(&data).map_err(|_| ContractError::SignerMismatch {})
+}
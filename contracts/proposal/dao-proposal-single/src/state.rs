@@ -1,10 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
 use dao_voting::{
-    pre_propose::ProposalCreationPolicy, threshold::Threshold, veto::VetoConfig, voting::Vote,
+    executor::ExecutorPolicy, keeper::KeeperBounty, pre_propose::ProposalCreationPolicy,
+    quadratic::QuadraticVoting, threshold::Threshold, veto::VetoConfig, voting::Vote,
 };
 
 use crate::proposal::SingleChoiceProposal;
@@ -12,7 +13,9 @@ use crate::proposal::SingleChoiceProposal;
 /// A vote cast for a proposal.
 #[cw_serde]
 pub struct Ballot {
-    /// The amount of voting power behind the vote.
+    /// The amount of voting power behind the vote. For proposals
+    /// using quadratic voting this is the square root of the voter's
+    /// voting power.
     pub power: Uint128,
     /// The position.
     pub vote: Vote,
@@ -38,10 +41,8 @@ pub struct Config {
     /// preventing governance attacks wherein an attacker aquires a
     /// large number of tokens and forces a proposal through.
     pub min_voting_period: Option<Duration>,
-    /// If set to true only members may execute passed
-    /// proposals. Otherwise, any address may execute a passed
-    /// proposal.
-    pub only_members_execute: bool,
+    /// Who may execute passed proposals.
+    pub executor_policy: ExecutorPolicy,
     /// Allows changing votes before the proposal expires. If this is
     /// enabled proposals will not be able to complete early as final
     /// vote information is not known until the time of proposal
@@ -61,11 +62,54 @@ pub struct Config {
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
+    /// Optional quadratic voting configuration. If set, votes are
+    /// counted with the square root of the voter's voting power.
+    pub quadratic_voting: Option<QuadraticVoting>,
+    /// If set, proposals use commit-reveal voting. Votes are
+    /// committed as hashes for `max_voting_period` and revealed
+    /// during the `reveal_period` that follows. Only revealed votes
+    /// are counted.
+    pub reveal_period: Option<Duration>,
+}
+
+/// The module's configuration as saved before executor policies were
+/// introduced. Used to migrate the stored config.
+#[cw_serde]
+pub struct LegacyConfig {
+    pub threshold: Threshold,
+    pub max_voting_period: Duration,
+    pub min_voting_period: Option<Duration>,
+    pub only_members_execute: bool,
+    pub allow_revoting: bool,
+    pub dao: Addr,
+    pub close_proposal_on_execution_failure: bool,
+    pub veto: Option<VetoConfig>,
+    pub quadratic_voting: Option<QuadraticVoting>,
+}
+
+impl From<LegacyConfig> for Config {
+    fn from(legacy: LegacyConfig) -> Self {
+        Config {
+            threshold: legacy.threshold,
+            max_voting_period: legacy.max_voting_period,
+            min_voting_period: legacy.min_voting_period,
+            executor_policy: legacy.only_members_execute.into(),
+            allow_revoting: legacy.allow_revoting,
+            dao: legacy.dao,
+            close_proposal_on_execution_failure: legacy.close_proposal_on_execution_failure,
+            veto: legacy.veto,
+            quadratic_voting: legacy.quadratic_voting,
+            reveal_period: None,
+        }
+    }
 }
 
 /// The current top level config for the module.  The "config" key was
 /// previously used to store configs for v1 DAOs.
 pub const CONFIG: Item<Config> = Item::new("config_v2");
+/// The config as saved before executor policies were introduced. Shares
+/// its key with `CONFIG`.
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config_v2");
 /// The number of proposals that have been created.
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
@@ -74,6 +118,21 @@ pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
 pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// The nonce each voter's next signed vote must have.
+pub const SIGNED_VOTE_NONCES: Map<&Addr, u64> = Map::new("signed_vote_nonces");
+/// The credits each voter has spent on proposals using credit
+/// quadratic voting. Entries are removed once the proposal they were
+/// spent on expires.
+pub const CREDITS_SPENT: Map<(&Addr, u64), Uint128> = Map::new("credits_spent");
+/// Vote commitments on commit-reveal proposals that have not yet
+/// been revealed.
+pub const VOTE_COMMITMENTS: Map<(u64, &Addr), Binary> = Map::new("vote_commitments");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The bounty paid to whoever executes or closes a proposal (if
+/// any).
+pub const KEEPER_BOUNTY: Item<KeeperBounty> = Item::new("keeper_bounty");
+/// The total amount of each native denom held in escrow for open
+/// proposals. Escrowed funds may not be used to pay keeper bounties.
+pub const ESCROWED: Map<&str, Uint128> = Map::new("escrowed");
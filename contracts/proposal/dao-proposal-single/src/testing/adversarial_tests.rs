@@ -17,6 +17,7 @@ use cw_multi_test::{next_block, App};
 use cw_utils::Duration;
 use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     status::Status,
     threshold::{PercentageThreshold, Threshold::AbsolutePercentage},
     voting::Vote,
@@ -166,7 +167,7 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(
             &mut app,
@@ -176,10 +177,15 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
                 },
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -265,7 +271,7 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(
             &mut app,
@@ -275,10 +281,15 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
                 },
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -0,0 +1,346 @@
+use cosmwasm_std::{Addr, Binary, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, Executor};
+use cw_utils::Duration;
+use dao_voting::{status::Status, voting::Vote};
+
+use crate::{
+    commit_reveal::vote_commitment,
+    msg::{ExecuteMsg, QueryMsg},
+    testing::{
+        execute::{make_proposal, mint_cw20s},
+        instantiate::{
+            get_default_token_dao_proposal_module_instantiate,
+            instantiate_with_staked_balances_governance,
+        },
+        queries::{
+            query_dao_token, query_proposal, query_proposal_config, query_single_proposal_module,
+        },
+    },
+    ContractError,
+};
+
+use super::CREATOR_ADDR;
+
+const VOTER: &str = "voter";
+const VOTING_PERIOD: u64 = 604800;
+const REVEAL_PERIOD: u64 = 86400;
+
+struct CommonTest {
+    app: App,
+    core_addr: Addr,
+    proposal_module: Addr,
+    proposal_id: u64,
+}
+
+fn setup_test(reveal_period: Option<Duration>) -> CommonTest {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.reveal_period = reveal_period;
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: VOTER.to_string(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    CommonTest {
+        app,
+        core_addr,
+        proposal_module,
+        proposal_id,
+    }
+}
+
+fn commitment(proposal_id: u64, voter: &str, vote: Vote) -> Binary {
+    vote_commitment(proposal_id, &Addr::unchecked(voter), vote, "salt")
+}
+
+fn commit_vote(
+    app: &mut App,
+    proposal_module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    commitment: Binary,
+) -> Result<(), ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_module.clone(),
+        &ExecuteMsg::CommitVote {
+            proposal_id,
+            commitment,
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn reveal_vote(
+    app: &mut App,
+    proposal_module: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    vote: Vote,
+) -> Result<(), ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_module.clone(),
+        &ExecuteMsg::RevealVote {
+            proposal_id,
+            vote,
+            salt: "salt".to_string(),
+            rationale: None,
+        },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
+
+fn query_commitment(
+    app: &App,
+    proposal_module: &Addr,
+    voter: &str,
+    proposal_id: u64,
+) -> Option<Binary> {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_module,
+            &QueryMsg::VoteCommitment {
+                proposal_id,
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_commit_reveal_voting() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        proposal_id,
+    } = setup_test(Some(Duration::Time(REVEAL_PERIOD)));
+
+    // Votes may not be cast directly.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: Vote::Yes,
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CommitRevealProposal { id: proposal_id });
+
+    let err = commit_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Binary::from(b"yes".to_vec()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidCommitment {});
+
+    let err = commit_vote(
+        &mut app,
+        &proposal_module,
+        "stranger",
+        proposal_id,
+        commitment(proposal_id, "stranger", Vote::Yes),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotRegistered {});
+
+    let creator_commitment = commitment(proposal_id, CREATOR_ADDR, Vote::Yes);
+    commit_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        creator_commitment.clone(),
+    )
+    .unwrap();
+    commit_vote(
+        &mut app,
+        &proposal_module,
+        VOTER,
+        proposal_id,
+        commitment(proposal_id, VOTER, Vote::No),
+    )
+    .unwrap();
+    assert_eq!(
+        query_commitment(&app, &proposal_module, CREATOR_ADDR, proposal_id),
+        Some(creator_commitment)
+    );
+
+    // Committed votes are not tallied.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.votes.total(), Uint128::zero());
+    assert_eq!(proposal.status, Status::Open);
+
+    let err = reveal_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CommitPhaseOpen { id: proposal_id });
+
+    app.update_block(|b| b.time = b.time.plus_seconds(VOTING_PERIOD));
+
+    let err = commit_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        commitment(proposal_id, CREATOR_ADDR, Vote::No),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CommitPhaseEnded { id: proposal_id });
+
+    let err = reveal_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::No,
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::CommitmentMismatch {});
+
+    reveal_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    )
+    .unwrap();
+    assert_eq!(
+        query_commitment(&app, &proposal_module, CREATOR_ADDR, proposal_id),
+        None
+    );
+
+    // The outcome is not known until the reveal period ends.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.votes.yes, Uint128::new(60));
+    assert_eq!(proposal.status, Status::Open);
+
+    let err = reveal_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoSuchCommitment {
+            id: proposal_id,
+            voter: CREATOR_ADDR.to_string()
+        }
+    );
+
+    app.update_block(|b| b.time = b.time.plus_seconds(REVEAL_PERIOD));
+
+    // Unrevealed votes are not counted.
+    let err = reveal_vote(&mut app, &proposal_module, VOTER, proposal_id, Vote::No).unwrap_err();
+    assert_eq!(err, ContractError::Expired { id: proposal_id });
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.votes.no, Uint128::zero());
+    assert_eq!(proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_commit_reveal_config() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        proposal_id,
+    } = setup_test(None);
+
+    let err = commit_vote(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        commitment(proposal_id, CREATOR_ADDR, Vote::Yes),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotCommitReveal { id: proposal_id });
+
+    // The reveal period must be measured in the same units as the
+    // voting period.
+    let config = query_proposal_config(&app, &proposal_module);
+    let update_config = |reveal_period| ExecuteMsg::UpdateConfig {
+        threshold: config.threshold.clone(),
+        max_voting_period: config.max_voting_period,
+        min_voting_period: config.min_voting_period,
+        executor_policy: config.executor_policy.clone(),
+        allow_revoting: config.allow_revoting,
+        dao: config.dao.to_string(),
+        close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+        veto: config.veto.clone(),
+        quadratic_voting: config.quadratic_voting.clone(),
+        reveal_period,
+    };
+
+    for reveal_period in [Duration::Height(100), Duration::Time(0)] {
+        let err: ContractError = app
+            .execute_contract(
+                core_addr.clone(),
+                proposal_module.clone(),
+                &update_config(Some(reveal_period)),
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap();
+        assert_eq!(err, ContractError::InvalidRevealPeriod {});
+    }
+
+    app.execute_contract(
+        core_addr,
+        proposal_module.clone(),
+        &update_config(Some(Duration::Time(REVEAL_PERIOD))),
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_proposal_config(&app, &proposal_module).reveal_period,
+        Some(Duration::Time(REVEAL_PERIOD))
+    );
+
+    // Only proposals created after the update use commit-reveal
+    // voting.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.commit_expiration, None);
+}
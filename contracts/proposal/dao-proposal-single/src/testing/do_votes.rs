@@ -11,6 +11,7 @@ use cw_denom::CheckedDenom;
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    executor::ExecutorPolicy,
     status::Status,
     threshold::Threshold,
 };
@@ -138,9 +139,11 @@ where
         threshold,
         max_voting_period,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
         pre_propose_info,
     };
 
@@ -208,6 +211,7 @@ where
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
                 vote: None,
+                escrow: vec![],
             },
         },
         &funds,
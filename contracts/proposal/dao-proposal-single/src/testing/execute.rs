@@ -5,6 +5,7 @@ use cw_denom::CheckedDenom;
 use dao_pre_propose_single as cppbps;
 use dao_voting::{
     deposit::CheckedDepositInfo,
+    keeper::UncheckedKeeperBounty,
     pre_propose::ProposalCreationPolicy,
     proposal::SingleChoiceProposeMsg as ProposeMsg,
     voting::{SingleChoiceAutoVote, Vote},
@@ -43,11 +44,7 @@ pub(crate) fn make_proposal(
         } => {
             let deposit_config = query_pre_proposal_single_config(app, pre_propose);
             match deposit_config.deposit_info {
-                Some(CheckedDepositInfo {
-                    denom,
-                    amount,
-                    refund_policy: _,
-                }) => match denom {
+                Some(CheckedDepositInfo { denom, amount, .. }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
                         // Give an allowance, no funds.
@@ -96,6 +93,7 @@ pub(crate) fn make_proposal(
                         description: "description".to_string(),
                         msgs: msgs.clone(),
                         vote,
+                        escrow: vec![],
                     },
                 },
                 &funds,
@@ -119,6 +117,45 @@ pub(crate) fn make_proposal(
     id
 }
 
+// Creates a proposal through the pre-propose module, attaching `funds`
+// to be escrowed with it. Expects that no deposit is required.
+pub(crate) fn make_proposal_with_escrow(
+    app: &mut App,
+    proposal_single: &Addr,
+    proposer: &str,
+    msgs: Vec<CosmosMsg>,
+    funds: Vec<Coin>,
+) -> u64 {
+    let pre_propose = match query_creation_policy(app, proposal_single) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+    };
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose,
+        &cppbps::ExecuteMsg::Propose {
+            msg: cppbps::ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs,
+                vote: None,
+                escrow: funds.clone(),
+            },
+        },
+        &funds,
+    )
+    .unwrap();
+    let id = query_next_proposal_id(app, proposal_single) - 1;
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::Proposal { proposal_id: id })
+        .unwrap();
+    assert_eq!(proposal.proposal.escrow, funds);
+
+    id
+}
+
 pub(crate) fn vote_on_proposal(
     app: &mut App,
     proposal_single: &Addr,
@@ -454,3 +491,19 @@ pub(crate) fn remove_vote_hook_should_fail(
     .downcast()
     .unwrap()
 }
+
+pub(crate) fn update_keeper_bounty(
+    app: &mut App,
+    proposal_module: &Addr,
+    sender: &str,
+    bounty: Option<UncheckedKeeperBounty>,
+) -> Result<(), ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_module.clone(),
+        &ExecuteMsg::UpdateKeeperBounty { bounty },
+        &[],
+    )
+    .map(|_| ())
+    .map_err(|e| e.downcast().unwrap())
+}
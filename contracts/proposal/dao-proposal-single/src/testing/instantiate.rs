@@ -8,6 +8,7 @@ use dao_pre_propose_single as cppbps;
 
 use dao_voting::{
     deposit::{DepositRefundPolicy, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::ExecutorPolicy,
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold::ThresholdQuorum},
 };
@@ -37,6 +38,10 @@ pub(crate) fn get_pre_propose_info(
             msg: to_json_binary(&cppbps::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -56,7 +61,7 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(
             app,
@@ -66,10 +71,15 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
                 },
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
     }
 }
 
@@ -85,10 +95,12 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         },
         max_voting_period: Duration::Time(604800), // One week.
         min_voting_period: None,
-        only_members_execute: true,
+        executor_policy: ExecutorPolicy::Members {},
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
     }
 }
 
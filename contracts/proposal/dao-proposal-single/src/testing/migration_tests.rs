@@ -311,6 +311,9 @@ fn test_v1_v2_full_migration() {
             },
             amount: Uint128::new(1),
             refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
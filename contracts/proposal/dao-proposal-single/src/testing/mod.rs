@@ -1,10 +1,12 @@
 mod adversarial_tests;
+mod commit_reveal;
 mod contracts;
 mod do_votes;
 mod execute;
 mod instantiate;
 mod migration_tests;
 mod queries;
+mod signed_votes;
 mod tests;
 
 pub(crate) const CREATOR_ADDR: &str = "creator";
@@ -4,7 +4,7 @@ use dao_interface::state::{ProposalModule, ProposalModuleStatus};
 
 use cw_hooks::HooksResponse;
 use dao_pre_propose_single as cppbps;
-use dao_voting::pre_propose::ProposalCreationPolicy;
+use dao_voting::{keeper::KeeperBounty, pre_propose::ProposalCreationPolicy};
 
 use crate::{
     msg::QueryMsg,
@@ -101,6 +101,12 @@ pub(crate) fn query_vote_hooks(app: &App, proposal_single: &Addr) -> HooksRespon
         .unwrap()
 }
 
+pub(crate) fn query_keeper_bounty(app: &App, proposal_single: &Addr) -> Option<KeeperBounty> {
+    app.wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::KeeperBounty {})
+        .unwrap()
+}
+
 pub(crate) fn query_list_proposals_reverse(
     app: &App,
     proposal_single: &Addr,
@@ -0,0 +1,300 @@
+use bech32::{ToBase32, Variant};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, Executor};
+use dao_voting::voting::Vote;
+use k256::ecdsa::{signature::Signer as _, Signature, SigningKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    msg::{ExecuteMsg, QueryMsg},
+    signed_vote::{SignedVote, SignedVotePayload},
+    testing::{
+        execute::{make_proposal, mint_cw20s},
+        instantiate::{
+            get_default_token_dao_proposal_module_instantiate,
+            instantiate_with_staked_balances_governance,
+        },
+        queries::{query_dao_token, query_single_proposal_module, query_vote},
+    },
+    ContractError,
+};
+
+use super::CREATOR_ADDR;
+
+/// A voter who signs votes off-chain.
+struct Signer {
+    key: SigningKey,
+    address: String,
+}
+
+impl Signer {
+    fn new(seed: u8) -> Self {
+        let key = SigningKey::from_slice(&[seed; 32]).unwrap();
+        let address = bech32::encode(
+            "juno",
+            Ripemd160::digest(Sha256::digest(Self::pubkey_of(&key))).to_base32(),
+            Variant::Bech32,
+        )
+        .unwrap();
+        Self { key, address }
+    }
+
+    fn pubkey_of(key: &SigningKey) -> Vec<u8> {
+        key.verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .to_vec()
+    }
+
+    fn sign(&self, payload: &SignedVotePayload) -> SignedVote {
+        let data = to_json_binary(payload).unwrap();
+        let sign_doc = format!(
+            r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+            data.to_base64(),
+            self.address
+        );
+        let signature: Signature = self.key.sign(sign_doc.as_bytes());
+        SignedVote {
+            data,
+            voter: self.address.clone(),
+            pubkey: Binary::from(Self::pubkey_of(&self.key)),
+            signature: Binary::from(signature.to_bytes().to_vec()),
+        }
+    }
+}
+
+struct CommonTest {
+    app: App,
+    proposal_module: Addr,
+    proposal_id: u64,
+    voter: Signer,
+}
+
+fn setup_test() -> CommonTest {
+    let voter = Signer::new(1);
+
+    let mut app = App::default();
+    let instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(60),
+            },
+            Cw20Coin {
+                address: voter.address.clone(),
+                amount: Uint128::new(40),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    CommonTest {
+        app,
+        proposal_module,
+        proposal_id,
+        voter,
+    }
+}
+
+fn payload(app: &App, proposal_module: &Addr, proposal_id: u64, nonce: u64) -> SignedVotePayload {
+    SignedVotePayload {
+        chain_id: app.block_info().chain_id,
+        contract: proposal_module.to_string(),
+        nonce,
+        proposal_id,
+        rationale: Some("signed".to_string()),
+        vote: Vote::Yes,
+    }
+}
+
+/// Submits signed votes and returns the outcome of each.
+fn submit_signed_votes(
+    app: &mut App,
+    proposal_module: &Addr,
+    votes: Vec<SignedVote>,
+) -> Vec<String> {
+    app.execute_contract(
+        Addr::unchecked("relayer"),
+        proposal_module.clone(),
+        &ExecuteMsg::SubmitSignedVotes { votes },
+        &[],
+    )
+    .unwrap()
+    .events
+    .into_iter()
+    .filter(|event| event.ty == "wasm")
+    .flat_map(|event| event.attributes)
+    .filter(|attribute| attribute.key == "outcome")
+    .map(|attribute| attribute.value)
+    .collect()
+}
+
+fn skipped(err: ContractError) -> String {
+    format!("skipped: {err}")
+}
+
+fn query_nonce(app: &App, proposal_module: &Addr, voter: &str) -> u64 {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_module,
+            &QueryMsg::SignedVoteNonce {
+                voter: voter.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_submit_signed_votes() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        proposal_id,
+        voter,
+    } = setup_test();
+
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 0);
+
+    let signed = voter.sign(&payload(&app, &proposal_module, proposal_id, 0));
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed.clone()]);
+    assert_eq!(outcomes, vec!["counted"]);
+
+    let ballot = query_vote(&app, &proposal_module, &voter.address, proposal_id)
+        .vote
+        .unwrap();
+    assert_eq!(ballot.voter, Addr::unchecked(&voter.address));
+    assert_eq!(ballot.vote, Vote::Yes);
+    assert_eq!(ballot.power, Uint128::new(40));
+    assert_eq!(ballot.rationale, Some("signed".to_string()));
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 1);
+
+    // The same signed vote can not be submitted twice.
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed]);
+    assert_eq!(
+        outcomes,
+        vec![skipped(ContractError::WrongNonce {
+            expected: 1,
+            actual: 0
+        })]
+    );
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 1);
+}
+
+#[test]
+fn test_signed_vote_wrong_domain() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        proposal_id,
+        voter,
+    } = setup_test();
+
+    let signed = voter.sign(&SignedVotePayload {
+        chain_id: "another-chain".to_string(),
+        ..payload(&app, &proposal_module, proposal_id, 0)
+    });
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed]);
+    assert_eq!(
+        outcomes,
+        vec![skipped(ContractError::WrongSigningDomain {})]
+    );
+
+    let signed = voter.sign(&SignedVotePayload {
+        contract: "another-module".to_string(),
+        ..payload(&app, &proposal_module, proposal_id, 0)
+    });
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed]);
+    assert_eq!(
+        outcomes,
+        vec![skipped(ContractError::WrongSigningDomain {})]
+    );
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 0);
+}
+
+#[test]
+fn test_signed_vote_bad_signature() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        proposal_id,
+        voter,
+    } = setup_test();
+
+    // Votes signed by one key may not be attributed to another
+    // address.
+    let mut signed = Signer::new(2).sign(&payload(&app, &proposal_module, proposal_id, 0));
+    signed.voter = voter.address.clone();
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed]);
+    assert_eq!(outcomes, vec![skipped(ContractError::SignerMismatch {})]);
+
+    // The signed data may not be changed.
+    let mut signed = voter.sign(&payload(&app, &proposal_module, proposal_id, 0));
+    signed.data = to_json_binary(&SignedVotePayload {
+        vote: Vote::No,
+        ..payload(&app, &proposal_module, proposal_id, 0)
+    })
+    .unwrap();
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![signed]);
+    assert_eq!(outcomes, vec![skipped(ContractError::InvalidSignature {})]);
+
+    // Unverified votes do not consume the voter's nonce.
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 0);
+    assert!(
+        query_vote(&app, &proposal_module, &voter.address, proposal_id)
+            .vote
+            .is_none()
+    );
+}
+
+#[test]
+fn test_signed_vote_failure_skipped() {
+    let CommonTest {
+        mut app,
+        proposal_module,
+        proposal_id,
+        voter,
+    } = setup_test();
+
+    // A vote that can not be cast is skipped, consuming its nonce,
+    // and the rest of the batch is still counted.
+    let invalid = voter.sign(&payload(&app, &proposal_module, proposal_id + 1, 0));
+    let replayed = invalid.clone();
+    let valid = voter.sign(&payload(&app, &proposal_module, proposal_id, 1));
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![invalid, valid]);
+    assert_eq!(
+        outcomes,
+        vec![
+            skipped(ContractError::NoSuchProposal {
+                id: proposal_id + 1
+            }),
+            "counted".to_string()
+        ]
+    );
+    assert_eq!(
+        query_vote(&app, &proposal_module, &voter.address, proposal_id)
+            .vote
+            .unwrap()
+            .vote,
+        Vote::Yes
+    );
+    assert_eq!(query_nonce(&app, &proposal_module, &voter.address), 2);
+
+    // The skipped vote can not be replayed once its proposal exists.
+    let outcomes = submit_signed_votes(&mut app, &proposal_module, vec![replayed]);
+    assert_eq!(
+        outcomes,
+        vec![skipped(ContractError::WrongNonce {
+            expected: 2,
+            actual: 0
+        })]
+    );
+}
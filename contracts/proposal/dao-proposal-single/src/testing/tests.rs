@@ -19,8 +19,11 @@ use dao_interface::{
 use dao_testing::{ShouldExecute, TestSingleChoiceVote};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo, VotingModuleTokenType},
+    executor::{ExecutorPolicy, ExecutorPolicyError},
+    keeper::{KeeperBounty, KeeperBountyError, UncheckedKeeperBounty},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
+    quadratic::{IdentityGate, QuadraticMode, QuadraticVoting, QuadraticVotingError},
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
@@ -36,16 +39,17 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     proposal::SingleChoiceProposal,
     query::{ProposalResponse, VoteInfo},
-    state::Config,
+    state::{Config, LegacyConfig, CONFIG, LEGACY_CONFIG},
     testing::{
-        contracts::{pre_propose_single_contract, proposal_single_contract},
+        contracts::{cw4_group_contract, pre_propose_single_contract, proposal_single_contract},
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
             add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
             execute_proposal, execute_proposal_should_fail, instantiate_cw20_base_default,
-            make_proposal, mint_cw20s, mint_natives, remove_proposal_hook,
-            remove_proposal_hook_should_fail, remove_vote_hook, remove_vote_hook_should_fail,
-            update_rationale, vote_on_proposal, vote_on_proposal_should_fail,
+            make_proposal, make_proposal_with_escrow, mint_cw20s, mint_natives,
+            remove_proposal_hook, remove_proposal_hook_should_fail, remove_vote_hook,
+            remove_vote_hook_should_fail, update_keeper_bounty, update_rationale, vote_on_proposal,
+            vote_on_proposal_should_fail,
         },
         instantiate::{
             get_default_non_token_dao_proposal_module_instantiate,
@@ -68,7 +72,7 @@ use crate::{
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{query_keeper_bounty, query_next_proposal_id, query_vote},
     CREATOR_ADDR,
 };
 
@@ -130,6 +134,10 @@ fn test_simple_propose_staked_balances() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
         votes: Votes::zero(),
     };
 
@@ -146,7 +154,10 @@ fn test_simple_propose_staked_balances() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(gov_token),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         })
     );
 }
@@ -180,6 +191,10 @@ fn test_simple_proposal_cw4_voting() {
         msgs: vec![],
         status: Status::Open,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
         votes: Votes::zero(),
     };
 
@@ -232,6 +247,10 @@ fn test_simple_proposal_auto_vote_yes() {
         msgs: vec![],
         status: Status::Passed,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
         votes: Votes {
             yes: Uint128::new(1),
             no: Uint128::zero(),
@@ -281,6 +300,10 @@ fn test_simple_proposal_auto_vote_no() {
         msgs: vec![],
         status: Status::Rejected,
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
         votes: Votes {
             yes: Uint128::zero(),
             no: Uint128::new(1),
@@ -354,6 +377,9 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
             },
             amount: Uint128::new(10_000_000),
             refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         }),
         false,
     );
@@ -384,6 +410,10 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         status: Status::Open,
         votes: Votes::zero(),
         veto: None,
+        quadratic_voting: None,
+        turnout: Uint128::zero(),
+        commit_expiration: None,
+        escrow: vec![],
     };
 
     assert_eq!(created.proposal, expected);
@@ -399,7 +429,10 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         })
     );
 }
@@ -1757,6 +1790,61 @@ fn test_proposal_message_timelock_veto_before_passed() {
     // assert_eq!(proposal.proposal.status, Status::Executed);
 }
 
+#[test]
+fn test_executor_allowlist() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.executor_policy = ExecutorPolicy::Allowlist {
+        addresses: vec!["bot".to_string()],
+    };
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Members not on the allowlist may not execute.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // An allowlisted address may, even without voting power.
+    execute_proposal(&mut app, &proposal_module, "bot", proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+}
+
+#[test]
+fn test_executor_policy_empty_allowlist() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.executor_policy = ExecutorPolicy::Allowlist { addresses: vec![] };
+    let proposal_single_id = app.store_code(proposal_single_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            proposal_single_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &instantiate,
+            &[],
+            "proposal-single",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::ExecutorPolicyError(ExecutorPolicyError::EmptyAllowlist {})
+    );
+}
+
 #[test]
 fn test_veto_only_members_execute_proposal() -> anyhow::Result<()> {
     let mut app = App::default();
@@ -1884,6 +1972,258 @@ fn test_proposal_close_after_expiry() {
     assert_eq!(proposal.proposal.status, Status::Closed);
 }
 
+#[test]
+fn test_keeper_bounty() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let bounty = UncheckedKeeperBounty {
+        denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(10),
+    };
+
+    // Only the DAO may set the bounty.
+    let err = update_keeper_bounty(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        Some(bounty.clone()),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let err = update_keeper_bounty(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        Some(UncheckedKeeperBounty {
+            amount: Uint128::zero(),
+            ..bounty.clone()
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::KeeperBountyError(KeeperBountyError::ZeroAmount {})
+    );
+
+    update_keeper_bounty(&mut app, &proposal_module, core_addr.as_str(), Some(bounty)).unwrap();
+    assert_eq!(
+        query_keeper_bounty(&app, &proposal_module),
+        Some(KeeperBounty {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+        })
+    );
+
+    // Fund the pool with enough for one and a half bounties.
+    mint_natives(&mut app, proposal_module.as_str(), coins(15, "ujuno"));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::new(10)
+    );
+
+    // Anyone may close a rejected proposal and collect the bounty,
+    // but the pool only has 5 tokens left so nothing is paid.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    close_proposal(&mut app, &proposal_module, "keeper", proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "keeper", "ujuno"),
+        Uint128::zero()
+    );
+
+    mint_natives(&mut app, proposal_module.as_str(), coins(5, "ujuno"));
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    close_proposal(&mut app, &proposal_module, "keeper", proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "keeper", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_balance_native(&app, proposal_module.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+
+    // Removing the bounty stops payouts.
+    update_keeper_bounty(&mut app, &proposal_module, core_addr.as_str(), None).unwrap();
+    assert_eq!(query_keeper_bounty(&app, &proposal_module), None);
+}
+
+#[test]
+fn test_keeper_share_of_forfeited_deposit() {
+    let mut app = App::default();
+    let mut instantiate = get_default_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.executor_policy = ExecutorPolicy::Anyone {};
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::VotingModuleToken {
+                token_type: VotingModuleTokenType::Cw20,
+            },
+            amount: Uint128::new(10_000_000),
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: Some(Decimal::percent(25)),
+        }),
+        false,
+    );
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let gov_token = query_dao_token(&app, &core_addr);
+
+    // Passed proposals are refunded in full.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    let balance = query_balance_cw20(&app, &gov_token, CREATOR_ADDR);
+    execute_proposal(&mut app, &proposal_module, "keeper", proposal_id);
+    assert_eq!(
+        query_balance_cw20(&app, &gov_token, CREATOR_ADDR),
+        balance + Uint128::new(10_000_000)
+    );
+    assert_eq!(
+        query_balance_cw20(&app, &gov_token, "keeper"),
+        Uint128::zero()
+    );
+
+    // Whoever closes a rejected proposal receives their share of the
+    // forfeited deposit, and the DAO the rest.
+    mint_cw20s(&mut app, &gov_token, &core_addr, CREATOR_ADDR, 10_000_000);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+    app.update_block(|b| b.time = b.time.plus_seconds(604800));
+    let dao_balance = query_balance_cw20(&app, &gov_token, &core_addr);
+    close_proposal(&mut app, &proposal_module, "keeper", proposal_id);
+    assert_eq!(
+        query_balance_cw20(&app, &gov_token, "keeper"),
+        Uint128::new(2_500_000)
+    );
+    assert_eq!(
+        query_balance_cw20(&app, &gov_token, &core_addr),
+        dao_balance + Uint128::new(7_500_000)
+    );
+}
+
+#[test]
+fn test_proposal_escrow() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_natives(&mut app, CREATOR_ADDR, coins(30, "ujuno"));
+
+    // The DAO holds nothing, so this proposal may only be executed
+    // with the funds escrowed with it.
+    let send = |amount| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: "recipient".to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+    let executed = make_proposal_with_escrow(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![send(10)],
+        coins(10, "ujuno"),
+    );
+    let closed = make_proposal_with_escrow(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![],
+        coins(10, "ujuno"),
+    );
+    let failed = make_proposal_with_escrow(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![send(20)],
+        coins(10, "ujuno"),
+    );
+
+    // Escrowed funds may not be used to pay keeper bounties.
+    update_keeper_bounty(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        Some(UncheckedKeeperBounty {
+            denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+        }),
+    )
+    .unwrap();
+    mint_natives(&mut app, proposal_module.as_str(), coins(5, "ujuno"));
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        executed,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, executed);
+    assert_eq!(
+        query_balance_native(&app, "recipient", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::zero()
+    );
+
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, closed, Vote::No);
+    close_proposal(&mut app, &proposal_module, "keeper", closed);
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_balance_native(&app, "keeper", "ujuno"),
+        Uint128::zero()
+    );
+
+    // Escrow is refunded if execution fails.
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, failed, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, failed);
+    let proposal = query_proposal(&app, &proposal_module, failed);
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+    assert_eq!(
+        query_balance_native(&app, CREATOR_ADDR, "ujuno"),
+        Uint128::new(20)
+    );
+    assert_eq!(
+        query_balance_native(&app, proposal_module.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+}
+
 #[test]
 fn test_proposal_cant_close_after_expiry_is_passed() {
     let mut app = App::default();
@@ -2069,10 +2409,12 @@ fn test_update_config() {
                 },
                 max_voting_period: Duration::Height(6),
                 min_voting_period: None,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
             })
             .unwrap(),
             funds: vec![],
@@ -2104,10 +2446,12 @@ fn test_update_config() {
             },
             max_voting_period: Duration::Height(6),
             min_voting_period: None,
-            only_members_execute: true,
+            executor_policy: ExecutorPolicy::Members {},
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
         }
     );
 
@@ -2123,10 +2467,12 @@ fn test_update_config() {
                 },
                 max_voting_period: Duration::Height(6),
                 min_voting_period: None,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
             },
             &[],
         )
@@ -2152,10 +2498,12 @@ fn test_update_config() {
                 },
                 max_voting_period: Duration::Height(6),
                 min_voting_period: None,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
             },
             &[],
         )
@@ -2244,7 +2592,11 @@ fn test_anyone_may_propose_and_proposal_listing() {
                     no: Uint128::zero(),
                     abstain: Uint128::zero()
                 },
-                veto: None
+                veto: None,
+                quadratic_voting: None,
+                turnout: Uint128::zero(),
+                commit_expiration: None,
+                escrow: vec![],
             }
         }
     )
@@ -2744,11 +3096,13 @@ fn test_allow_revoting_config_changes() {
             },
             max_voting_period: Duration::Height(10),
             min_voting_period: None,
-            only_members_execute: true,
+            executor_policy: ExecutorPolicy::Members {},
             // Turn off revoting.
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
         },
         &[],
     )
@@ -3049,10 +3403,12 @@ fn test_proposal_count_initialized_to_zero() {
             },
             max_voting_period: Duration::Height(10),
             min_voting_period: None,
-            only_members_execute: true,
+            executor_policy: ExecutorPolicy::Members {},
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            quadratic_voting: None,
+            reveal_period: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3119,6 +3475,38 @@ pub fn test_migrate_updates_version() {
     assert_eq!(version.contract, CONTRACT_NAME);
 }
 
+#[test]
+pub fn test_migrate_legacy_executor_policy() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
+    LEGACY_CONFIG
+        .save(
+            &mut deps.storage,
+            &LegacyConfig {
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Height(10),
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: Addr::unchecked("dao"),
+                close_proposal_on_execution_failure: true,
+                veto: None,
+                quadratic_voting: None,
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    let config = CONFIG.load(&deps.storage).unwrap();
+    assert_eq!(config.executor_policy, ExecutorPolicy::Members {});
+
+    // Migrating again leaves the config as is.
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    assert_eq!(CONFIG.load(&deps.storage).unwrap(), config);
+}
+
 // //// TODO test migrate
 // /// Instantiates a DAO with a v1 proposal module and then migrates it
 // /// to v2.
@@ -3323,7 +3711,7 @@ pub fn test_migrate_updates_version() {
 //             },
 //             max_voting_period: Duration::Height(6),
 //             min_voting_period: None,
-//             only_members_execute: false,
+//             executor_policy: ExecutorPolicy::Anyone {},
 //             allow_revoting: false,
 //             dao: core_addr.clone(),
 //             close_proposal_on_execution_failure: true,
@@ -3457,11 +3845,13 @@ fn test_execution_failed() {
             threshold: config.threshold,
             max_voting_period: config.max_voting_period,
             min_voting_period: config.min_voting_period,
-            only_members_execute: config.only_members_execute,
+            executor_policy: config.executor_policy,
             allow_revoting: config.allow_revoting,
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            quadratic_voting: None,
+            reveal_period: None,
         },
         &[],
     )
@@ -3526,6 +3916,10 @@ fn test_reply_proposal_mock() {
                 msgs: vec![],
                 status: Status::Open,
                 veto: None,
+                quadratic_voting: None,
+                turnout: Uint128::zero(),
+                commit_expiration: None,
+                escrow: vec![],
                 votes: Votes::zero(),
             },
         )
@@ -3954,8 +4348,15 @@ fn test_update_pre_propose_module() {
                                 },
                                 amount: Uint128::new(1),
                                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                                alternatives: vec![],
+                                pricing: None,
+                                keeper_share: None,
                             }),
                             open_proposal_submission: false,
+                            rate_limit: None,
+                            proposal_threshold: None,
+                            submission_roles: None,
+                            message_templates: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -4005,8 +4406,15 @@ fn test_update_pre_propose_module() {
                 denom: CheckedDenom::Cw20(gov_token.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
             }),
             open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
         }
     );
 
@@ -4238,3 +4646,278 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+#[test]
+fn test_quadratic_voting() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::ThresholdQuorum {
+        threshold: PercentageThreshold::Majority {},
+        quorum: PercentageThreshold::Percent(Decimal::percent(50)),
+    };
+    instantiate.quadratic_voting = Some(QuadraticVoting {
+        identity_gate: None,
+        mode: QuadraticMode::PerBallot {},
+    });
+
+    let members: Vec<String> = (0..9).map(|i| format!("member{i}")).collect();
+    let mut weights = vec![Cw20Coin {
+        address: "whale".to_string(),
+        amount: Uint128::new(100),
+    }];
+    weights.extend(members.iter().map(|address| Cw20Coin {
+        address: address.clone(),
+        amount: Uint128::new(4),
+    }));
+
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, Some(weights));
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+
+    // The whale holds a majority of the voting power, but their vote
+    // only counts for the square root of it.
+    vote_on_proposal(&mut app, &proposal_module, "whale", proposal_id, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(10));
+    assert_eq!(proposal.proposal.turnout, Uint128::new(100));
+    // Quadratic proposals may not be rejected early.
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    for member in members.iter() {
+        vote_on_proposal(&mut app, &proposal_module, member, proposal_id, Vote::Yes);
+    }
+    let vote = query_vote(&app, &proposal_module, "member0", proposal_id);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(2));
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(18));
+    assert_eq!(proposal.proposal.turnout, Uint128::new(136));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_quadratic_voting_credits() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.allow_revoting = true;
+    instantiate.quadratic_voting = Some(QuadraticVoting {
+        identity_gate: None,
+        mode: QuadraticMode::Credits {},
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: "whale".to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let first = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+    let second = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+
+    let vote_with_credits = |app: &mut App, proposal_id: u64, vote: Vote, votes: u128| {
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteWithCredits {
+                proposal_id,
+                vote,
+                votes: Uint128::new(votes),
+                rationale: None,
+            },
+            &[],
+        )
+    };
+
+    // Six votes cost 36 of the whale's 100 credits.
+    vote_with_credits(&mut app, first, Vote::No, 6).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, first);
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(6));
+    assert_eq!(proposal.proposal.turnout, Uint128::new(100));
+
+    let err: ContractError = vote_with_credits(&mut app, second, Vote::Yes, 9)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::InsufficientCredits {
+            available: Uint128::new(64),
+            cost: Uint128::new(81),
+        })
+    );
+
+    // A plain vote casts as many votes as the remaining credits afford.
+    vote_on_proposal(&mut app, &proposal_module, "whale", second, Vote::Yes);
+    let vote = query_vote(&app, &proposal_module, "whale", second);
+    assert_eq!(vote.vote.unwrap().power, Uint128::new(8));
+
+    // Revoting replaces the previous spend, freeing credits.
+    vote_with_credits(&mut app, first, Vote::No, 2).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, first);
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(2));
+    assert_eq!(proposal.proposal.turnout, Uint128::new(100));
+
+    let err: ContractError = vote_with_credits(&mut app, first, Vote::No, 0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::ZeroVotes {})
+    );
+
+    // Credits are returned once the proposals they were spent on
+    // expire.
+    app.update_block(|block| block.time = block.time.plus_seconds(604800));
+    let third = make_proposal(&mut app, &proposal_module, "whale", vec![], None);
+    vote_with_credits(&mut app, third, Vote::Yes, 10).unwrap();
+    let proposal = query_proposal(&app, &proposal_module, third);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(10));
+}
+
+#[test]
+fn test_vote_with_credits_requires_credit_mode() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.quadratic_voting = Some(QuadraticVoting {
+        identity_gate: None,
+        mode: QuadraticMode::PerBallot {},
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![], None);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module,
+            &ExecuteMsg::VoteWithCredits {
+                proposal_id,
+                vote: Vote::Yes,
+                votes: Uint128::new(1),
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::NotCreditVoting {})
+    );
+}
+
+#[test]
+fn test_quadratic_voting_identity_gate() {
+    let mut app = App::default();
+
+    let cw4_id = app.store_code(cw4_group_contract());
+    let identity_group = app
+        .instantiate_contract(
+            cw4_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &cw4_group::msg::InstantiateMsg {
+                admin: None,
+                members: vec![cw4::Member {
+                    addr: "verified".to_string(),
+                    weight: 1,
+                }],
+            },
+            &[],
+            "identity group",
+            None,
+        )
+        .unwrap();
+
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.quadratic_voting = Some(QuadraticVoting {
+        identity_gate: Some(IdentityGate::Cw4Group {
+            address: identity_group.to_string(),
+        }),
+        mode: QuadraticMode::PerBallot {},
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "verified".to_string(),
+                amount: Uint128::new(9),
+            },
+            Cw20Coin {
+                address: "unverified".to_string(),
+                amount: Uint128::new(16),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "verified", vec![], None);
+
+    let err = vote_on_proposal_should_fail(
+        &mut app,
+        &proposal_module,
+        "unverified",
+        proposal_id,
+        Vote::Yes,
+    );
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::NotVerified {})
+    );
+
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        "verified",
+        proposal_id,
+        Vote::Yes,
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(3));
+}
+
+#[test]
+fn test_quadratic_voting_absolute_percentage_not_allowed() {
+    let mut app = App::default();
+    let instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                veto: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Time(604800),
+                min_voting_period: None,
+                executor_policy: ExecutorPolicy::Members {},
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: true,
+                quadratic_voting: Some(QuadraticVoting {
+                    identity_gate: None,
+                    mode: QuadraticMode::PerBallot {},
+                }),
+                reveal_period: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::QuadraticVotingError(QuadraticVotingError::UnsupportedThreshold {})
+    );
+}
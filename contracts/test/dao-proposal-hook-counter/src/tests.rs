@@ -6,6 +6,7 @@ use dao_interface::state::ProposalModule;
 use dao_interface::state::{Admin, ModuleInstantiateInfo};
 
 use dao_voting::{
+    executor::ExecutorPolicy,
     pre_propose::PreProposeInfo,
     threshold::{PercentageThreshold, Threshold},
     voting::Vote,
@@ -151,10 +152,12 @@ fn test_counters() {
         threshold,
         max_voting_period,
         min_voting_period: None,
-        only_members_execute: false,
+        executor_policy: ExecutorPolicy::Anyone {},
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        quadratic_voting: None,
+        reveal_period: None,
         veto: None,
     };
 
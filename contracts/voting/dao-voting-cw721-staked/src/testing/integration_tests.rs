@@ -13,6 +13,7 @@ use dao_interface::{
 };
 use dao_testing::test_tube::{cw721_base::Cw721Base, dao_dao_core::DaoCore};
 use dao_voting::{
+    executor::ExecutorPolicy,
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
 };
@@ -109,8 +110,10 @@ fn test_full_integration_with_factory() {
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -14,7 +14,8 @@ use dao_interface::{
     state::{Admin, ModuleInstantiateInfo, ProposalModule},
 };
 use dao_voting::{
-    pre_propose::PreProposeInfo, threshold::PercentageThreshold, threshold::Threshold,
+    executor::ExecutorPolicy, pre_propose::PreProposeInfo, threshold::PercentageThreshold,
+    threshold::Threshold,
 };
 
 use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg};
@@ -133,8 +134,10 @@ impl TestEnvBuilder {
                     },
                     max_voting_period: Duration::Time(432000),
                     allow_revoting: false,
-                    only_members_execute: true,
+                    executor_policy: ExecutorPolicy::Members {},
                     close_proposal_on_execution_failure: false,
+                    quadratic_voting: None,
+                    reveal_period: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
@@ -14,6 +14,7 @@ use dao_interface::{
 };
 use dao_testing::test_tube::{cw_tokenfactory_issuer::TokenfactoryIssuer, dao_dao_core::DaoCore};
 use dao_voting::{
+    executor::ExecutorPolicy,
     pre_propose::PreProposeInfo,
     threshold::{ActiveThreshold, ActiveThresholdError, PercentageThreshold, Threshold},
 };
@@ -393,8 +394,10 @@ fn test_factory() {
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -503,8 +506,10 @@ fn test_factory_funds_pass_through() {
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -629,8 +634,10 @@ fn test_factory_no_callback() {
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -710,8 +717,10 @@ fn test_factory_wrong_callback() {
                 },
                 max_voting_period: Duration::Time(432000),
                 allow_revoting: false,
-                only_members_execute: true,
+                executor_policy: ExecutorPolicy::Members {},
                 close_proposal_on_execution_failure: false,
+                quadratic_voting: None,
+                reveal_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
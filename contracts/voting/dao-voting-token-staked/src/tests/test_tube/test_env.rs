@@ -17,7 +17,8 @@ use dao_interface::{
     voting::{IsActiveResponse, VotingPowerAtHeightResponse},
 };
 use dao_voting::{
-    pre_propose::PreProposeInfo, threshold::PercentageThreshold, threshold::Threshold,
+    executor::ExecutorPolicy, pre_propose::PreProposeInfo, threshold::PercentageThreshold,
+    threshold::Threshold,
 };
 
 use dao_testing::test_tube::{
@@ -244,8 +245,10 @@ impl TestEnvBuilder {
                     },
                     max_voting_period: Duration::Time(432000),
                     allow_revoting: false,
-                    only_members_execute: true,
+                    executor_policy: ExecutorPolicy::Members {},
                     close_proposal_on_execution_failure: false,
+                    quadratic_voting: None,
+                    reveal_period: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_binary, Empty, StdResult, Storage, SubMsg, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Empty, StdResult, Storage, SubMsg, WasmMsg};
 use cw_hooks::Hooks;
 use dao_voting::{
     pre_propose::ProposalCreationPolicy,
@@ -97,10 +97,12 @@ pub fn proposal_status_changed_hooks(
 pub type PreProposeHookMsg = dao_pre_propose_base::msg::ExecuteMsg<Empty, Empty>;
 
 /// Adds prepropose / deposit module hook which will handle deposit refunds.
+/// `keeper` is the address that executed or closed the proposal, if any.
 pub fn proposal_completed_hooks(
     proposal_creation_policy: ProposalCreationPolicy,
     proposal_id: u64,
     new_status: Status,
+    keeper: Option<&Addr>,
 ) -> StdResult<Vec<SubMsg>> {
     let mut hooks: Vec<SubMsg> = vec![];
     match proposal_creation_policy {
@@ -109,6 +111,7 @@ pub fn proposal_completed_hooks(
             let msg = to_json_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
+                keeper: keeper.map(Addr::to_string),
             })?;
             hooks.push(SubMsg::reply_on_error(
                 WasmMsg::Execute {
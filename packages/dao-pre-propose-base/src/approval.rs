@@ -0,0 +1,413 @@
+//! State and logic shared by pre-propose modules that require
+//! proposals to be approved before they are created, such as
+//! `dao-pre-propose-approval-single` and
+//! `dao-pre-propose-approval-multiple`.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+use dao_voting::deposit::{CheckedDepositInfo, DepositRefundPolicy};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::PreProposeError;
+
+#[cw_serde]
+pub enum ProposalStatus {
+    /// The proposal is pending approval.
+    Pending {},
+    /// An approver has asked the proposer to change the proposal
+    /// before it may be approved.
+    ChangesRequested {
+        /// The approver's feedback.
+        feedback: String,
+    },
+    /// The proposal has been approved.
+    Approved {
+        /// The created proposal ID.
+        created_proposal_id: u64,
+    },
+    /// The proposal has been rejected.
+    Rejected {
+        /// The reason the proposal was rejected, if one was given.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// The proposal was not approved or rejected before it expired.
+    Expired {},
+}
+
+#[cw_serde]
+pub struct Proposal<ProposeMsg> {
+    /// The status of a completed proposal.
+    pub status: ProposalStatus,
+    /// The approval ID used to identify this pending proposal.
+    pub approval_id: u64,
+    /// The address that created the proposal.
+    pub proposer: Addr,
+    /// The propose message that ought to be executed on the proposal
+    /// message if this proposal is approved.
+    pub msg: ProposeMsg,
+    /// Snapshot of the deposit info at the time of proposal
+    /// submission.
+    pub deposit: Option<CheckedDepositInfo>,
+    /// When the proposal expires if it is still pending. If `None`,
+    /// the proposal does not expire.
+    #[serde(default)]
+    pub expiration: Option<Expiration>,
+}
+
+/// The approvals and rejections a pending proposal has received.
+#[cw_serde]
+#[derive(Default)]
+pub struct ApprovalVotes {
+    pub approvals: Vec<Addr>,
+    pub rejections: Vec<Addr>,
+}
+
+pub struct ApprovalContract<ProposeMsg> {
+    pub approver: Item<'static, Addr>,
+    /// Addresses other than the approver that may approve or reject
+    /// proposals.
+    pub approvers: Item<'static, Vec<Addr>>,
+    /// The number of approvals required to approve a proposal.
+    /// Defaults to one.
+    pub approval_threshold: Item<'static, u64>,
+    pub approval_votes: Map<'static, u64, ApprovalVotes>,
+    /// How long proposals may remain pending before they expire. If
+    /// not set, pending proposals do not expire.
+    pub pending_expiration: Item<'static, Duration>,
+    pub pending_proposals: Map<'static, u64, Proposal<ProposeMsg>>,
+    pub completed_proposals: Map<'static, u64, Proposal<ProposeMsg>>,
+    pub created_proposal_to_completed_proposal: Map<'static, u64, u64>,
+    /// Used internally to track the current approval_id.
+    current_id: Item<'static, u64>,
+}
+
+impl<ProposeMsg> Default for ApprovalContract<ProposeMsg> {
+    fn default() -> Self {
+        Self {
+            approver: Item::new("approver"),
+            approvers: Item::new("approvers"),
+            approval_threshold: Item::new("approval_threshold"),
+            approval_votes: Map::new("approval_votes"),
+            pending_expiration: Item::new("pending_expiration"),
+            pending_proposals: Map::new("pending_proposals"),
+            completed_proposals: Map::new("completed_proposals"),
+            created_proposal_to_completed_proposal: Map::new("created_to_completed_proposal"),
+            current_id: Item::new("current_id"),
+        }
+    }
+}
+
+impl<ProposeMsg> ApprovalContract<ProposeMsg>
+where
+    ProposeMsg: Serialize + DeserializeOwned + Clone,
+{
+    /// Returns every address that may approve or reject proposals,
+    /// starting with the approver, and the number of approvals
+    /// required.
+    pub fn load_approvers(&self, store: &dyn Storage) -> StdResult<(Vec<Addr>, u64)> {
+        let mut approvers = vec![self.approver.load(store)?];
+        approvers.extend(self.approvers.may_load(store)?.unwrap_or_default());
+        let threshold = self.approval_threshold.may_load(store)?.unwrap_or(1);
+        Ok((approvers, threshold))
+    }
+
+    pub fn advance_approval_id(&self, store: &mut dyn Storage) -> StdResult<u64> {
+        let id: u64 = self.current_id.may_load(store)?.unwrap_or_default() + 1;
+        self.current_id.save(store, &id)?;
+        Ok(id)
+    }
+
+    /// Returns when a proposal submitted or edited now will expire.
+    pub fn pending_expiration(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+    ) -> StdResult<Option<Expiration>> {
+        Ok(self
+            .pending_expiration
+            .may_load(storage)?
+            .map(|duration| duration.after(&env.block)))
+    }
+
+    /// Loads the pending proposal ID, erroring if it has expired.
+    pub fn load_pending(
+        &self,
+        storage: &dyn Storage,
+        env: &Env,
+        id: u64,
+    ) -> Result<Proposal<ProposeMsg>, PreProposeError> {
+        let proposal = self
+            .pending_proposals
+            .may_load(storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        if proposal
+            .expiration
+            .is_some_and(|expiration| expiration.is_expired(&env.block))
+        {
+            return Err(PreProposeError::PendingProposalExpired {});
+        }
+        Ok(proposal)
+    }
+
+    /// Records SENDER's approval or rejection of the pending proposal
+    /// ID, replacing any earlier vote of theirs. Returns true if the
+    /// proposal now has enough approvals to be approved, or enough
+    /// rejections that it can no longer be approved.
+    pub fn record_vote(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        sender: &Addr,
+        id: u64,
+        approve: bool,
+    ) -> Result<bool, PreProposeError> {
+        // Check sender is an approver
+        let (approvers, threshold) = self.load_approvers(storage)?;
+        if !approvers.contains(sender) {
+            return Err(PreProposeError::Unauthorized {});
+        }
+        let proposal = self.load_pending(storage, env, id)?;
+        if approve && proposal.status != (ProposalStatus::Pending {}) {
+            return Err(PreProposeError::AwaitingChanges {});
+        }
+
+        let mut votes = self
+            .approval_votes
+            .may_load(storage, id)?
+            .unwrap_or_default();
+        votes.approvals.retain(|a| a != sender);
+        votes.rejections.retain(|a| a != sender);
+        if approve {
+            votes.approvals.push(sender.clone());
+        } else {
+            votes.rejections.push(sender.clone());
+        }
+        self.approval_votes.save(storage, id, &votes)?;
+
+        // Votes from addresses that are no longer approvers do not
+        // count.
+        let count =
+            |voters: &[Addr]| voters.iter().filter(|a| approvers.contains(a)).count() as u64;
+        Ok(if approve {
+            count(&votes.approvals) >= threshold
+        } else {
+            count(&votes.rejections) > (approvers.len() as u64).saturating_sub(threshold)
+        })
+    }
+
+    /// Validates and saves the approvers other than APPROVER and the
+    /// number of approvals required to approve a proposal.
+    pub fn save_approvers(
+        &self,
+        deps: DepsMut,
+        approver: &Addr,
+        approvers: &[String],
+        threshold: u64,
+    ) -> Result<(), PreProposeError> {
+        let mut approvers = approvers
+            .iter()
+            .map(|a| deps.api.addr_validate(a))
+            .collect::<StdResult<Vec<_>>>()?;
+        approvers.retain(|a| a != approver);
+        approvers.sort();
+        approvers.dedup();
+        if threshold == 0 || threshold > approvers.len() as u64 + 1 {
+            return Err(PreProposeError::InvalidApprovalThreshold {});
+        }
+
+        self.approvers.save(deps.storage, &approvers)?;
+        self.approval_threshold.save(deps.storage, &threshold)?;
+        Ok(())
+    }
+
+    /// Rejects the pending proposal ID once enough approvers have
+    /// rejected it. Its deposit is refunded if deposits are always
+    /// refunded, and otherwise sent to DAO.
+    pub fn execute_reject(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        dao: &Addr,
+        id: u64,
+        reason: Option<String>,
+    ) -> Result<Response, PreProposeError> {
+        // Attribute values may not be empty, so the reason is only
+        // reported if one was given.
+        let reason_attribute = reason.clone().map(|reason| ("reason", reason));
+        if !self.record_vote(deps.storage, &env, &info.sender, id, false)? {
+            return Ok(Response::default()
+                .add_attribute("method", "rejection_recorded")
+                .add_attribute("approval_id", id.to_string())
+                .add_attribute("approver", info.sender)
+                .add_attributes(reason_attribute));
+        }
+
+        let Proposal {
+            approval_id,
+            proposer,
+            msg,
+            deposit,
+            expiration,
+            ..
+        } = self
+            .pending_proposals
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+
+        self.completed_proposals.save(
+            deps.storage,
+            id,
+            &Proposal {
+                status: ProposalStatus::Rejected { reason },
+                approval_id,
+                proposer: proposer.clone(),
+                msg,
+                deposit: deposit.clone(),
+                expiration,
+            },
+        )?;
+        self.pending_proposals.remove(deps.storage, id);
+
+        let messages = if let Some(ref deposit_info) = deposit {
+            // Refund can be issued if proposal if deposits are always
+            // refunded. `OnlyPassed` and `Never` refund deposit policies
+            // do not apply here.
+            if deposit_info.refund_policy == DepositRefundPolicy::Always {
+                deposit_info.get_return_deposit_message(&proposer)?
+            } else {
+                // If the proposer doesn't get the deposit, the DAO does.
+                deposit_info.get_return_deposit_message(dao)?
+            }
+        } else {
+            vec![]
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_rejected")
+            .add_attribute("proposal", id.to_string())
+            .add_attributes(reason_attribute)
+            .add_attribute("deposit_info", to_json_binary(&deposit)?.to_string())
+            .add_messages(messages))
+    }
+
+    pub fn execute_request_changes(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+        feedback: String,
+    ) -> Result<Response, PreProposeError> {
+        // Check sender is an approver
+        let (approvers, _) = self.load_approvers(deps.storage)?;
+        if !approvers.contains(&info.sender) {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        let mut proposal = self.load_pending(deps.storage, &env, id)?;
+        proposal.status = ProposalStatus::ChangesRequested {
+            feedback: feedback.clone(),
+        };
+        self.pending_proposals.save(deps.storage, id, &proposal)?;
+        // Approvals were given for the old version of the proposal.
+        self.approval_votes.remove(deps.storage, id);
+
+        Ok(Response::default()
+            .add_attribute("method", "changes_requested")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("approver", info.sender)
+            .add_attribute("feedback", feedback))
+    }
+
+    pub fn execute_expire(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let proposal = self
+            .pending_proposals
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        match proposal.expiration {
+            Some(expiration) if expiration.is_expired(&env.block) => (),
+            _ => return Err(PreProposeError::PendingProposalNotExpired {}),
+        }
+
+        // Expired proposals were never decided on, so their deposits
+        // are refunded regardless of the refund policy.
+        let messages = match proposal.deposit {
+            Some(ref deposit_info) => {
+                deposit_info.get_return_deposit_message(&proposal.proposer)?
+            }
+            None => vec![],
+        };
+
+        self.pending_proposals.remove(deps.storage, id);
+        self.completed_proposals.save(
+            deps.storage,
+            id,
+            &Proposal {
+                status: ProposalStatus::Expired {},
+                ..proposal
+            },
+        )?;
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_expired")
+            .add_attribute("approval_id", id.to_string())
+            .add_messages(messages))
+    }
+
+    pub fn execute_update_approver(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        // Check sender is the approver
+        let approver = self.approver.load(deps.storage)?;
+        if approver != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        // Validate address and save new approver
+        let addr = deps.api.addr_validate(&address)?;
+        self.approver.save(deps.storage, &addr)?;
+
+        // If the new approver was one of the other approvers, the old
+        // approver takes their place so the number of approvers is
+        // unchanged.
+        let mut approvers = self.approvers.may_load(deps.storage)?.unwrap_or_default();
+        if let Some(existing) = approvers.iter_mut().find(|a| **a == addr) {
+            *existing = approver;
+            self.approvers.save(deps.storage, &approvers)?;
+        }
+
+        Ok(Response::default())
+    }
+
+    pub fn execute_update_approvers(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        approvers: Vec<String>,
+        threshold: u64,
+    ) -> Result<Response, PreProposeError> {
+        // Check sender is the approver
+        let approver = self.approver.load(deps.storage)?;
+        if approver != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        self.save_approvers(deps, &approver, &approvers, threshold)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "update_approvers")
+            .add_attribute("threshold", threshold.to_string()))
+    }
+}
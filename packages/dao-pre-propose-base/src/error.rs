@@ -1,11 +1,13 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use cw_denom::DenomError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 use cw_hooks::HookError;
 use dao_voting::{deposit::DepositError, status::Status};
 
+use crate::state::ProposalMessageType;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum PreProposeError {
     #[error(transparent)]
@@ -32,6 +34,48 @@ pub enum PreProposeError {
     #[error("You must be a member of this DAO (have voting power) to create a proposal")]
     NotMember {},
 
+    #[error(
+        "Proposer has ({power}) voting power but ({required}) is required to create a proposal"
+    )]
+    BelowProposalThreshold { power: Uint128, required: Uint128 },
+
+    #[error("Proposal threshold must be greater than zero")]
+    ZeroProposalThreshold {},
+
+    #[error("Proposal threshold percentage must not be greater than 1")]
+    UnreachableProposalThreshold {},
+
+    #[error("Proposer does not hold a role that may create proposals")]
+    MissingSubmissionRole {},
+
+    #[error("Proposer's roles may not propose ({msg_type:?}) messages")]
+    MessageTypeNotAllowed { msg_type: ProposalMessageType },
+
+    #[error("At least one submission role must be configured")]
+    NoSubmissionRoles {},
+
+    #[error("Proposal message ({index}) does not match any message template")]
+    MessageNotAllowed { index: usize },
+
+    #[error("Proposal may send at most ({max}{denom}) but sends ({amount}{denom})")]
+    SpendLimitExceeded {
+        denom: String,
+        amount: Uint128,
+        max: Uint128,
+    },
+
+    #[error("Escrow of ({amount}{denom}) was not sent with the proposal")]
+    EscrowNotSent { denom: String, amount: Uint128 },
+
+    #[error("Proposer already has the maximum of ({max}) open proposals")]
+    TooManyOpenProposals { max: u64 },
+
+    #[error("Proposer may not create another proposal until ({expiration})")]
+    ProposalCooldown { expiration: Expiration },
+
+    #[error("Maximum open proposals must be greater than zero")]
+    ZeroMaxOpenProposals {},
+
     #[error("No denomination for withdrawal. specify a denomination to withdraw")]
     NoWithdrawalDenom {},
 
@@ -44,6 +88,33 @@ pub enum PreProposeError {
     #[error("Proposal not found")]
     ProposalNotFound {},
 
+    #[error("Approval threshold must be between one and the number of approvers")]
+    InvalidApprovalThreshold {},
+
+    #[error("Proposal is awaiting changes from its proposer")]
+    AwaitingChanges {},
+
+    #[error("Changes have not been requested for this proposal")]
+    ChangesNotRequested {},
+
+    #[error("Pending proposal has expired")]
+    PendingProposalExpired {},
+
+    #[error("Pending proposal has not expired")]
+    PendingProposalNotExpired {},
+
+    #[error("Draft has already been sponsored by this address")]
+    AlreadySponsored {},
+
+    #[error("At least one sponsor must be required")]
+    ZeroSponsorsRequired {},
+
+    #[error("Drafts may not include a vote, as the proposer is not the one creating the proposal")]
+    DraftVote {},
+
+    #[error("Draft expiration must be non-zero")]
+    ZeroDraftExpiration {},
+
     #[error("Unauthorized")]
     Unauthorized {},
 
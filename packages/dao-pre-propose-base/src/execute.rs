@@ -1,23 +1,34 @@
+use std::collections::BTreeMap;
+
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg,
-    WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
 
-use cw_denom::UncheckedDenom;
-use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_utils::nonpayable;
+use dao_interface::voting::{
+    Query as CwCoreQuery, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, DepositError, DepositPricingScope, DepositRefundPolicy,
+        UncheckedDepositInfo,
+    },
     status::Status,
 };
 use serde::Serialize;
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{DepositInfoResponse, DepositQuoteResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{
+        Config, MessageTemplate, PreProposeContract, ProposalMessageType, ProposalRateLimit,
+        ProposalThreshold, UncheckedSubmissionRoles,
+    },
 };
 
 const CONTRACT_NAME: &str = "crates.io::dao-pre-propose-base";
@@ -55,10 +66,27 @@ where
             .deposit_info
             .map(|info| info.into_checked(deps.as_ref(), dao.clone()))
             .transpose()?;
+        if let Some(ref rate_limit) = msg.rate_limit {
+            rate_limit.validate()?;
+        }
+        if let Some(ref proposal_threshold) = msg.proposal_threshold {
+            proposal_threshold.validate()?;
+        }
+        let submission_roles = msg
+            .submission_roles
+            .map(|roles| roles.into_checked(deps.as_ref()))
+            .transpose()?;
+        for template in msg.message_templates.iter().flatten() {
+            template.validate(deps.as_ref())?;
+        }
 
         let config = Config {
             deposit_info,
             open_proposal_submission: msg.open_proposal_submission,
+            rate_limit: msg.rate_limit,
+            proposal_threshold: msg.proposal_threshold,
+            submission_roles,
+            message_templates: msg.message_templates,
         };
 
         self.config.save(deps.storage, &config)?;
@@ -86,7 +114,20 @@ where
             ExecuteMsg::UpdateConfig {
                 deposit_info,
                 open_proposal_submission,
-            } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+                rate_limit,
+                proposal_threshold,
+                submission_roles,
+                message_templates,
+            } => self.execute_update_config(
+                deps,
+                info,
+                deposit_info,
+                open_proposal_submission,
+                rate_limit,
+                proposal_threshold,
+                submission_roles,
+                message_templates,
+            ),
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -99,7 +140,8 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+                keeper,
+            } => self.execute_proposal_completed_hook(deps, info, proposal_id, new_status, keeper),
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -113,11 +155,118 @@ where
         msg: ProposalMessage,
     ) -> Result<Response, PreProposeError> {
         self.check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.check_rate_limit(deps.as_ref(), &env, &info.sender)?;
+
+        let deposit_info = self.paid_deposit(deps.as_ref(), &env, &info)?;
+        if let Some(ref deposit_info) = deposit_info {
+            deposit_info.check_native_deposit_paid(&info)?;
+        }
+
+        self.submit_proposal(deps, env, info, deposit_info, msg, vec![])
+    }
+
+    /// Like `execute_propose`, but `escrow` is forwarded to the
+    /// proposal module to be escrowed with the proposal. The escrow
+    /// is split from the funds sent before the deposit is selected,
+    /// so that escrowed funds are never taken as a deposit. May only
+    /// be used with proposal modules that escrow the funds sent with
+    /// proposals.
+    pub fn execute_propose_with_escrow(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ProposalMessage,
+        escrow: Vec<Coin>,
+    ) -> Result<Response, PreProposeError> {
+        self.check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.check_rate_limit(deps.as_ref(), &env, &info.sender)?;
 
+        let (escrow, deposit_funds) = split_escrow(&info.funds, escrow)?;
+        let deposit_payment = MessageInfo {
+            sender: info.sender.clone(),
+            funds: deposit_funds,
+        };
+        let deposit_info = self.paid_deposit(deps.as_ref(), &env, &deposit_payment)?;
+        match deposit_info {
+            Some(ref deposit_info) if matches!(deposit_info.denom, CheckedDenom::Native(_)) => {
+                deposit_info.check_native_deposit_paid(&deposit_payment)?
+            }
+            _ => {
+                nonpayable(&deposit_payment).map_err(DepositError::from)?;
+            }
+        }
+
+        self.submit_proposal(deps, env, info, deposit_info, msg, escrow)
+    }
+
+    /// Returns which of the accepted deposits the sender is paying,
+    /// or `None` if no deposit is required.
+    pub fn paid_deposit(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> Result<Option<CheckedDepositInfo>, PreProposeError> {
+        Ok(self
+            .deposit_quote(deps, Some(&info.sender))?
+            .deposit_info
+            .map(|deposit_info| {
+                deposit_info.select_paid(deps, &info.funds, &info.sender, &env.contract.address)
+            })
+            .transpose()?)
+    }
+
+    /// Returns the deposit currently required of `proposer`, with
+    /// dynamic pricing applied. If no proposer is given, only the
+    /// module's open proposals are counted.
+    pub fn deposit_quote(
+        &self,
+        deps: Deps,
+        proposer: Option<&Addr>,
+    ) -> StdResult<DepositQuoteResponse> {
         let config = self.config.load(deps.storage)?;
+        let deposit_info = match config.deposit_info {
+            Some(deposit_info) => deposit_info,
+            None => {
+                return Ok(DepositQuoteResponse {
+                    deposit_info: None,
+                    open_proposals: 0,
+                })
+            }
+        };
+        let open_proposals = match (&deposit_info.pricing, proposer) {
+            (None, _) => 0,
+            (Some(pricing), _) if pricing.scope == DepositPricingScope::Module => self
+                .open_proposal_count
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+            (Some(_), Some(proposer)) => self
+                .open_proposal_counts
+                .may_load(deps.storage, proposer.clone())?
+                .unwrap_or_default(),
+            (Some(_), None) => 0,
+        };
+        Ok(DepositQuoteResponse {
+            deposit_info: Some(deposit_info.priced(open_proposals)?),
+            open_proposals,
+        })
+    }
 
-        let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-            deposit_info.check_native_deposit_paid(&info)?;
+    /// Takes the deposit, snapshots it, and creates the proposal in
+    /// the proposal module, sending `funds` along with the propose
+    /// message. Callers are responsible for checking that the sender
+    /// may submit proposals and has paid any native deposit.
+    fn submit_proposal(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        deposit_info: Option<CheckedDepositInfo>,
+        msg: ProposalMessage,
+        funds: Vec<Coin>,
+    ) -> Result<Response, PreProposeError> {
+        let deposit_messages = if let Some(ref deposit_info) = deposit_info {
             deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
         } else {
             vec![]
@@ -131,16 +280,14 @@ where
             &proposal_module,
             &dao_interface::proposal::Query::NextProposalId {},
         )?;
-        self.deposits.save(
-            deps.storage,
-            next_id,
-            &(config.deposit_info, info.sender.clone()),
-        )?;
+        self.deposits
+            .save(deps.storage, next_id, &(deposit_info, info.sender.clone()))?;
+        self.record_open_proposal(deps.storage, &env, &info.sender, next_id)?;
 
         let propose_messsage = WasmMsg::Execute {
             contract_addr: proposal_module.into_string(),
             msg: to_json_binary(&msg)?,
-            funds: vec![],
+            funds,
         };
 
         let hooks_msgs = self
@@ -166,12 +313,48 @@ where
             .add_messages(deposit_messages))
     }
 
+    /// Counts PROPOSAL_ID, created by PROPOSER, as open until it
+    /// completes and starts PROPOSER's cooldown, if one is
+    /// configured. Modules that create proposals without
+    /// `execute_propose` must call this when they do so for rate
+    /// limits and deposit pricing to account for those proposals.
+    pub fn record_open_proposal(
+        &self,
+        storage: &mut dyn Storage,
+        env: &Env,
+        proposer: &Addr,
+        proposal_id: u64,
+    ) -> StdResult<()> {
+        self.open_proposals.save(storage, proposal_id, proposer)?;
+        self.open_proposal_counts
+            .update(storage, proposer.clone(), |count| -> StdResult<_> {
+                Ok(count.unwrap_or_default() + 1)
+            })?;
+        let total = self
+            .open_proposal_count
+            .may_load(storage)?
+            .unwrap_or_default();
+        self.open_proposal_count.save(storage, &(total + 1))?;
+
+        let config = self.config.load(storage)?;
+        if let Some(cooldown) = config.rate_limit.and_then(|r| r.cooldown) {
+            self.cooldowns
+                .save(storage, proposer.clone(), &cooldown.after(&env.block))?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn execute_update_config(
         &self,
         deps: DepsMut,
         info: MessageInfo,
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        rate_limit: Option<ProposalRateLimit>,
+        proposal_threshold: Option<ProposalThreshold>,
+        submission_roles: Option<UncheckedSubmissionRoles>,
+        message_templates: Option<Vec<MessageTemplate>>,
     ) -> Result<Response, PreProposeError> {
         let dao = self.dao.load(deps.storage)?;
         if info.sender != dao {
@@ -180,11 +363,27 @@ where
             let deposit_info = deposit_info
                 .map(|d| d.into_checked(deps.as_ref(), dao))
                 .transpose()?;
+            if let Some(ref rate_limit) = rate_limit {
+                rate_limit.validate()?;
+            }
+            if let Some(ref proposal_threshold) = proposal_threshold {
+                proposal_threshold.validate()?;
+            }
+            let submission_roles = submission_roles
+                .map(|roles| roles.into_checked(deps.as_ref()))
+                .transpose()?;
+            for template in message_templates.iter().flatten() {
+                template.validate(deps.as_ref())?;
+            }
             self.config.save(
                 deps.storage,
                 &Config {
                     deposit_info,
                     open_proposal_submission,
+                    rate_limit,
+                    proposal_threshold,
+                    submission_roles,
+                    message_templates,
                 },
             )?;
 
@@ -205,29 +404,48 @@ where
         if info.sender != dao {
             Err(PreProposeError::NotDao {})
         } else {
-            let denom = match denom {
-                Some(denom) => Some(denom.into_checked(deps)?),
+            let denoms = match denom {
+                Some(denom) => vec![denom.into_checked(deps)?],
                 None => {
+                    // Proposers may have deposited the primary token
+                    // or any of its alternatives.
                     let config = self.config.load(deps.storage)?;
-                    config.deposit_info.map(|d| d.denom)
+                    let mut denoms = vec![];
+                    if let Some(deposit_info) = config.deposit_info {
+                        for denom in std::iter::once(deposit_info.denom).chain(
+                            deposit_info
+                                .alternatives
+                                .into_iter()
+                                .map(|alternative| alternative.denom),
+                        ) {
+                            if !denoms.contains(&denom) {
+                                denoms.push(denom);
+                            }
+                        }
+                    }
+                    denoms
                 }
             };
-            match denom {
-                None => Err(PreProposeError::NoWithdrawalDenom {}),
-                Some(denom) => {
-                    let balance = denom.query_balance(&deps.querier, &env.contract.address)?;
-                    if balance.is_zero() {
-                        Err(PreProposeError::NothingToWithdraw {})
-                    } else {
-                        let withdraw_message = denom.get_transfer_to_message(&dao, balance)?;
-                        Ok(Response::default()
-                            .add_message(withdraw_message)
-                            .add_attribute("method", "withdraw")
-                            .add_attribute("receiver", &dao)
-                            .add_attribute("denom", denom.to_string()))
-                    }
+            if denoms.is_empty() {
+                return Err(PreProposeError::NoWithdrawalDenom {});
+            }
+
+            let mut response = Response::default()
+                .add_attribute("method", "withdraw")
+                .add_attribute("receiver", &dao);
+            for denom in denoms {
+                let balance = denom.query_balance(&deps.querier, &env.contract.address)?;
+                if !balance.is_zero() {
+                    response = response
+                        .add_message(denom.get_transfer_to_message(&dao, balance)?)
+                        .add_attribute("denom", denom.to_string());
                 }
             }
+            if response.messages.is_empty() {
+                Err(PreProposeError::NothingToWithdraw {})
+            } else {
+                Ok(response)
+            }
         }
     }
 
@@ -271,10 +489,11 @@ where
 
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        deps: DepsMut,
         info: MessageInfo,
         id: u64,
         new_status: Status,
+        keeper: Option<String>,
     ) -> Result<Response, PreProposeError> {
         let proposal_module = self.proposal_module.load(deps.storage)?;
         if info.sender != proposal_module {
@@ -293,6 +512,25 @@ where
             return Err(PreProposeError::NotCompleted { status: new_status });
         }
 
+        // The proposal no longer counts towards deposit pricing or the
+        // proposer's open proposal limit.
+        if let Some(proposer) = self.open_proposals.may_load(deps.storage, id)? {
+            self.open_proposals.remove(deps.storage, id);
+            let count = self
+                .open_proposal_counts
+                .load(deps.storage, proposer.clone())?
+                .saturating_sub(1);
+            if count == 0 {
+                self.open_proposal_counts.remove(deps.storage, proposer);
+            } else {
+                self.open_proposal_counts
+                    .save(deps.storage, proposer, &count)?;
+            }
+            let total = self.open_proposal_count.load(deps.storage)?;
+            self.open_proposal_count
+                .save(deps.storage, &total.saturating_sub(1))?;
+        }
+
         match self.deposits.may_load(deps.storage, id)? {
             Some((deposit_info, proposer)) => {
                 let messages = if let Some(ref deposit_info) = deposit_info {
@@ -312,9 +550,13 @@ where
                     if should_refund_to_proposer {
                         deposit_info.get_return_deposit_message(&proposer)?
                     } else {
-                        // If the proposer doesn't get the deposit, the DAO does.
+                        // If the proposer doesn't get the deposit, the DAO
+                        // does, less any share owed to the keeper.
                         let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
+                        let keeper = keeper
+                            .map(|keeper| deps.api.addr_validate(&keeper))
+                            .transpose()?;
+                        deposit_info.get_forfeit_deposit_messages(&dao, keeper.as_ref())?
                     }
                 } else {
                     // No deposit info for this proposal. Nothing to do.
@@ -344,7 +586,7 @@ where
         if !config.open_proposal_submission {
             let dao = self.dao.load(deps.storage)?;
             let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
-                dao.into_string(),
+                &dao,
                 &CwCoreQuery::VotingPowerAtHeight {
                     address: who.into_string(),
                     height: None,
@@ -353,6 +595,145 @@ where
             if voting_power.power.is_zero() {
                 return Err(PreProposeError::NotMember {});
             }
+
+            if let Some(proposal_threshold) = config.proposal_threshold {
+                let total_power: TotalPowerAtHeightResponse = deps
+                    .querier
+                    .query_wasm_smart(&dao, &CwCoreQuery::TotalPowerAtHeight { height: None })?;
+                let required = proposal_threshold.required_power(total_power.power);
+                if voting_power.power < required {
+                    return Err(PreProposeError::BelowProposalThreshold {
+                        power: voting_power.power,
+                        required,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that WHO holds one of the roles that may create
+    /// proposals, if submission is restricted by role, and that one
+    /// of their roles may propose each message in MSGS. Proposal
+    /// modules built on this package must call this with the
+    /// messages of each proposal.
+    pub fn check_roles<T>(
+        &self,
+        deps: Deps,
+        who: &Addr,
+        msgs: &[CosmosMsg<T>],
+    ) -> Result<(), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        let submission_roles = match config.submission_roles {
+            Some(submission_roles) => submission_roles,
+            None => return Ok(()),
+        };
+
+        let held = submission_roles.held_by(deps, who)?;
+        if held.is_empty() {
+            return Err(PreProposeError::MissingSubmissionRole {});
+        }
+        for msg in msgs {
+            let msg_type = ProposalMessageType::of(msg);
+            let allowed = held.iter().any(|role| match role.allowed_msgs {
+                Some(ref allowed_msgs) => allowed_msgs.contains(&msg_type),
+                None => true,
+            });
+            if !allowed {
+                return Err(PreProposeError::MessageTypeNotAllowed { msg_type });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that each message in MSGS matches one of the message
+    /// templates, if any are configured, and that MSGS do not send
+    /// more than their bank send templates allow. Proposal modules
+    /// built on this package must call this with the messages of
+    /// each proposal, or of each choice of a multiple choice
+    /// proposal.
+    pub fn check_message_templates<T>(
+        &self,
+        deps: Deps,
+        msgs: &[CosmosMsg<T>],
+    ) -> Result<(), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        let templates = match config.message_templates {
+            Some(templates) => templates,
+            None => return Ok(()),
+        };
+
+        let mut sent: BTreeMap<String, Uint128> = BTreeMap::new();
+        for (index, msg) in msgs.iter().enumerate() {
+            let funds = match msg {
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount,
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg: execute_msg,
+                    funds,
+                }) if templates
+                    .iter()
+                    .any(|template| template.allows_execute(contract_addr, execute_msg)) =>
+                {
+                    funds
+                }
+                _ => return Err(PreProposeError::MessageNotAllowed { index }),
+            };
+            for coin in funds {
+                let total = sent.entry(coin.denom.clone()).or_default();
+                *total = total.checked_add(coin.amount).map_err(StdError::from)?;
+            }
+        }
+
+        for (denom, amount) in sent {
+            let max = templates
+                .iter()
+                .filter_map(|template| match template {
+                    MessageTemplate::BankSend {
+                        denom: allowed,
+                        max_amount,
+                    } if *allowed == denom => Some(*max_amount),
+                    _ => None,
+                })
+                .max()
+                .unwrap_or_default();
+            if amount > max {
+                return Err(PreProposeError::SpendLimitExceeded { denom, amount, max });
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that WHO has neither reached the configured maximum
+    /// number of open proposals nor is still cooling down from their
+    /// last proposal.
+    pub fn check_rate_limit(
+        &self,
+        deps: Deps,
+        env: &Env,
+        who: &Addr,
+    ) -> Result<(), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        let rate_limit = match config.rate_limit {
+            Some(rate_limit) => rate_limit,
+            None => return Ok(()),
+        };
+
+        if let Some(max) = rate_limit.max_open_proposals {
+            let open = self
+                .open_proposal_counts
+                .may_load(deps.storage, who.clone())?
+                .unwrap_or_default();
+            if open >= max {
+                return Err(PreProposeError::TooManyOpenProposals { max });
+            }
+        }
+        if rate_limit.cooldown.is_some() {
+            if let Some(expiration) = self.cooldowns.may_load(deps.storage, who.clone())? {
+                if !expiration.is_expired(&env.block) {
+                    return Err(PreProposeError::ProposalCooldown { expiration });
+                }
+            }
         }
         Ok(())
     }
@@ -364,6 +745,12 @@ where
             }
             QueryMsg::Dao {} => to_json_binary(&self.dao.load(deps.storage)?),
             QueryMsg::Config {} => to_json_binary(&self.config.load(deps.storage)?),
+            QueryMsg::DepositQuote { proposer } => {
+                let proposer = proposer
+                    .map(|proposer| deps.api.addr_validate(&proposer))
+                    .transpose()?;
+                to_json_binary(&self.deposit_quote(deps, proposer.as_ref())?)
+            }
             QueryMsg::DepositInfo { proposal_id } => {
                 let (deposit_info, proposer) = self.deposits.load(deps.storage, proposal_id)?;
                 to_json_binary(&DepositInfoResponse {
@@ -378,3 +765,36 @@ where
         }
     }
 }
+
+/// Splits ESCROW from FUNDS, erroring if it was not sent. Returns the
+/// escrow with one nonzero coin per denom, sorted by denom, and the
+/// remaining funds, which are to pay the deposit.
+fn split_escrow(
+    funds: &[Coin],
+    escrow: Vec<Coin>,
+) -> Result<(Vec<Coin>, Vec<Coin>), PreProposeError> {
+    let mut merged: BTreeMap<String, Uint128> = BTreeMap::new();
+    for coin in escrow {
+        let total = merged.entry(coin.denom).or_default();
+        *total = total.checked_add(coin.amount).map_err(StdError::from)?;
+    }
+    let escrow: Vec<Coin> = merged
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect();
+
+    let mut remaining = funds.to_vec();
+    for coin in &escrow {
+        let sent = remaining
+            .iter_mut()
+            .find(|sent| sent.denom == coin.denom && sent.amount >= coin.amount)
+            .ok_or_else(|| PreProposeError::EscrowNotSent {
+                denom: coin.denom.clone(),
+                amount: coin.amount,
+            })?;
+        sent.amount -= coin.amount;
+    }
+    remaining.retain(|coin| !coin.amount.is_zero());
+    Ok((escrow, remaining))
+}
@@ -5,6 +5,10 @@ use dao_voting::{
     status::Status,
 };
 
+use crate::state::{
+    MessageTemplate, ProposalRateLimit, ProposalThreshold, UncheckedSubmissionRoles,
+};
+
 #[cw_serde]
 pub struct InstantiateMsg<InstantiateExt> {
     /// Information about the deposit requirements for this
@@ -14,6 +18,24 @@ pub struct InstantiateMsg<InstantiateExt> {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Limits on how often a single address may create proposals. If
+    /// `None`, proposals are not rate limited.
+    #[serde(default)]
+    pub rate_limit: Option<ProposalRateLimit>,
+    /// The voting power members must have to create a proposal. If
+    /// `None`, any nonzero voting power is sufficient. Only applies
+    /// if `open_proposal_submission` is false.
+    #[serde(default)]
+    pub proposal_threshold: Option<ProposalThreshold>,
+    /// If set, only holders of one of these roles in a cw721-roles
+    /// contract may create proposals.
+    #[serde(default)]
+    pub submission_roles: Option<UncheckedSubmissionRoles>,
+    /// If set, every message in a proposal must match one of these
+    /// templates. An empty list only allows proposals without
+    /// messages.
+    #[serde(default)]
+    pub message_templates: Option<Vec<MessageTemplate>>,
     /// Extension for instantiation. The default implementation will
     /// do nothing with this data.
     pub extension: InstantiateExt,
@@ -29,9 +51,20 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// override the existing configuration. This new configuration
     /// will only apply to proposals created after the config is
     /// updated. Only the DAO may execute this message.
+    ///
+    /// As every field is overridden, every field must be present in
+    /// the message. Set a field to `null` to disable it.
     UpdateConfig {
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        #[serde(deserialize_with = "required")]
+        rate_limit: Option<ProposalRateLimit>,
+        #[serde(deserialize_with = "required")]
+        proposal_threshold: Option<ProposalThreshold>,
+        #[serde(deserialize_with = "required")]
+        submission_roles: Option<UncheckedSubmissionRoles>,
+        #[serde(deserialize_with = "required")]
+        message_templates: Option<Vec<MessageTemplate>>,
     },
 
     /// Withdraws funds inside of this contract to the message
@@ -55,8 +88,9 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// an attacker to drain proposal deposits.
     Withdraw {
         /// The denom to withdraw funds for. If no denom is specified,
-        /// the denomination currently configured for proposal
-        /// deposits will be used.
+        /// funds are withdrawn for the denomination currently
+        /// configured for proposal deposits and each of its
+        /// alternatives.
         ///
         /// You may want to specify a denomination here if you are
         /// withdrawing funds that were previously accepted for
@@ -85,6 +119,11 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     ProposalCompletedHook {
         proposal_id: u64,
         new_status: Status,
+        /// The address that executed or closed the proposal, if
+        /// any. Paid the deposit's keeper share when the deposit is
+        /// not refunded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keeper: Option<String>,
     },
 }
 
@@ -109,6 +148,12 @@ where
     /// PROPOSAL_ID.
     #[returns(DepositInfoResponse)]
     DepositInfo { proposal_id: u64 },
+    /// Gets the deposit currently required to create a proposal,
+    /// which may depend on the number of open proposals. If
+    /// PROPOSER is specified, their open proposals are counted where
+    /// the deposit is priced per proposer.
+    #[returns(DepositQuoteResponse)]
+    DepositQuote { proposer: Option<String> },
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
@@ -118,6 +163,15 @@ where
     QueryExtension { msg: QueryExt },
 }
 
+#[cw_serde]
+pub struct DepositQuoteResponse {
+    /// The deposit currently required, or `None` if no deposit is
+    /// required.
+    pub deposit_info: Option<CheckedDepositInfo>,
+    /// The number of open proposals counted to price the deposit.
+    pub open_proposals: u64,
+}
+
 #[cw_serde]
 pub struct DepositInfoResponse {
     /// The deposit that has been paid for the specified proposal.
@@ -125,3 +179,15 @@ pub struct DepositInfoResponse {
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
 }
+
+/// Deserializes an `Option` field that may be `null` but may not be
+/// omitted. Without this, serde treats a missing `Option` field as
+/// `None`, so a config update written before a field existed would
+/// silently disable it.
+fn required<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    serde::Deserialize::deserialize(deserializer)
+}
@@ -1,12 +1,17 @@
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{from_json, Addr, Binary, CosmosMsg, Decimal, Deps, StdResult, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 
+use dao_cw721_extensions::roles::MetadataExt;
 use dao_voting::deposit::CheckedDepositInfo;
 
+use crate::error::PreProposeError;
+
 #[cw_serde]
 pub struct Config {
     /// Information about the deposit required to create a
@@ -16,6 +21,244 @@ pub struct Config {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Limits on how often a single address may create proposals. If
+    /// `None`, proposals are not rate limited.
+    #[serde(default)]
+    pub rate_limit: Option<ProposalRateLimit>,
+    /// The voting power members must have to create a proposal. If
+    /// `None`, any nonzero voting power is sufficient. Only applies
+    /// if `open_proposal_submission` is false.
+    #[serde(default)]
+    pub proposal_threshold: Option<ProposalThreshold>,
+    /// If set, only holders of one of these roles in a cw721-roles
+    /// contract may create proposals.
+    #[serde(default)]
+    pub submission_roles: Option<SubmissionRoles>,
+    /// If set, every message in a proposal must match one of these
+    /// templates. An empty list only allows proposals without
+    /// messages.
+    #[serde(default)]
+    pub message_templates: Option<Vec<MessageTemplate>>,
+}
+
+#[cw_serde]
+pub struct UncheckedSubmissionRoles {
+    /// The cw721-roles contract whose token roles are checked.
+    pub cw721_roles: String,
+    /// The roles that may create proposals.
+    pub roles: Vec<SubmissionRole>,
+}
+
+#[cw_serde]
+pub struct SubmissionRoles {
+    /// The cw721-roles contract whose token roles are checked.
+    pub cw721_roles: Addr,
+    /// The roles that may create proposals.
+    pub roles: Vec<SubmissionRole>,
+}
+
+#[cw_serde]
+pub struct SubmissionRole {
+    /// The role, as set in the `role` field of a token's metadata.
+    pub role: String,
+    /// The types of messages holders of this role may propose. If
+    /// `None`, any message may be proposed.
+    pub allowed_msgs: Option<Vec<ProposalMessageType>>,
+}
+
+/// The type of a message in a proposal, one for each variant of
+/// `CosmosMsg`.
+#[cw_serde]
+#[derive(Copy)]
+pub enum ProposalMessageType {
+    Bank,
+    Wasm,
+    Stargate,
+    Ibc,
+    Gov,
+    Custom,
+    /// Any other message, such as staking and distribution
+    /// messages.
+    Other,
+}
+
+impl ProposalMessageType {
+    pub fn of<T>(msg: &CosmosMsg<T>) -> Self {
+        match msg {
+            CosmosMsg::Bank(_) => ProposalMessageType::Bank,
+            CosmosMsg::Wasm(_) => ProposalMessageType::Wasm,
+            CosmosMsg::Stargate { .. } => ProposalMessageType::Stargate,
+            CosmosMsg::Ibc(_) => ProposalMessageType::Ibc,
+            CosmosMsg::Gov(_) => ProposalMessageType::Gov,
+            CosmosMsg::Custom(_) => ProposalMessageType::Custom,
+            _ => ProposalMessageType::Other,
+        }
+    }
+}
+
+impl UncheckedSubmissionRoles {
+    pub fn into_checked(self, deps: Deps) -> Result<SubmissionRoles, PreProposeError> {
+        if self.roles.is_empty() {
+            return Err(PreProposeError::NoSubmissionRoles {});
+        }
+        Ok(SubmissionRoles {
+            cw721_roles: deps.api.addr_validate(&self.cw721_roles)?,
+            roles: self.roles,
+        })
+    }
+}
+
+impl SubmissionRoles {
+    /// Returns the configured roles held by WHO.
+    pub fn held_by(&self, deps: Deps, who: &Addr) -> StdResult<Vec<&SubmissionRole>> {
+        let mut held = vec![];
+        let mut start_after = None;
+        loop {
+            let tokens: cw721::TokensResponse = deps.querier.query_wasm_smart(
+                &self.cw721_roles,
+                &cw721::Cw721QueryMsg::Tokens {
+                    owner: who.to_string(),
+                    start_after: start_after.clone(),
+                    limit: None,
+                },
+            )?;
+            for token_id in &tokens.tokens {
+                let info: cw721::NftInfoResponse<MetadataExt> = deps.querier.query_wasm_smart(
+                    &self.cw721_roles,
+                    &cw721::Cw721QueryMsg::NftInfo {
+                        token_id: token_id.clone(),
+                    },
+                )?;
+                if let Some(role) = info.extension.role {
+                    held.extend(self.roles.iter().filter(|r| r.role == role));
+                }
+            }
+            match tokens.tokens.last() {
+                Some(last) => start_after = Some(last.clone()),
+                None => break,
+            }
+        }
+        Ok(held)
+    }
+}
+
+/// A kind of message that proposals may contain.
+#[cw_serde]
+pub enum MessageTemplate {
+    /// Bank sends of `denom`. At most `max_amount` may be sent in
+    /// total by a proposal's messages, including funds sent with wasm
+    /// executes.
+    BankSend { denom: String, max_amount: Uint128 },
+    /// Wasm executes on `contract`. If `variants` is set, the
+    /// top-level variant of the executed message must be one of
+    /// them, for example `"transfer"`.
+    WasmExecute {
+        contract: String,
+        variants: Option<Vec<String>>,
+    },
+}
+
+impl MessageTemplate {
+    pub fn validate(&self, deps: Deps) -> StdResult<()> {
+        if let MessageTemplate::WasmExecute { contract, .. } = self {
+            deps.api.addr_validate(contract)?;
+        }
+        Ok(())
+    }
+
+    /// Returns true if this template allows executing MSG on
+    /// CONTRACT.
+    pub fn allows_execute(&self, contract: &str, msg: &Binary) -> bool {
+        match self {
+            MessageTemplate::WasmExecute {
+                contract: allowed,
+                variants,
+            } => {
+                allowed == contract
+                    && match variants {
+                        Some(variants) => {
+                            top_level_variant(msg).is_some_and(|v| variants.contains(&v))
+                        }
+                        None => true,
+                    }
+            }
+            MessageTemplate::BankSend { .. } => false,
+        }
+    }
+}
+
+/// Returns the top-level variant of MSG if it is a JSON encoded
+/// enum, which serializes either as the variant's name or as an
+/// object with the variant's name as its only key.
+fn top_level_variant(msg: &Binary) -> Option<String> {
+    if let Ok(variant) = from_json::<String>(msg) {
+        return Some(variant);
+    }
+    let fields: BTreeMap<String, serde::de::IgnoredAny> = from_json(msg).ok()?;
+    if fields.len() != 1 {
+        return None;
+    }
+    fields.into_keys().next()
+}
+
+/// The voting power an address must have in the DAO to create a
+/// proposal.
+#[cw_serde]
+pub enum ProposalThreshold {
+    /// The absolute voting power required.
+    AbsoluteCount { count: Uint128 },
+    /// The percentage of the DAO's total voting power required.
+    Percentage { percent: Decimal },
+}
+
+impl ProposalThreshold {
+    pub fn validate(&self) -> Result<(), PreProposeError> {
+        match self {
+            ProposalThreshold::AbsoluteCount { count } => {
+                if count.is_zero() {
+                    return Err(PreProposeError::ZeroProposalThreshold {});
+                }
+            }
+            ProposalThreshold::Percentage { percent } => {
+                if percent.is_zero() {
+                    return Err(PreProposeError::ZeroProposalThreshold {});
+                }
+                if *percent > Decimal::one() {
+                    return Err(PreProposeError::UnreachableProposalThreshold {});
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the voting power required given the DAO's total
+    /// voting power.
+    pub fn required_power(&self, total_power: Uint128) -> Uint128 {
+        match self {
+            ProposalThreshold::AbsoluteCount { count } => *count,
+            ProposalThreshold::Percentage { percent } => total_power * *percent,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ProposalRateLimit {
+    /// The maximum number of open proposals an address may have at
+    /// once. If `None`, the number of open proposals is not limited.
+    pub max_open_proposals: Option<u64>,
+    /// The minimum time an address must wait after creating a
+    /// proposal before creating another. If `None`, there is no
+    /// cooldown.
+    pub cooldown: Option<Duration>,
+}
+
+impl ProposalRateLimit {
+    pub fn validate(&self) -> Result<(), PreProposeError> {
+        if self.max_open_proposals == Some(0) {
+            return Err(PreProposeError::ZeroMaxOpenProposals {});
+        }
+        Ok(())
+    }
 }
 
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
@@ -30,6 +273,17 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
+    /// Proposals created through this module that have not yet
+    /// completed, mapped to their proposers. Used to price deposits
+    /// and rate limit proposers.
+    pub open_proposals: Map<'static, u64, Addr>,
+    /// The number of open proposals created by each proposer.
+    pub open_proposal_counts: Map<'static, Addr, u64>,
+    /// The total number of open proposals.
+    pub open_proposal_count: Item<'static, u64>,
+    /// The time at which each proposer may next create a proposal,
+    /// if a cooldown is configured.
+    pub cooldowns: Map<'static, Addr, Expiration>,
 
     // These types are used in associated functions, but not
     // assocaited data. To stop the compiler complaining about unused
@@ -43,12 +297,17 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 {
+    #[allow(clippy::too_many_arguments)]
     const fn new(
         proposal_key: &'static str,
         dao_key: &'static str,
         config_key: &'static str,
         deposits_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
+        open_proposals_key: &'static str,
+        open_proposal_counts_key: &'static str,
+        open_proposal_count_key: &'static str,
+        cooldowns_key: &'static str,
     ) -> Self {
         Self {
             proposal_module: Item::new(proposal_key),
@@ -56,6 +315,10 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
             config: Item::new(config_key),
             deposits: Map::new(deposits_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
+            open_proposals: Map::new(open_proposals_key),
+            open_proposal_counts: Map::new(open_proposal_counts_key),
+            open_proposal_count: Item::new(open_proposal_count_key),
+            cooldowns: Map::new(cooldowns_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
             query_type: PhantomData,
@@ -77,6 +340,10 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "config",
             "deposits",
             "proposal_submitted_hooks",
+            "open_proposals",
+            "open_proposal_counts",
+            "open_proposal_count",
+            "cooldowns",
         )
     }
 }
@@ -33,6 +33,7 @@ fn test_completed_hook_status_invariant() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            keeper: None,
         },
     );
 
@@ -62,6 +63,7 @@ fn test_completed_hook_auth() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            keeper: None,
         },
     );
 
@@ -88,6 +90,10 @@ fn test_proposal_submitted_hooks() {
             &Config {
                 deposit_info: None,
                 open_proposal_submission: true,
+                rate_limit: None,
+                proposal_threshold: None,
+                submission_roles: None,
+                message_templates: None,
             },
         )
         .unwrap();
@@ -200,3 +206,29 @@ fn test_execute_ext_does_nothing() {
         .unwrap();
     assert_eq!(res, Response::default())
 }
+
+#[test]
+fn test_update_config_fields_required() {
+    // Omitting a field would otherwise disable it.
+    let err = from_json::<ExecuteMsg<Empty, Empty>>(
+        br#"{"update_config":{"deposit_info":null,"open_proposal_submission":false,"proposal_threshold":null,"submission_roles":null,"message_templates":null}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("rate_limit"));
+
+    let msg: ExecuteMsg<Empty, Empty> = from_json(
+        br#"{"update_config":{"deposit_info":null,"open_proposal_submission":false,"rate_limit":null,"proposal_threshold":null,"submission_roles":null,"message_templates":null}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        msg,
+        ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            rate_limit: None,
+            proposal_threshold: None,
+            submission_roles: None,
+            message_templates: None,
+        }
+    );
+}
@@ -1,6 +1,7 @@
 use cosmwasm_std::Empty;
 
 use cw_multi_test::{Contract, ContractWrapper};
+use dao_pre_propose_condorcet as cppc;
 use dao_pre_propose_multiple as cppm;
 use dao_pre_propose_single as cpps;
 
@@ -59,6 +60,15 @@ pub fn proposal_condorcet_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn proposal_signaling_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_signaling::contract::execute,
+        dao_proposal_signaling::contract::instantiate,
+        dao_proposal_signaling::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_proposal_single::contract::execute,
@@ -79,6 +89,15 @@ pub fn pre_propose_single_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn pre_propose_condorcet_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cppc::contract::execute,
+        cppc::contract::instantiate,
+        cppc::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn pre_propose_multiple_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         cppm::contract::execute,
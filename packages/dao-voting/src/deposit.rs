@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, Deps, MessageInfo, StdError, StdResult, Uint128, WasmMsg,
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Deps, MessageInfo, StdError, StdResult,
+    Uint128, WasmMsg,
 };
 use cw_utils::{must_pay, PaymentError};
 
@@ -26,6 +27,14 @@ pub enum DepositError {
 
     #[error("invalid deposit amount. got ({actual}), expected ({expected})")]
     InvalidDeposit { actual: Uint128, expected: Uint128 },
+
+    #[error(
+        "invalid zero deposit pricing increment. set the pricing to `None` to have a flat deposit"
+    )]
+    ZeroPricingIncrement,
+
+    #[error("invalid keeper share. must be greater than zero and at most one")]
+    InvalidKeeperShare,
 }
 
 // The voting module token type to expect.
@@ -61,6 +70,75 @@ pub struct UncheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding deposits on proposal completion.
     pub refund_policy: DepositRefundPolicy,
+    /// Other tokens that proposers may deposit instead of `denom`.
+    /// Proposers pay any one of the accepted deposits.
+    #[serde(default)]
+    pub alternatives: Vec<UncheckedDepositAlternative>,
+    /// If set, the deposit increases with the number of open
+    /// proposals. Otherwise, the deposit is flat.
+    #[serde(default)]
+    pub pricing: Option<DepositPricing>,
+    /// If set, this share of a deposit that is not refunded is paid
+    /// to whoever executed or closed the proposal, and the rest to
+    /// the DAO. Must be greater than zero and at most one.
+    #[serde(default)]
+    pub keeper_share: Option<Decimal>,
+}
+
+/// A deposit that may be paid in place of a deposit's primary token.
+#[cw_serde]
+pub struct UncheckedDepositAlternative {
+    /// The token to be deposited.
+    pub denom: DepositToken,
+    /// The number of tokens that must be deposited. Must be a
+    /// positive, non-zero number.
+    pub amount: Uint128,
+}
+
+/// Counterpart to `UncheckedDepositAlternative` which has been
+/// validated.
+#[cw_serde]
+pub struct CheckedDepositAlternative {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+/// Dynamic pricing for proposal deposits. For every counted open
+/// proposal, `increment` times the base deposit is added to the
+/// deposit. For example, with an increment of `0.5` and two counted
+/// open proposals the deposit is twice the base amount.
+#[cw_serde]
+pub struct DepositPricing {
+    /// Which open proposals are counted.
+    pub scope: DepositPricingScope,
+    /// The fraction of the base deposit added per open proposal.
+    pub increment: Decimal,
+}
+
+#[cw_serde]
+pub enum DepositPricingScope {
+    /// Count all open proposals created through the pre-propose
+    /// module.
+    Module,
+    /// Count only the proposer's open proposals.
+    Proposer,
+}
+
+impl DepositPricing {
+    pub fn validate(&self) -> Result<(), DepositError> {
+        if self.increment.is_zero() {
+            Err(DepositError::ZeroPricingIncrement)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the price of a deposit with a base price of `amount`
+    /// when `open_proposals` proposals are counted.
+    pub fn price(&self, amount: Uint128, open_proposals: u64) -> StdResult<Uint128> {
+        let increase = (amount * self.increment).checked_mul(Uint128::from(open_proposals))?;
+        Ok(amount.checked_add(increase)?)
+    }
 }
 
 #[cw_serde]
@@ -89,6 +167,18 @@ pub struct CheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding proposal deposits.
     pub refund_policy: DepositRefundPolicy,
+    /// Other tokens that proposers may deposit instead of
+    /// `denom`. Empty for deposits that have been paid.
+    #[serde(default)]
+    pub alternatives: Vec<CheckedDepositAlternative>,
+    /// How the deposit scales with the number of open proposals.
+    /// `None` for flat deposits and deposits that have been paid.
+    #[serde(default)]
+    pub pricing: Option<DepositPricing>,
+    /// The share of a forfeited deposit paid to whoever executed or
+    /// closed the proposal.
+    #[serde(default)]
+    pub keeper_share: Option<Decimal>,
 }
 
 impl UncheckedDepositInfo {
@@ -98,60 +188,174 @@ impl UncheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            alternatives,
+            pricing,
+            keeper_share,
         } = self;
-        // Check that the deposit is non-zero. Modules should make
-        // deposit information optional and consumers should provide
-        // `None` when they do not want to have a proposal deposit.
-        if amount.is_zero() {
-            return Err(DepositError::ZeroDeposit);
+        let (denom, amount) = check_deposit_token(deps, &dao, denom, amount)?;
+        if let Some(ref pricing) = pricing {
+            pricing.validate()?;
         }
-
-        let denom = match denom {
-            DepositToken::Token { denom } => denom.into_checked(deps),
-            DepositToken::VotingModuleToken { token_type } => {
-                let voting_module: Addr = deps
-                    .querier
-                    .query_wasm_smart(dao, &dao_interface::msg::QueryMsg::VotingModule {})?;
-
-                match token_type {
-                    VotingModuleTokenType::Native => {
-                        // If the voting module has no native token denom this
-                        // will error. This is desirable.
-                        let denom: DenomResponse = deps.querier.query_wasm_smart(
-                            voting_module,
-                            &dao_interface::voting::Query::Denom {},
-                        )?;
-
-                        // Validate that native denom is formatted correctly.
-                        UncheckedDenom::Native(denom.denom).into_checked(deps)
-                    }
-                    VotingModuleTokenType::Cw20 => {
-                        // If the voting module has no cw20 token this will
-                        // error. This is desirable.
-                        let token_addr: Addr = deps.querier.query_wasm_smart(
-                            voting_module,
-                            &dao_interface::voting::Query::TokenContract {},
-                        )?;
-
-                        // We don't assume here that the voting module has
-                        // returned a valid token. Conversion of the unchecked
-                        // denom into a checked one will do a `TokenInfo {}`
-                        // query.
-                        UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
-                    }
-                }
+        if let Some(share) = keeper_share {
+            if share.is_zero() || share > Decimal::one() {
+                return Err(DepositError::InvalidKeeperShare);
             }
-        }?;
+        }
+        let alternatives = alternatives
+            .into_iter()
+            .map(|UncheckedDepositAlternative { denom, amount }| {
+                check_deposit_token(deps, &dao, denom, amount)
+                    .map(|(denom, amount)| CheckedDepositAlternative { denom, amount })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
         Ok(CheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            alternatives,
+            pricing,
+            keeper_share,
         })
     }
 }
 
+/// Validates a deposit token and amount.
+fn check_deposit_token(
+    deps: Deps,
+    dao: &Addr,
+    denom: DepositToken,
+    amount: Uint128,
+) -> Result<(CheckedDenom, Uint128), DepositError> {
+    // Check that the deposit is non-zero. Modules should make
+    // deposit information optional and consumers should provide
+    // `None` when they do not want to have a proposal deposit.
+    if amount.is_zero() {
+        return Err(DepositError::ZeroDeposit);
+    }
+
+    let denom = match denom {
+        DepositToken::Token { denom } => denom.into_checked(deps),
+        DepositToken::VotingModuleToken { token_type } => {
+            let voting_module: Addr = deps
+                .querier
+                .query_wasm_smart(dao, &dao_interface::msg::QueryMsg::VotingModule {})?;
+
+            match token_type {
+                VotingModuleTokenType::Native => {
+                    // If the voting module has no native token denom this
+                    // will error. This is desirable.
+                    let denom: DenomResponse = deps
+                        .querier
+                        .query_wasm_smart(voting_module, &dao_interface::voting::Query::Denom {})?;
+
+                    // Validate that native denom is formatted correctly.
+                    UncheckedDenom::Native(denom.denom).into_checked(deps)
+                }
+                VotingModuleTokenType::Cw20 => {
+                    // If the voting module has no cw20 token this will
+                    // error. This is desirable.
+                    let token_addr: Addr = deps.querier.query_wasm_smart(
+                        voting_module,
+                        &dao_interface::voting::Query::TokenContract {},
+                    )?;
+
+                    // We don't assume here that the voting module has
+                    // returned a valid token. Conversion of the unchecked
+                    // denom into a checked one will do a `TokenInfo {}`
+                    // query.
+                    UncheckedDenom::Cw20(token_addr.into_string()).into_checked(deps)
+                }
+            }
+        }
+    }?;
+
+    Ok((denom, amount))
+}
+
 impl CheckedDepositInfo {
+    /// Returns the deposit required when `open_proposals` proposals
+    /// are counted by this deposit's pricing, with the pricing
+    /// applied to the primary deposit and its alternatives. The
+    /// returned deposit has no pricing.
+    pub fn priced(&self, open_proposals: u64) -> StdResult<CheckedDepositInfo> {
+        let pricing = match self.pricing {
+            Some(ref pricing) => pricing,
+            None => return Ok(self.clone()),
+        };
+        Ok(CheckedDepositInfo {
+            denom: self.denom.clone(),
+            amount: pricing.price(self.amount, open_proposals)?,
+            refund_policy: self.refund_policy.clone(),
+            alternatives: self
+                .alternatives
+                .iter()
+                .map(|alternative| {
+                    Ok(CheckedDepositAlternative {
+                        denom: alternative.denom.clone(),
+                        amount: pricing.price(alternative.amount, open_proposals)?,
+                    })
+                })
+                .collect::<StdResult<_>>()?,
+            pricing: None,
+            keeper_share: self.keeper_share,
+        })
+    }
+
+    /// Determines which of the accepted deposits a proposer is
+    /// paying. The first accepted native deposit whose denom is in
+    /// `funds` is selected, then the first accepted cw20 deposit that
+    /// `depositor` has given `spender` a sufficient allowance for. If
+    /// neither is found the primary deposit is selected so that paying
+    /// it fails with the usual errors. Returns a deposit with no
+    /// alternatives, which is to be taken and recorded.
+    pub fn select_paid(
+        &self,
+        deps: Deps,
+        funds: &[Coin],
+        depositor: &Addr,
+        spender: &Addr,
+    ) -> StdResult<CheckedDepositInfo> {
+        let options = std::iter::once((&self.denom, self.amount)).chain(
+            self.alternatives
+                .iter()
+                .map(|alternative| (&alternative.denom, alternative.amount)),
+        );
+        let selected = |denom: &CheckedDenom, amount: Uint128| CheckedDepositInfo {
+            denom: denom.clone(),
+            amount,
+            refund_policy: self.refund_policy.clone(),
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: self.keeper_share,
+        };
+
+        for (denom, amount) in options.clone() {
+            if let CheckedDenom::Native(native) = denom {
+                if funds.iter().any(|c| &c.denom == native) {
+                    return Ok(selected(denom, amount));
+                }
+            }
+        }
+        if !self.alternatives.is_empty() {
+            for (denom, amount) in options {
+                if let CheckedDenom::Cw20(token) = denom {
+                    let allowance: cw20::AllowanceResponse = deps.querier.query_wasm_smart(
+                        token,
+                        &cw20::Cw20QueryMsg::Allowance {
+                            owner: depositor.to_string(),
+                            spender: spender.to_string(),
+                        },
+                    )?;
+                    if allowance.allowance >= amount {
+                        return Ok(selected(denom, amount));
+                    }
+                }
+            }
+        }
+        Ok(selected(&self.denom, self.amount))
+    }
+
     pub fn check_native_deposit_paid(&self, info: &MessageInfo) -> Result<(), DepositError> {
         if let Self {
             amount,
@@ -221,11 +425,39 @@ impl CheckedDepositInfo {
         let message = self.denom.get_transfer_to_message(depositor, self.amount)?;
         Ok(vec![message])
     }
+
+    /// Returns the messages paying out a deposit that is not being
+    /// refunded. If the deposit has a keeper share and a `keeper` is
+    /// given, the keeper is paid their share and the DAO the rest.
+    /// Otherwise, the DAO receives the whole deposit.
+    pub fn get_forfeit_deposit_messages(
+        &self,
+        dao: &Addr,
+        keeper: Option<&Addr>,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        let mut messages = vec![];
+        let mut dao_amount = self.amount;
+        if let (Some(share), Some(keeper)) = (self.keeper_share, keeper) {
+            let keeper_amount = self.amount * share;
+            if !keeper_amount.is_zero() {
+                messages.push(self.denom.get_transfer_to_message(keeper, keeper_amount)?);
+                dao_amount -= keeper_amount;
+            }
+        }
+        if !dao_amount.is_zero() {
+            messages.push(self.denom.get_transfer_to_message(dao, dao_amount)?);
+        }
+        Ok(messages)
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use cosmwasm_std::{coin, coins, testing::mock_info, BankMsg};
+    use cosmwasm_std::{
+        coin, coins,
+        testing::{mock_dependencies, mock_info},
+        BankMsg,
+    };
 
     use super::*;
 
@@ -239,6 +471,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         deposit_info.check_native_deposit_paid(&info).unwrap();
 
@@ -261,6 +496,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -279,6 +517,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -299,6 +540,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
 
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
@@ -312,11 +556,102 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
     }
 
+    #[test]
+    fn test_priced() {
+        let mut deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![CheckedDepositAlternative {
+                denom: CheckedDenom::Native("uusdc".to_string()),
+                amount: Uint128::new(3),
+            }],
+            pricing: None,
+            keeper_share: None,
+        };
+        assert_eq!(deposit_info.priced(5).unwrap(), deposit_info);
+
+        deposit_info.pricing = Some(DepositPricing {
+            scope: DepositPricingScope::Module,
+            increment: Decimal::percent(50),
+        });
+        let priced = deposit_info.priced(2).unwrap();
+        assert_eq!(priced.amount, Uint128::new(20));
+        assert_eq!(priced.alternatives[0].amount, Uint128::new(5));
+        assert_eq!(priced.pricing, None);
+        assert_eq!(deposit_info.priced(0).unwrap().amount, Uint128::new(10));
+
+        let err = DepositPricing {
+            scope: DepositPricingScope::Proposer,
+            increment: Decimal::zero(),
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err, DepositError::ZeroPricingIncrement);
+    }
+
+    #[test]
+    fn test_select_paid() {
+        let deps = mock_dependencies();
+        let depositor = Addr::unchecked("ekez");
+        let spender = Addr::unchecked("pre-propose");
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![CheckedDepositAlternative {
+                denom: CheckedDenom::Native("uusdc".to_string()),
+                amount: Uint128::new(1),
+            }],
+            pricing: None,
+            keeper_share: None,
+        };
+        let primary = CheckedDepositInfo {
+            alternatives: vec![],
+            ..deposit_info.clone()
+        };
+
+        let selected = deposit_info
+            .select_paid(deps.as_ref(), &coins(1, "uusdc"), &depositor, &spender)
+            .unwrap();
+        assert_eq!(
+            selected,
+            CheckedDepositInfo {
+                denom: CheckedDenom::Native("uusdc".to_string()),
+                amount: Uint128::new(1),
+                refund_policy: DepositRefundPolicy::Always,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: None,
+            }
+        );
+
+        let selected = deposit_info
+            .select_paid(
+                deps.as_ref(),
+                &coins(10, NATIVE_DENOM),
+                &depositor,
+                &spender,
+            )
+            .unwrap();
+        assert_eq!(selected, primary);
+
+        // The primary deposit is selected if nothing accepted was
+        // sent, so that checking payment fails as usual.
+        let selected = deposit_info
+            .select_paid(deps.as_ref(), &coins(10, "uatom"), &depositor, &spender)
+            .unwrap();
+        assert_eq!(selected, primary);
+    }
+
     #[test]
     fn test_get_take_deposit_messages() {
         // Does nothing if a native token is being used.
@@ -324,6 +659,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let messages = deposit_info
             .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked(CW20))
@@ -364,6 +702,9 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -390,6 +731,9 @@ pub mod tests {
             denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -414,4 +758,88 @@ pub mod tests {
             .unwrap();
         assert_eq!(messages, vec![]);
     }
+
+    #[test]
+    fn test_get_forfeit_deposit_messages() {
+        let dao = Addr::unchecked("dao");
+        let keeper = Addr::unchecked("keeper");
+        let mut deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(15),
+            refund_policy: DepositRefundPolicy::Never,
+            alternatives: vec![],
+            pricing: None,
+            keeper_share: None,
+        };
+
+        // Without a keeper share the DAO receives everything.
+        let messages = deposit_info
+            .get_forfeit_deposit_messages(&dao, Some(&keeper))
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "dao".to_string(),
+                amount: coins(15, NATIVE_DENOM)
+            })]
+        );
+
+        // The keeper's share is rounded down.
+        deposit_info.keeper_share = Some(Decimal::percent(50));
+        let messages = deposit_info
+            .get_forfeit_deposit_messages(&dao, Some(&keeper))
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "keeper".to_string(),
+                    amount: coins(7, NATIVE_DENOM)
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "dao".to_string(),
+                    amount: coins(8, NATIVE_DENOM)
+                })
+            ]
+        );
+
+        // Without a keeper the DAO receives everything.
+        let messages = deposit_info
+            .get_forfeit_deposit_messages(&dao, None)
+            .unwrap();
+        assert_eq!(messages.len(), 1);
+
+        // The keeper may be given the whole deposit.
+        deposit_info.keeper_share = Some(Decimal::one());
+        let messages = deposit_info
+            .get_forfeit_deposit_messages(&dao, Some(&keeper))
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: "keeper".to_string(),
+                amount: coins(15, NATIVE_DENOM)
+            })]
+        );
+    }
+
+    #[test]
+    fn test_invalid_keeper_share() {
+        let deps = mock_dependencies();
+        for share in [Decimal::zero(), Decimal::percent(101)] {
+            let err = UncheckedDepositInfo {
+                denom: DepositToken::Token {
+                    denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+                },
+                amount: Uint128::new(10),
+                refund_policy: DepositRefundPolicy::Never,
+                alternatives: vec![],
+                pricing: None,
+                keeper_share: Some(share),
+            }
+            .into_checked(deps.as_ref(), Addr::unchecked("dao"))
+            .unwrap_err();
+            assert_eq!(err, DepositError::InvalidKeeperShare);
+        }
+    }
 }
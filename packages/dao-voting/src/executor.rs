@@ -0,0 +1,122 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, StdError, StdResult};
+use thiserror::Error;
+
+use crate::voting::get_voting_power;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ExecutorPolicyError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("An executor allowlist must contain at least one address.")]
+    EmptyAllowlist {},
+}
+
+/// Who may execute a proposal once it has passed.
+#[cw_serde]
+pub enum ExecutorPolicy {
+    /// Any address may execute passed proposals.
+    Anyone {},
+    /// Only addresses with voting power at the height the proposal
+    /// was created may execute it.
+    Members {},
+    /// Only the address that created the proposal may execute it.
+    Proposer {},
+    /// Only the listed addresses may execute passed proposals. This
+    /// lets, for example, a deployment bot or multisig control when
+    /// upgrades happen.
+    Allowlist { addresses: Vec<String> },
+}
+
+impl ExecutorPolicy {
+    pub fn validate(&self, deps: &Deps) -> Result<(), ExecutorPolicyError> {
+        if let Self::Allowlist { addresses } = self {
+            if addresses.is_empty() {
+                return Err(ExecutorPolicyError::EmptyAllowlist {});
+            }
+            for address in addresses {
+                deps.api.addr_validate(address)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Determines if `sender` may execute a proposal created by
+    /// `proposer` at `start_height` in `dao`.
+    pub fn can_execute(
+        &self,
+        deps: Deps,
+        dao: &Addr,
+        sender: &Addr,
+        proposer: &Addr,
+        start_height: u64,
+    ) -> StdResult<bool> {
+        Ok(match self {
+            Self::Anyone {} => true,
+            Self::Members {} => {
+                !get_voting_power(deps, sender.clone(), dao, Some(start_height))?.is_zero()
+            }
+            Self::Proposer {} => sender == proposer,
+            Self::Allowlist { addresses } => addresses.iter().any(|a| a == sender.as_str()),
+        })
+    }
+}
+
+impl From<bool> for ExecutorPolicy {
+    /// Converts the legacy `only_members_execute` flag into a
+    /// policy.
+    fn from(only_members_execute: bool) -> Self {
+        if only_members_execute {
+            Self::Members {}
+        } else {
+            Self::Anyone {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let deps = mock_dependencies();
+        assert_eq!(
+            ExecutorPolicy::Allowlist { addresses: vec![] }
+                .validate(&deps.as_ref())
+                .unwrap_err(),
+            ExecutorPolicyError::EmptyAllowlist {}
+        );
+        ExecutorPolicy::Allowlist {
+            addresses: vec!["bot".to_string()],
+        }
+        .validate(&deps.as_ref())
+        .unwrap();
+    }
+
+    #[test]
+    fn test_can_execute() {
+        let deps = mock_dependencies();
+        let dao = Addr::unchecked("dao");
+        let proposer = Addr::unchecked("proposer");
+        let bot = Addr::unchecked("bot");
+
+        let can_execute = |policy: ExecutorPolicy, sender: &Addr| {
+            policy
+                .can_execute(deps.as_ref(), &dao, sender, &proposer, 0)
+                .unwrap()
+        };
+
+        assert!(can_execute(ExecutorPolicy::Anyone {}, &bot));
+        assert!(can_execute(ExecutorPolicy::Proposer {}, &proposer));
+        assert!(!can_execute(ExecutorPolicy::Proposer {}, &bot));
+        let allowlist = ExecutorPolicy::Allowlist {
+            addresses: vec!["bot".to_string()],
+        };
+        assert!(can_execute(allowlist.clone(), &bot));
+        assert!(!can_execute(allowlist, &proposer));
+    }
+}
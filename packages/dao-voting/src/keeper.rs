@@ -0,0 +1,138 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CosmosMsg, Deps, StdError, StdResult, Uint128};
+use cw_denom::{CheckedDenom, DenomError, UncheckedDenom};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum KeeperBountyError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("Keeper bounty amount must be greater than zero.")]
+    ZeroAmount {},
+}
+
+/// A bounty paid to whoever executes or closes a proposal once it
+/// has finished, so that somebody has a reason to pay the gas to do
+/// so.
+///
+/// Bounties are paid from a pool held by the proposal module, which
+/// the DAO funds by sending tokens to the module. If the pool holds
+/// less than `amount`, no bounty is paid and execution or closing
+/// proceeds as normal.
+///
+/// Proposal deposits may fund bounties as well, see
+/// `UncheckedDepositInfo::keeper_share`.
+#[cw_serde]
+pub struct UncheckedKeeperBounty {
+    pub denom: UncheckedDenom,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct KeeperBounty {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+impl UncheckedKeeperBounty {
+    pub fn into_checked(self, deps: Deps) -> Result<KeeperBounty, KeeperBountyError> {
+        if self.amount.is_zero() {
+            return Err(KeeperBountyError::ZeroAmount {});
+        }
+        Ok(KeeperBounty {
+            denom: self.denom.into_checked(deps)?,
+            amount: self.amount,
+        })
+    }
+}
+
+impl KeeperBounty {
+    /// Returns a message paying the bounty from `pool` to `keeper`,
+    /// or `None` if the pool can not cover it. `reserved` is the
+    /// amount of the pool's balance that may not be used for
+    /// bounties, for example because it is held in escrow.
+    pub fn payout(
+        &self,
+        deps: Deps,
+        pool: &Addr,
+        keeper: &Addr,
+        reserved: Uint128,
+    ) -> StdResult<Option<CosmosMsg>> {
+        let balance = self.denom.query_balance(&deps.querier, pool)?;
+        if balance.saturating_sub(reserved) < self.amount {
+            Ok(None)
+        } else {
+            self.denom
+                .get_transfer_to_message(keeper, self.amount)
+                .map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{
+        coins,
+        testing::{mock_dependencies, mock_dependencies_with_balance, MOCK_CONTRACT_ADDR},
+        BankMsg,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_into_checked() {
+        let deps = mock_dependencies();
+        let bounty = UncheckedKeeperBounty {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::zero(),
+        };
+        assert_eq!(
+            bounty.into_checked(deps.as_ref()).unwrap_err(),
+            KeeperBountyError::ZeroAmount {}
+        );
+    }
+
+    #[test]
+    fn test_payout() {
+        let bounty = KeeperBounty {
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+        };
+        let pool = Addr::unchecked(MOCK_CONTRACT_ADDR);
+        let keeper = Addr::unchecked("keeper");
+
+        let deps = mock_dependencies_with_balance(&coins(9, "ujuno"));
+        assert_eq!(
+            bounty
+                .payout(deps.as_ref(), &pool, &keeper, Uint128::zero())
+                .unwrap(),
+            None
+        );
+
+        let deps = mock_dependencies_with_balance(&coins(15, "ujuno"));
+        assert_eq!(
+            bounty
+                .payout(deps.as_ref(), &pool, &keeper, Uint128::new(6))
+                .unwrap(),
+            None
+        );
+
+        let deps = mock_dependencies_with_balance(&coins(10, "ujuno"));
+        assert_eq!(
+            bounty
+                .payout(deps.as_ref(), &pool, &keeper, Uint128::zero())
+                .unwrap(),
+            Some(
+                BankMsg::Send {
+                    to_address: "keeper".to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()
+            )
+        );
+    }
+}
@@ -3,9 +3,12 @@
 pub mod deposit;
 pub mod duration;
 pub mod error;
+pub mod executor;
+pub mod keeper;
 pub mod multiple_choice;
 pub mod pre_propose;
 pub mod proposal;
+pub mod quadratic;
 pub mod reply;
 pub mod status;
 pub mod threshold;
@@ -1,33 +1,150 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty, StdError, StdResult, Uint128};
+use cosmwasm_std::{from_json, BankMsg, CosmosMsg, Empty, StdError, StdResult, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_denom::UncheckedDenom;
 
 use crate::threshold::{validate_quorum, PercentageThreshold, ThresholdError};
 
 /// Maximum number of choices for multiple choice votes. Chosen
 /// in order to impose a bound on state / queries.
 pub const MAX_NUM_CHOICES: u32 = 20;
+/// Maximum number of options a ranked choice ballot may rank. Bounds
+/// the number of distinct rankings, and so the cost of the instant
+/// runoff tally, regardless of the number of voters.
+pub const MAX_RANKED_CHOICES: u32 = 3;
 const NONE_OPTION_DESCRIPTION: &str = "None of the above";
 
 /// Determines how many choices may be selected.
 #[cw_serde]
 pub enum VotingStrategy {
-    SingleChoice { quorum: PercentageThreshold },
+    SingleChoice {
+        quorum: PercentageThreshold,
+    },
+    /// Approval voting. Voters may select up to `max_selections`
+    /// options, each of which receives the voter's full voting
+    /// power. The `winners` options with the most votes win, so long
+    /// as they received more votes than "None of the above", and the
+    /// messages of every winning option are executed.
+    Approval {
+        quorum: PercentageThreshold,
+        /// The maximum number of options a voter may select.
+        max_selections: u32,
+        /// The maximum number of winning options.
+        winners: u32,
+    },
+    /// Budget allocation. Each option requests an amount from
+    /// `budget` and voters may select up to `max_selections` options,
+    /// each of which receives the voter's full voting power. Options
+    /// with more votes than "None of the above" are funded in order
+    /// of their votes while the remaining budget covers their
+    /// request, and the messages of every funded option are executed.
+    /// An option's messages may only transfer `denom`, and must
+    /// transfer exactly its requested amount.
+    Budget {
+        quorum: PercentageThreshold,
+        /// The denom the budget is paid in.
+        denom: UncheckedDenom,
+        /// The total amount of `denom` that may be allocated to
+        /// options.
+        budget: Uint128,
+        /// The maximum number of options a voter may select.
+        max_selections: u32,
+    },
+    /// Ranked choice voting. Voters rank options in order of
+    /// preference and the winner is determined by instant runoff:
+    /// the option with the fewest votes is eliminated and its votes
+    /// transferred to the next ranked option until an option has a
+    /// majority of the remaining votes.
+    RankedChoice {
+        quorum: PercentageThreshold,
+    },
 }
 
 impl VotingStrategy {
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             VotingStrategy::SingleChoice { quorum } => validate_quorum(quorum),
+            VotingStrategy::Approval {
+                quorum,
+                max_selections,
+                winners,
+            } => {
+                if *max_selections == 0 || *winners == 0 {
+                    return Err(ThresholdError::InvalidApprovalStrategy {});
+                }
+                validate_quorum(quorum)
+            }
+            VotingStrategy::Budget {
+                quorum,
+                budget,
+                max_selections,
+                ..
+            } => {
+                if budget.is_zero() || *max_selections == 0 {
+                    return Err(ThresholdError::InvalidBudgetStrategy {});
+                }
+                validate_quorum(quorum)
+            }
+            VotingStrategy::RankedChoice { quorum } => validate_quorum(quorum),
         }
     }
 
     pub fn get_quorum(&self) -> PercentageThreshold {
         match self {
             VotingStrategy::SingleChoice { quorum } => *quorum,
+            VotingStrategy::Approval { quorum, .. } => *quorum,
+            VotingStrategy::Budget { quorum, .. } => *quorum,
+            VotingStrategy::RankedChoice { quorum } => *quorum,
+        }
+    }
+
+    /// The maximum number of options a voter may select, or rank
+    /// for ranked choice voting.
+    pub fn max_selections(&self) -> u32 {
+        match self {
+            VotingStrategy::SingleChoice { .. } => 1,
+            VotingStrategy::Approval { max_selections, .. } => *max_selections,
+            VotingStrategy::Budget { max_selections, .. } => *max_selections,
+            VotingStrategy::RankedChoice { .. } => MAX_RANKED_CHOICES,
         }
     }
 }
 
+/// Returns the total amount of DENOM transferred by MSGS, or `None` if
+/// any message does anything other than transfer DENOM. Used to check
+/// that the options of a budget allocation proposal only move the
+/// amounts they request from the budget.
+pub fn budget_transfer_amount(denom: &UncheckedDenom, msgs: &[CosmosMsg]) -> Option<Uint128> {
+    msgs.iter().try_fold(Uint128::zero(), |total, msg| {
+        let amount = match (denom, msg) {
+            (UncheckedDenom::Native(denom), CosmosMsg::Bank(BankMsg::Send { amount, .. })) => {
+                amount.iter().try_fold(Uint128::zero(), |sum, coin| {
+                    if coin.denom == *denom {
+                        sum.checked_add(coin.amount).ok()
+                    } else {
+                        None
+                    }
+                })?
+            }
+            (
+                UncheckedDenom::Cw20(token),
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }),
+            ) if contract_addr == token && funds.is_empty() => {
+                match from_json::<Cw20ExecuteMsg>(msg).ok()? {
+                    Cw20ExecuteMsg::Transfer { amount, .. } => amount,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        total.checked_add(amount).ok()
+    })
+}
+
 /// A multiple choice vote, picking the desired option
 #[cw_serde]
 #[derive(Copy)]
@@ -102,6 +219,10 @@ pub struct MultipleChoiceOption {
     pub title: String,
     pub description: String,
     pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The amount this option requests from the budget of a budget
+    /// allocation proposal.
+    #[serde(default)]
+    pub requested_amount: Option<Uint128>,
 }
 
 /// Multiple choice options that have been verified for correctness, and have all fields
@@ -122,6 +243,10 @@ pub struct CheckedMultipleChoiceOption {
     pub description: String,
     pub msgs: Vec<CosmosMsg<Empty>>,
     pub vote_count: Uint128,
+    /// The amount this option requests from the budget of a budget
+    /// allocation proposal.
+    #[serde(default)]
+    pub requested_amount: Option<Uint128>,
 }
 
 impl MultipleChoiceOptions {
@@ -147,6 +272,7 @@ impl MultipleChoiceOptions {
                     msgs: choice.msgs,
                     vote_count: Uint128::zero(),
                     title: choice.title,
+                    requested_amount: choice.requested_amount,
                 };
                 checked_options.push(checked_option)
             });
@@ -159,6 +285,7 @@ impl MultipleChoiceOptions {
             msgs: vec![],
             vote_count: Uint128::zero(),
             title: NONE_OPTION_DESCRIPTION.to_string(),
+            requested_amount: None,
         };
 
         checked_options.push(none_option);
@@ -216,17 +343,51 @@ mod test {
         assert_eq!(votes, MultipleChoiceVotes::zero(2))
     }
 
+    #[test]
+    fn test_validate_approval_strategy() {
+        let strategy = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            max_selections: 3,
+            winners: 2,
+        };
+        strategy.validate().unwrap();
+        assert_eq!(strategy.max_selections(), 3);
+        assert_eq!(strategy.get_quorum(), PercentageThreshold::Majority {});
+
+        let strategy = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            max_selections: 0,
+            winners: 2,
+        };
+        assert_eq!(
+            strategy.validate(),
+            Err(ThresholdError::InvalidApprovalStrategy {})
+        );
+
+        let strategy = VotingStrategy::Approval {
+            quorum: PercentageThreshold::Majority {},
+            max_selections: 1,
+            winners: 0,
+        };
+        assert_eq!(
+            strategy.validate(),
+            Err(ThresholdError::InvalidApprovalStrategy {})
+        );
+    }
+
     #[test]
     fn test_into_checked() {
         let options = vec![
             super::MultipleChoiceOption {
                 description: "multiple choice option 1".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 title: "title".to_string(),
             },
             super::MultipleChoiceOption {
                 description: "multiple choice option 2".to_string(),
                 msgs: vec![],
+                requested_amount: None,
                 title: "title".to_string(),
             },
         ];
@@ -267,6 +428,7 @@ mod test {
         let options = vec![super::MultipleChoiceOption {
             description: "multiple choice option 1".to_string(),
             msgs: vec![],
+            requested_amount: None,
             title: "title".to_string(),
         }];
 
@@ -68,3 +68,28 @@ pub struct MultipleChoiceProposeMsg {
     /// An optional vote cast by the proposer.
     pub vote: Option<MultipleChoiceAutoVote>,
 }
+
+/// A choice in a condorcet proposal.
+#[cw_serde]
+pub struct CondorcetChoice {
+    /// The messages that should be executed if this choice wins.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// The contents of a message to create a proposal in the condorcet
+/// proposal module.
+///
+/// We move this type to this package for the same reasons as
+/// `SingleChoiceProposeMsg`.
+#[cw_serde]
+pub struct CondorcetProposeMsg {
+    /// The choices of the proposal. A "none of the above" choice is
+    /// added by the proposal module.
+    pub choices: Vec<CondorcetChoice>,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+}
@@ -0,0 +1,219 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps, Isqrt, OverflowError, StdError, Uint128};
+use thiserror::Error;
+
+use crate::threshold::Threshold;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum QuadraticVotingError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Quadratic voting can not be used with an absolute percentage threshold. Use a threshold and quorum or an absolute count instead.")]
+    UnsupportedThreshold {},
+
+    #[error(
+        "Voter is not a member of the identity group and may not vote on quadratic proposals."
+    )]
+    NotVerified {},
+
+    #[error("Casting these votes costs {cost} credits, but only {available} are available.")]
+    InsufficientCredits { available: Uint128, cost: Uint128 },
+
+    #[error("At least one vote must be cast.")]
+    ZeroVotes {},
+
+    #[error("The number of votes to cast may only be chosen on proposals using credit quadratic voting.")]
+    NotCreditVoting {},
+}
+
+/// A source of identity used to limit sybil attacks against
+/// quadratic voting. Splitting voting power across many addresses
+/// would otherwise increase the total weight a voter controls.
+#[cw_serde]
+pub enum IdentityGate {
+    /// Only members of the cw4 group at `address` with a non-zero
+    /// weight at the proposal's start height may vote.
+    Cw4Group { address: String },
+}
+
+/// How the weight of a quadratic ballot is determined.
+#[cw_serde]
+pub enum QuadraticMode {
+    /// Each ballot is counted with the square root of the voter's
+    /// voting power.
+    PerBallot {},
+    /// Voters spend voice credits on votes, with `n` votes costing
+    /// `n²` credits. A voter's credits are their voting power at the
+    /// proposal's start height, less the credits they have spent on
+    /// other proposals in the module that are still open for voting.
+    /// Credits spent on a proposal are returned once its voting
+    /// period ends.
+    Credits {},
+}
+
+impl Default for QuadraticMode {
+    fn default() -> Self {
+        Self::PerBallot {}
+    }
+}
+
+/// Configuration for quadratic voting. When enabled, a ballot is
+/// counted with the square root of the voter's voting power, or of
+/// the credits they spend on it, rather than the voting power
+/// itself.
+///
+/// As the sum of the square roots of every voter's power can not be
+/// known ahead of time, quorum is measured using the (linear) voting
+/// power of voters who have voted, while the threshold for passing is
+/// measured using the square root weighted votes. For the same reason
+/// quadratic proposals are never passed or rejected before their
+/// voting period ends.
+#[cw_serde]
+pub struct QuadraticVoting {
+    /// An optional identity gate voters must pass before voting.
+    pub identity_gate: Option<IdentityGate>,
+    /// Whether ballots are weighted by voting power or by the
+    /// credits spent on them. Defaults to `PerBallot`.
+    #[serde(default)]
+    pub mode: QuadraticMode,
+}
+
+impl QuadraticVoting {
+    pub fn validate(&self, deps: &Deps) -> Result<(), QuadraticVotingError> {
+        if let Some(IdentityGate::Cw4Group { address }) = &self.identity_gate {
+            deps.api.addr_validate(address)?;
+        }
+        Ok(())
+    }
+
+    /// Quadratic voting measures the passing threshold against votes
+    /// cast, so thresholds that are a percentage of total voting
+    /// power may not be used with it.
+    pub fn validate_threshold(&self, threshold: &Threshold) -> Result<(), QuadraticVotingError> {
+        match threshold {
+            Threshold::AbsolutePercentage { .. } => {
+                Err(QuadraticVotingError::UnsupportedThreshold {})
+            }
+            Threshold::ThresholdQuorum { .. } | Threshold::AbsoluteCount { .. } => Ok(()),
+        }
+    }
+
+    /// Errors if `voter` does not pass the identity gate at `height`.
+    pub fn check_identity(
+        &self,
+        deps: Deps,
+        voter: &Addr,
+        height: u64,
+    ) -> Result<(), QuadraticVotingError> {
+        match &self.identity_gate {
+            Some(IdentityGate::Cw4Group { address }) => {
+                let member: cw4::MemberResponse = deps.querier.query_wasm_smart(
+                    address,
+                    &cw4::Cw4QueryMsg::Member {
+                        addr: voter.to_string(),
+                        at_height: Some(height),
+                    },
+                )?;
+                match member.weight {
+                    Some(weight) if weight > 0 => Ok(()),
+                    _ => Err(QuadraticVotingError::NotVerified {}),
+                }
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the weight a ballot with `power` voting power has under
+/// quadratic voting.
+pub fn quadratic_weight(power: Uint128) -> Uint128 {
+    power.isqrt()
+}
+
+/// Returns the number of votes to cast and their cost in credits
+/// under credit quadratic voting. If `votes` is `None`, as many votes
+/// as `available` credits afford are cast.
+pub fn spend_credits(
+    available: Uint128,
+    votes: Option<Uint128>,
+) -> Result<(Uint128, Uint128), QuadraticVotingError> {
+    let votes = votes.unwrap_or_else(|| quadratic_weight(available).max(Uint128::one()));
+    if votes.is_zero() {
+        return Err(QuadraticVotingError::ZeroVotes {});
+    }
+    let cost = votes.checked_mul(votes)?;
+    if cost > available {
+        return Err(QuadraticVotingError::InsufficientCredits { available, cost });
+    }
+    Ok((votes, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quadratic_weight() {
+        assert_eq!(quadratic_weight(Uint128::zero()), Uint128::zero());
+        assert_eq!(quadratic_weight(Uint128::new(1)), Uint128::new(1));
+        assert_eq!(quadratic_weight(Uint128::new(99)), Uint128::new(9));
+        assert_eq!(quadratic_weight(Uint128::new(100)), Uint128::new(10));
+        assert_eq!(
+            quadratic_weight(Uint128::new(1_000_000_000_000)),
+            Uint128::new(1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_spend_credits() {
+        assert_eq!(
+            spend_credits(Uint128::new(99), None),
+            Ok((Uint128::new(9), Uint128::new(81)))
+        );
+        assert_eq!(
+            spend_credits(Uint128::new(99), Some(Uint128::new(3))),
+            Ok((Uint128::new(3), Uint128::new(9)))
+        );
+        assert_eq!(
+            spend_credits(Uint128::new(99), Some(Uint128::new(10))),
+            Err(QuadraticVotingError::InsufficientCredits {
+                available: Uint128::new(99),
+                cost: Uint128::new(100)
+            })
+        );
+        assert_eq!(
+            spend_credits(Uint128::new(99), Some(Uint128::zero())),
+            Err(QuadraticVotingError::ZeroVotes {})
+        );
+        assert_eq!(
+            spend_credits(Uint128::zero(), None),
+            Err(QuadraticVotingError::InsufficientCredits {
+                available: Uint128::zero(),
+                cost: Uint128::one()
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_threshold() {
+        let quadratic = QuadraticVoting {
+            identity_gate: None,
+            mode: QuadraticMode::PerBallot {},
+        };
+        assert_eq!(
+            quadratic.validate_threshold(&Threshold::AbsolutePercentage {
+                percentage: crate::threshold::PercentageThreshold::Majority {},
+            }),
+            Err(QuadraticVotingError::UnsupportedThreshold {})
+        );
+        quadratic
+            .validate_threshold(&Threshold::AbsoluteCount {
+                threshold: Uint128::new(10),
+            })
+            .unwrap();
+    }
+}
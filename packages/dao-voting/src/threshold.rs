@@ -62,6 +62,12 @@ pub enum ThresholdError {
 
     #[error("Not possible to reach required (passing) threshold")]
     UnreachableThreshold {},
+
+    #[error("Approval voting must allow at least one selection and have at least one winner")]
+    InvalidApprovalStrategy {},
+
+    #[error("Budget allocation must have a non-zero budget and allow at least one selection")]
+    InvalidBudgetStrategy {},
 }
 
 /// A percentage of voting power that must vote yes for a proposal to
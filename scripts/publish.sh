@@ -125,6 +125,10 @@ cd contracts/pre-propose/dao-pre-propose-single
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/pre-propose/dao-pre-propose-sponsored
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 sleep 120
 
 cd contracts/pre-propose/dao-pre-propose-multiple
@@ -135,6 +139,10 @@ cd contracts/pre-propose/dao-pre-propose-approval-single
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/pre-propose/dao-pre-propose-approval-multiple
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/pre-propose/dao-pre-propose-approver
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"